@@ -2,41 +2,53 @@ use std::fs::File;
 
 use anyhow::Result;
 
-use uesave::{Property, Save, StructValue, ValueArray};
+use uesave::{Property, Save, StructValue, ValueRef};
 
-#[rustfmt::skip]
 fn main() -> Result<()> {
     let save = Save::read(&mut File::open(
         "examples/space-rig-decorator/PropPack.sav",
     )?)?;
-    let props = &save.root.properties["PropList"];
-    if let Property::Array { value: ValueArray::Struct { value, .. }, .. } = props {
-        for prop in value {
-            if let StructValue::Struct(p) = prop {
-                if let Property::Struct { value: StructValue::Struct(value), .. } = &p["PropPosition_7_B8CD81CD4E138D8E06FBBA8056FE4C85"] {
-                    if let Property::Struct { value: StructValue::Quat(value) , .. } = &value["Rotation"] {
-                        print!("{}:{}:{}:{}:", value.x, value.y, value.z, value.w);
-                    }
-                    if let Property::Struct { value: StructValue::Vector(value), .. } = &value["Translation"] {
-                        print!("{}:{}:{}:", value.x, value.y, value.z);
-                    }
-                    if let Property::Struct { value: StructValue::Vector(value), .. } = &value["Scale3D"] {
-                        print!("{}:{}:{}:", value.x, value.y, value.z);
-                    }
-                }
-                if let Property::Str { value, .. } = &p["PropName_10_4BB2A20D47DA97D8ECB7D888147BBB97"] {
-                    print!("{value}:");
-                }
-                if let Property::Bool { value, .. } = &p["IsStaticMesh_20_AB977B2F47FD519F53FB8CB85490631B"] {
-                    print!("{value}:");
-                }
-                if let Property::Object { value, .. } = &p["DynamicPropClass_19_AA6C35BE4D24AB6B42E8999E55661065"] {
-                    print!("{value}:");
-                }
-                if let Property::Object { value, .. } = &p["StaticMesh_18_BAF2BF524DA3EA4A985B9D87B727223A"] {
-                    println!("{value}");
-                }
-            }
+    let props = save
+        .get("PropList")
+        .and_then(ValueRef::as_property)
+        .and_then(Property::as_array::<StructValue>)
+        .unwrap_or_default();
+    for i in 0..props.len() {
+        let get = |name: &str| {
+            save.get(&format!("PropList[{i}].{name}"))
+                .and_then(ValueRef::as_property)
+        };
+        let position = "PropPosition_7_B8CD81CD4E138D8E06FBBA8056FE4C85";
+        let get_struct =
+            |name: &str| get(&format!("{position}.{name}")).and_then(Property::as_struct);
+        if let Some(value) = get_struct("Rotation").and_then(StructValue::as_quat) {
+            print!("{}:{}:{}:{}:", value.x, value.y, value.z, value.w);
+        }
+        if let Some(value) = get_struct("Translation").and_then(StructValue::as_vector) {
+            print!("{}:{}:{}:", value.x, value.y, value.z);
+        }
+        if let Some(value) = get_struct("Scale3D").and_then(StructValue::as_vector) {
+            print!("{}:{}:{}:", value.x, value.y, value.z);
+        }
+        if let Some(value) =
+            get("PropName_10_4BB2A20D47DA97D8ECB7D888147BBB97").and_then(Property::as_str)
+        {
+            print!("{value}:");
+        }
+        if let Some(value) =
+            get("IsStaticMesh_20_AB977B2F47FD519F53FB8CB85490631B").and_then(Property::as_bool)
+        {
+            print!("{value}:");
+        }
+        if let Some(value) =
+            get("DynamicPropClass_19_AA6C35BE4D24AB6B42E8999E55661065").and_then(Property::as_str)
+        {
+            print!("{value}:");
+        }
+        if let Some(value) =
+            get("StaticMesh_18_BAF2BF524DA3EA4A985B9D87B727223A").and_then(Property::as_str)
+        {
+            println!("{value}");
         }
     }
     Ok(())
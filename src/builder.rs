@@ -0,0 +1,602 @@
+use crate::{
+    CustomFormatData, FString, Header, PackageVersion, Properties, Property, PropertyKey, Root,
+    Save,
+};
+
+/// Engine version presets for [`Save::builder`] setting the save game, package and custom
+/// versions of the header. The custom versions are those registered by the engine itself, games
+/// add their own which can be set with [`SaveBuilder::custom_version`] or taken from a header of
+/// their own with [`SaveBuilder::header`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineVersion {
+    UE4_25,
+    UE4_26,
+    UE4_27,
+    UE5_0,
+    UE5_1,
+    UE5_2,
+    UE5_3,
+    UE5_4,
+}
+impl EngineVersion {
+    fn major_minor(self) -> (u16, u16) {
+        match self {
+            EngineVersion::UE4_25 => (4, 25),
+            EngineVersion::UE4_26 => (4, 26),
+            EngineVersion::UE4_27 => (4, 27),
+            EngineVersion::UE5_0 => (5, 0),
+            EngineVersion::UE5_1 => (5, 1),
+            EngineVersion::UE5_2 => (5, 2),
+            EngineVersion::UE5_3 => (5, 3),
+            EngineVersion::UE5_4 => (5, 4),
+        }
+    }
+    /// EUnrealEngineObjectUE4Version and EUnrealEngineObjectUE5Version saved by this version
+    fn package_version(self) -> PackageVersion {
+        match self {
+            EngineVersion::UE4_25 => PackageVersion::Old(518),
+            EngineVersion::UE4_26 | EngineVersion::UE4_27 => PackageVersion::Old(522),
+            // LARGE_WORLD_COORDINATES
+            EngineVersion::UE5_0 => PackageVersion::New(522, 1004),
+            // ADD_SOFTOBJECTPATH_LIST
+            EngineVersion::UE5_1 => PackageVersion::New(522, 1008),
+            // DATA_RESOURCES
+            EngineVersion::UE5_2 => PackageVersion::New(522, 1009),
+            // SCRIPT_SERIALIZATION_OFFSET
+            EngineVersion::UE5_3 => PackageVersion::New(522, 1010),
+            // PROPERTY_TAG_COMPLETE_TYPE_NAME
+            EngineVersion::UE5_4 => PackageVersion::New(522, 1012),
+        }
+    }
+    /// Latest versions of the custom versions registered by this version
+    fn custom_versions(self) -> &'static [(u128, i32)] {
+        match self {
+            EngineVersion::UE4_25 => CUSTOM_VERSIONS_4_25,
+            EngineVersion::UE4_26 => CUSTOM_VERSIONS_4_26,
+            EngineVersion::UE4_27 => CUSTOM_VERSIONS_4_27,
+            EngineVersion::UE5_0 => CUSTOM_VERSIONS_5_0,
+            EngineVersion::UE5_1 => CUSTOM_VERSIONS_5_1,
+            EngineVersion::UE5_2 => CUSTOM_VERSIONS_5_2,
+            EngineVersion::UE5_3 => CUSTOM_VERSIONS_5_3,
+            EngineVersion::UE5_4 => CUSTOM_VERSIONS_5_4,
+        }
+    }
+    /// Header of a save written by this version
+    pub fn header(self) -> Header {
+        let (major, minor) = self.major_minor();
+        Header {
+            magic: u32::from_le_bytes(*b"GVAS"),
+            // FSaveGameFileVersion::PackageFileSummaryVersionChange stores the UE5 version
+            save_game_version: if major >= 5 { 3 } else { 2 },
+            package_version: self.package_version(),
+            engine_version_major: major,
+            engine_version_minor: minor,
+            engine_version_patch: 0,
+            engine_version_build: 0,
+            engine_version: format!("++UE{major}+Release-{major}.{minor}").into(),
+            custom_format_version: 3,
+            custom_format: self
+                .custom_versions()
+                .iter()
+                .map(|&(id, value)| CustomFormatData {
+                    id: uuid::Uuid::from_u128(id),
+                    value,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Builder for new saves, see [`Save::builder`]
+#[derive(Debug)]
+pub struct SaveBuilder {
+    header: Header,
+    root: Root<'static>,
+}
+impl Default for SaveBuilder {
+    fn default() -> Self {
+        Self {
+            header: EngineVersion::UE4_27.header(),
+            root: Root {
+                save_game_type: FString::default(),
+                properties: Properties::default(),
+            },
+        }
+    }
+}
+impl SaveBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Replace the header e.g. with one read from an existing save of the game
+    pub fn header(mut self, header: Header) -> Self {
+        self.header = header;
+        self
+    }
+    /// Replace the header with the preset of `version`. Custom versions added before are lost.
+    pub fn engine_version(mut self, version: EngineVersion) -> Self {
+        self.header = version.header();
+        self
+    }
+    /// Set the version of the custom version `id`, adding it if it is not present
+    pub fn custom_version(mut self, id: uuid::Uuid, value: i32) -> Self {
+        let custom_format = &mut self.header.custom_format;
+        match custom_format.iter_mut().find(|c| c.id == id) {
+            Some(c) => c.value = value,
+            None => custom_format.push(CustomFormatData { id, value }),
+        }
+        self
+    }
+    /// Set the class of the save game object e.g. `/Script/Game.MySaveGame`
+    pub fn save_game_type(mut self, save_game_type: impl Into<FString<'static>>) -> Self {
+        self.root.save_game_type = save_game_type.into();
+        self
+    }
+    /// Add a root property, replacing any with the same key
+    pub fn property(
+        mut self,
+        key: impl Into<PropertyKey<'static>>,
+        property: Property<'static>,
+    ) -> Self {
+        self.root.properties.insert(key, property);
+        self
+    }
+    /// Add root properties e.g. from [`crate::to_properties`]
+    pub fn properties(mut self, properties: Properties<'static>) -> Self {
+        self.root.properties.0.extend(properties.0);
+        self
+    }
+    pub fn build(self) -> Save<'static> {
+        Save {
+            header: self.header,
+            root: self.root,
+            extra: vec![],
+            checksum: None,
+            container: None,
+            encryption: None,
+        }
+    }
+}
+
+const CUSTOM_VERSIONS_4_25: &[(u128, i32)] = &[
+    (0xfcf57afa_5076_4283_b9a9_e658ffa02d32, 61),
+    (0x24bb7af3_5646_4f83_1f2f_2dc249ad96ff, 5),
+    (0x76a52329_0923_45b5_98ae_d841cf2f6ad8, 2),
+    (0x5fbc6907_55c8_40ae_8e67_f1845efff13f, 1),
+    (0xfb26e412_1f15_4b4d_9372_550a961d2f70, 3),
+    (0x9c54d522_a826_4fbe_9421_074661b482d0, 30),
+    (0xb0d832e4_1f89_4f0d_accf_7eb736fd4aa2, 10),
+    (0xe1c64328_a22c_4d53_a36c_8e866417bd8c, 0),
+    (0x375ec13c_06e4_48fb_b500_84f0262a717e, 4),
+    (0xe4b068ed_f494_42e9_a231_da0b2e46bb41, 38),
+    (0xcffc743f_43b0_4480_9391_14df171d2073, 37),
+    (0xb02b49b5_bb20_44e9_a304_32b752e40360, 2),
+    (0xa4e4105c_59a1_49b5_a7c5_40c4547edfee, 0),
+    (0x39c831c9_5ae6_47dc_9a44_9c173e1c8e7c, 0),
+    (0x78f01b33_ebea_4f98_b9b4_84eaccb95aa2, 4),
+    (0x6631380f_2d4d_43e0_8009_cf276956a95a, 0),
+    (0x12f88b9f_8875_4afc_a67c_d90c383abd29, 43),
+    (0x7b5ae74c_d270_4c10_a958_57980b212a5a, 12),
+    (0xd7296918_1dd6_4bdd_9de2_64a83cc13884, 3),
+    (0xc2a15278_bfe7_4afe_6c17_90ff531df755, 1),
+    (0x6eaca3d4_40ec_4cc1_b786_8bed09428fc5, 3),
+    (0x29e575dd_e0a3_4627_9d10_d276232cdcea, 17),
+    (0xaf43a65d_7fd3_4947_9873_3e8ed9c1bb05, 7),
+    (0x6b266cec_1ec7_4b8f_a30b_e4d90942fc07, 1),
+    (0x0df73d61_a23f_47ea_b727_89e90c41499a, 1),
+    (0x601d1886_ac64_4f84_aa16_d3de0deac7d6, 31),
+    (0x9dffbcd6_494f_0158_e221_12823c92a888, 10),
+    (0xf2aed0ac_9afe_416f_8664_aa7ffa26d6fc, 1),
+    (0x174f1f0b_b4c6_45a5_b13f_2ee8d0fb917d, 10),
+    (0x35f94a83_e258_406c_a318_09f59610247c, 37),
+    (0xb68fc16e_8b1b_42e2_b453_215c058844fe, 1),
+    (0xb2e18506_4273_cfc2_a54e_f4bb758bba07, 1),
+    (0x54683250_8099_48af_8bc8_9896fbadf9b7, 0),
+    (0x430c4d19_7154_4970_8769_9b69df90b0e5, 14),
+    (0xaafe32bd_5395_4c14_b66a_5e251032d1dd, 1),
+    (0x23afe18e_4ce1_4e58_8d61_c252b953beb7, 11),
+    (0xa462b7ea_f499_4e3a_99c1_ec1f8224e1b2, 2),
+    (0x2eb5fdbd_01ac_4d10_8136_f38f3393a5da, 5),
+    (0x509d354f_f6e6_492f_a749_85b2073c631c, 0),
+    (0x717f9ee7_e9b0_493a_88b3_91321b388107, 6),
+    (0x4a56eb40_10f5_11dc_92d3_347eb2c96ae7, 2),
+    (0xd78a4a00_e858_4697_baa8_19b5487d46b4, 17),
+    (0x5579f886_933a_4c1f_83ba_087b6361b92f, 1),
+    (0x612fbe52_da53_400b_910d_4f919fb1857c, 1),
+    (0xa4237a36_caea_41c9_8fa2_18f858681bf3, 4),
+    (0x804e3f75_7088_4b49_a4d6_8c063c7eb6dc, 5),
+    (0xfb680af2_59ef_4ba3_baa8_19b573c8443d, 2),
+    (0x9950b70e_b41a_4e17_bbcc_fa0d57817fd6, 1),
+    (0xab965196_45d8_08fc_b7d7_228d78ad569e, 1),
+];
+
+const CUSTOM_VERSIONS_4_26: &[(u128, i32)] = &[
+    (0x82e77c4e_3323_43a5_b46b_13c597310df3, 0),
+    (0xfcf57afa_5076_4283_b9a9_e658ffa02d32, 68),
+    (0x24bb7af3_5646_4f83_1f2f_2dc249ad96ff, 5),
+    (0xfb26e412_1f15_4b4d_9372_550a961d2f70, 3),
+    (0x9c54d522_a826_4fbe_9421_074661b482d0, 43),
+    (0xb0d832e4_1f89_4f0d_accf_7eb736fd4aa2, 10),
+    (0xe1c64328_a22c_4d53_a36c_8e866417bd8c, 0),
+    (0x375ec13c_06e4_48fb_b500_84f0262a717e, 4),
+    (0xe4b068ed_f494_42e9_a231_da0b2e46bb41, 40),
+    (0xcffc743f_43b0_4480_9391_14df171d2073, 37),
+    (0xb02b49b5_bb20_44e9_a304_32b752e40360, 3),
+    (0xa4e4105c_59a1_49b5_a7c5_40c4547edfee, 0),
+    (0x39c831c9_5ae6_47dc_9a44_9c173e1c8e7c, 0),
+    (0x78f01b33_ebea_4f98_b9b4_84eaccb95aa2, 14),
+    (0x6631380f_2d4d_43e0_8009_cf276956a95a, 0),
+    (0x12f88b9f_8875_4afc_a67c_d90c383abd29, 44),
+    (0x7b5ae74c_d270_4c10_a958_57980b212a5a, 12),
+    (0xd7296918_1dd6_4bdd_9de2_64a83cc13884, 3),
+    (0xc2a15278_bfe7_4afe_6c17_90ff531df755, 1),
+    (0x6eaca3d4_40ec_4cc1_b786_8bed09428fc5, 3),
+    (0x29e575dd_e0a3_4627_9d10_d276232cdcea, 17),
+    (0xaf43a65d_7fd3_4947_9873_3e8ed9c1bb05, 15),
+    (0x6b266cec_1ec7_4b8f_a30b_e4d90942fc07, 1),
+    (0x0df73d61_a23f_47ea_b727_89e90c41499a, 1),
+    (0x601d1886_ac64_4f84_aa16_d3de0deac7d6, 43),
+    (0xe7086368_6b23_4c58_8439_1b7016265e91, 1),
+    (0x9dffbcd6_494f_0158_e221_12823c92a888, 10),
+    (0xf2aed0ac_9afe_416f_8664_aa7ffa26d6fc, 1),
+    (0x174f1f0b_b4c6_45a5_b13f_2ee8d0fb917d, 10),
+    (0x35f94a83_e258_406c_a318_09f59610247c, 40),
+    (0xb68fc16e_8b1b_42e2_b453_215c058844fe, 1),
+    (0xb2e18506_4273_cfc2_a54e_f4bb758bba07, 1),
+    (0x64f58936_fd1b_42ba_ba96_7289d5d0fa4e, 1),
+    (0x6f0ed827_a609_4895_9c91_998d90180ea4, 2),
+    (0x717f9ee7_e9b0_493a_88b3_91321b388107, 7),
+    (0x54683250_8099_48af_8bc8_9896fbadf9b7, 0),
+    (0x430c4d19_7154_4970_8769_9b69df90b0e5, 15),
+    (0xaafe32bd_5395_4c14_b66a_5e251032d1dd, 1),
+    (0x23afe18e_4ce1_4e58_8d61_c252b953beb7, 11),
+    (0xa462b7ea_f499_4e3a_99c1_ec1f8224e1b2, 4),
+    (0x2eb5fdbd_01ac_4d10_8136_f38f3393a5da, 5),
+    (0x509d354f_f6e6_492f_a749_85b2073c631c, 0),
+    (0x4a56eb40_10f5_11dc_92d3_347eb2c96ae7, 2),
+    (0xd78a4a00_e858_4697_baa8_19b5487d46b4, 18),
+    (0x5579f886_933a_4c1f_83ba_087b6361b92f, 2),
+    (0x612fbe52_da53_400b_910d_4f919fb1857c, 1),
+    (0xa4237a36_caea_41c9_8fa2_18f858681bf3, 4),
+    (0x804e3f75_7088_4b49_a4d6_8c063c7eb6dc, 5),
+    (0xfb680af2_59ef_4ba3_baa8_19b573c8443d, 2),
+    (0x9950b70e_b41a_4e17_bbcc_fa0d57817fd6, 1),
+];
+
+const CUSTOM_VERSIONS_4_27: &[(u128, i32)] = &[
+    (0x82e77c4e_3323_43a5_b46b_13c597310df3, 0),
+    (0xfcf57afa_5076_4283_b9a9_e658ffa02d32, 68),
+    (0x24bb7af3_5646_4f83_1f2f_2dc249ad96ff, 5),
+    (0xfb26e412_1f15_4b4d_9372_550a961d2f70, 3),
+    (0x9c54d522_a826_4fbe_9421_074661b482d0, 43),
+    (0xb0d832e4_1f89_4f0d_accf_7eb736fd4aa2, 10),
+    (0xe1c64328_a22c_4d53_a36c_8e866417bd8c, 0),
+    (0x375ec13c_06e4_48fb_b500_84f0262a717e, 4),
+    (0xe4b068ed_f494_42e9_a231_da0b2e46bb41, 40),
+    (0xcffc743f_43b0_4480_9391_14df171d2073, 37),
+    (0xb02b49b5_bb20_44e9_a304_32b752e40360, 3),
+    (0xa4e4105c_59a1_49b5_a7c5_40c4547edfee, 0),
+    (0x39c831c9_5ae6_47dc_9a44_9c173e1c8e7c, 0),
+    (0x78f01b33_ebea_4f98_b9b4_84eaccb95aa2, 14),
+    (0x6631380f_2d4d_43e0_8009_cf276956a95a, 0),
+    (0x12f88b9f_8875_4afc_a67c_d90c383abd29, 45),
+    (0x7b5ae74c_d270_4c10_a958_57980b212a5a, 13),
+    (0xd7296918_1dd6_4bdd_9de2_64a83cc13884, 3),
+    (0xc2a15278_bfe7_4afe_6c17_90ff531df755, 1),
+    (0x6eaca3d4_40ec_4cc1_b786_8bed09428fc5, 3),
+    (0x29e575dd_e0a3_4627_9d10_d276232cdcea, 17),
+    (0xaf43a65d_7fd3_4947_9873_3e8ed9c1bb05, 15),
+    (0x6b266cec_1ec7_4b8f_a30b_e4d90942fc07, 1),
+    (0x0df73d61_a23f_47ea_b727_89e90c41499a, 1),
+    (0x601d1886_ac64_4f84_aa16_d3de0deac7d6, 47),
+    (0xe7086368_6b23_4c58_8439_1b7016265e91, 1),
+    (0x9dffbcd6_494f_0158_e221_12823c92a888, 10),
+    (0xf2aed0ac_9afe_416f_8664_aa7ffa26d6fc, 1),
+    (0x174f1f0b_b4c6_45a5_b13f_2ee8d0fb917d, 10),
+    (0x35f94a83_e258_406c_a318_09f59610247c, 41),
+    (0xb68fc16e_8b1b_42e2_b453_215c058844fe, 1),
+    (0xb2e18506_4273_cfc2_a54e_f4bb758bba07, 1),
+    (0x64f58936_fd1b_42ba_ba96_7289d5d0fa4e, 1),
+    (0x6f0ed827_a609_4895_9c91_998d90180ea4, 2),
+    (0x717f9ee7_e9b0_493a_88b3_91321b388107, 8),
+    (0x54683250_8099_48af_8bc8_9896fbadf9b7, 0),
+    (0x430c4d19_7154_4970_8769_9b69df90b0e5, 15),
+    (0xaafe32bd_5395_4c14_b66a_5e251032d1dd, 1),
+    (0x23afe18e_4ce1_4e58_8d61_c252b953beb7, 11),
+    (0xa462b7ea_f499_4e3a_99c1_ec1f8224e1b2, 4),
+    (0x2eb5fdbd_01ac_4d10_8136_f38f3393a5da, 5),
+    (0x509d354f_f6e6_492f_a749_85b2073c631c, 0),
+    (0x4a56eb40_10f5_11dc_92d3_347eb2c96ae7, 2),
+    (0xd78a4a00_e858_4697_baa8_19b5487d46b4, 18),
+    (0x5579f886_933a_4c1f_83ba_087b6361b92f, 2),
+    (0x612fbe52_da53_400b_910d_4f919fb1857c, 1),
+    (0xa4237a36_caea_41c9_8fa2_18f858681bf3, 4),
+    (0x804e3f75_7088_4b49_a4d6_8c063c7eb6dc, 5),
+    (0xfb680af2_59ef_4ba3_baa8_19b573c8443d, 2),
+    (0x9950b70e_b41a_4e17_bbcc_fa0d57817fd6, 1),
+];
+
+const CUSTOM_VERSIONS_5_0: &[(u128, i32)] = &[
+    (0x82e77c4e_3323_43a5_b46b_13c597310df3, 0),
+    (0xfcf57afa_5076_4283_b9a9_e658ffa02d32, 76),
+    (0x24bb7af3_5646_4f83_1f2f_2dc249ad96ff, 5),
+    (0xfb26e412_1f15_4b4d_9372_550a961d2f70, 3),
+    (0x9c54d522_a826_4fbe_9421_074661b482d0, 44),
+    (0xb0d832e4_1f89_4f0d_accf_7eb736fd4aa2, 10),
+    (0xe1c64328_a22c_4d53_a36c_8e866417bd8c, 0),
+    (0x375ec13c_06e4_48fb_b500_84f0262a717e, 4),
+    (0xe4b068ed_f494_42e9_a231_da0b2e46bb41, 40),
+    (0xcffc743f_43b0_4480_9391_14df171d2073, 37),
+    (0xb02b49b5_bb20_44e9_a304_32b752e40360, 3),
+    (0xa4e4105c_59a1_49b5_a7c5_40c4547edfee, 0),
+    (0x39c831c9_5ae6_47dc_9a44_9c173e1c8e7c, 0),
+    (0x78f01b33_ebea_4f98_b9b4_84eaccb95aa2, 20),
+    (0x6631380f_2d4d_43e0_8009_cf276956a95a, 0),
+    (0x12f88b9f_8875_4afc_a67c_d90c383abd29, 47),
+    (0x7b5ae74c_d270_4c10_a958_57980b212a5a, 13),
+    (0xd7296918_1dd6_4bdd_9de2_64a83cc13884, 3),
+    (0xc2a15278_bfe7_4afe_6c17_90ff531df755, 1),
+    (0x6eaca3d4_40ec_4cc1_b786_8bed09428fc5, 3),
+    (0x29e575dd_e0a3_4627_9d10_d276232cdcea, 17),
+    (0xaf43a65d_7fd3_4947_9873_3e8ed9c1bb05, 15),
+    (0x6b266cec_1ec7_4b8f_a30b_e4d90942fc07, 1),
+    (0x0df73d61_a23f_47ea_b727_89e90c41499a, 1),
+    (0x601d1886_ac64_4f84_aa16_d3de0deac7d6, 83),
+    (0xe7086368_6b23_4c58_8439_1b7016265e91, 35),
+    (0x9dffbcd6_494f_0158_e221_12823c92a888, 10),
+    (0xf2aed0ac_9afe_416f_8664_aa7ffa26d6fc, 1),
+    (0x174f1f0b_b4c6_45a5_b13f_2ee8d0fb917d, 10),
+    (0x35f94a83_e258_406c_a318_09f59610247c, 41),
+    (0xb68fc16e_8b1b_42e2_b453_215c058844fe, 1),
+    (0xb2e18506_4273_cfc2_a54e_f4bb758bba07, 1),
+    (0x64f58936_fd1b_42ba_ba96_7289d5d0fa4e, 1),
+    (0x6f0ed827_a609_4895_9c91_998d90180ea4, 2),
+    (0x717f9ee7_e9b0_493a_88b3_91321b388107, 8),
+    (0x54683250_8099_48af_8bc8_9896fbadf9b7, 0),
+    (0x430c4d19_7154_4970_8769_9b69df90b0e5, 15),
+    (0xaafe32bd_5395_4c14_b66a_5e251032d1dd, 1),
+    (0x23afe18e_4ce1_4e58_8d61_c252b953beb7, 11),
+    (0xa462b7ea_f499_4e3a_99c1_ec1f8224e1b2, 4),
+    (0x2eb5fdbd_01ac_4d10_8136_f38f3393a5da, 5),
+    (0x509d354f_f6e6_492f_a749_85b2073c631c, 0),
+    (0x4a56eb40_10f5_11dc_92d3_347eb2c96ae7, 2),
+    (0xd78a4a00_e858_4697_baa8_19b5487d46b4, 18),
+    (0x5579f886_933a_4c1f_83ba_087b6361b92f, 2),
+    (0x612fbe52_da53_400b_910d_4f919fb1857c, 1),
+    (0xa4237a36_caea_41c9_8fa2_18f858681bf3, 4),
+    (0x804e3f75_7088_4b49_a4d6_8c063c7eb6dc, 5),
+    (0xfb680af2_59ef_4ba3_baa8_19b573c8443d, 2),
+    (0x9950b70e_b41a_4e17_bbcc_fa0d57817fd6, 1),
+    (0x697dd581_e64f_41ab_aa4a_51ecbeb7b628, 64),
+    (0xd89b5e42_24bd_4d46_8412_aca8df641779, 31),
+    (0x59da5d52_1232_4948_b878_597870b8e98b, 9),
+    (0x5b4c06b7_2463_4af8_805b_bf70cdf5d0dd, 2),
+];
+
+const CUSTOM_VERSIONS_5_1: &[(u128, i32)] = &[
+    (0x82e77c4e_3323_43a5_b46b_13c597310df3, 0),
+    (0xfcf57afa_5076_4283_b9a9_e658ffa02d32, 76),
+    (0x24bb7af3_5646_4f83_1f2f_2dc249ad96ff, 5),
+    (0xfb26e412_1f15_4b4d_9372_550a961d2f70, 3),
+    (0x9c54d522_a826_4fbe_9421_074661b482d0, 47),
+    (0xb0d832e4_1f89_4f0d_accf_7eb736fd4aa2, 10),
+    (0xe1c64328_a22c_4d53_a36c_8e866417bd8c, 0),
+    (0x375ec13c_06e4_48fb_b500_84f0262a717e, 4),
+    (0xe4b068ed_f494_42e9_a231_da0b2e46bb41, 40),
+    (0xcffc743f_43b0_4480_9391_14df171d2073, 37),
+    (0xb02b49b5_bb20_44e9_a304_32b752e40360, 3),
+    (0xa4e4105c_59a1_49b5_a7c5_40c4547edfee, 0),
+    (0x39c831c9_5ae6_47dc_9a44_9c173e1c8e7c, 0),
+    (0x78f01b33_ebea_4f98_b9b4_84eaccb95aa2, 20),
+    (0x6631380f_2d4d_43e0_8009_cf276956a95a, 0),
+    (0x12f88b9f_8875_4afc_a67c_d90c383abd29, 47),
+    (0x7b5ae74c_d270_4c10_a958_57980b212a5a, 13),
+    (0xd7296918_1dd6_4bdd_9de2_64a83cc13884, 3),
+    (0xc2a15278_bfe7_4afe_6c17_90ff531df755, 1),
+    (0x6eaca3d4_40ec_4cc1_b786_8bed09428fc5, 3),
+    (0x29e575dd_e0a3_4627_9d10_d276232cdcea, 17),
+    (0xaf43a65d_7fd3_4947_9873_3e8ed9c1bb05, 15),
+    (0x6b266cec_1ec7_4b8f_a30b_e4d90942fc07, 1),
+    (0x0df73d61_a23f_47ea_b727_89e90c41499a, 1),
+    (0x601d1886_ac64_4f84_aa16_d3de0deac7d6, 104),
+    (0xe7086368_6b23_4c58_8439_1b7016265e91, 40),
+    (0x9dffbcd6_494f_0158_e221_12823c92a888, 10),
+    (0xf2aed0ac_9afe_416f_8664_aa7ffa26d6fc, 1),
+    (0x174f1f0b_b4c6_45a5_b13f_2ee8d0fb917d, 10),
+    (0x35f94a83_e258_406c_a318_09f59610247c, 41),
+    (0xb68fc16e_8b1b_42e2_b453_215c058844fe, 1),
+    (0xb2e18506_4273_cfc2_a54e_f4bb758bba07, 1),
+    (0x64f58936_fd1b_42ba_ba96_7289d5d0fa4e, 1),
+    (0x6f0ed827_a609_4895_9c91_998d90180ea4, 2),
+    (0x717f9ee7_e9b0_493a_88b3_91321b388107, 8),
+    (0x54683250_8099_48af_8bc8_9896fbadf9b7, 0),
+    (0x430c4d19_7154_4970_8769_9b69df90b0e5, 15),
+    (0xaafe32bd_5395_4c14_b66a_5e251032d1dd, 1),
+    (0x23afe18e_4ce1_4e58_8d61_c252b953beb7, 11),
+    (0xa462b7ea_f499_4e3a_99c1_ec1f8224e1b2, 4),
+    (0x2eb5fdbd_01ac_4d10_8136_f38f3393a5da, 5),
+    (0x509d354f_f6e6_492f_a749_85b2073c631c, 0),
+    (0x4a56eb40_10f5_11dc_92d3_347eb2c96ae7, 2),
+    (0xd78a4a00_e858_4697_baa8_19b5487d46b4, 18),
+    (0x5579f886_933a_4c1f_83ba_087b6361b92f, 2),
+    (0x612fbe52_da53_400b_910d_4f919fb1857c, 1),
+    (0xa4237a36_caea_41c9_8fa2_18f858681bf3, 4),
+    (0x804e3f75_7088_4b49_a4d6_8c063c7eb6dc, 5),
+    (0xfb680af2_59ef_4ba3_baa8_19b573c8443d, 2),
+    (0x9950b70e_b41a_4e17_bbcc_fa0d57817fd6, 1),
+    (0x697dd581_e64f_41ab_aa4a_51ecbeb7b628, 84),
+    (0xd89b5e42_24bd_4d46_8412_aca8df641779, 40),
+    (0x59da5d52_1232_4948_b878_597870b8e98b, 11),
+    (0x5b4c06b7_2463_4af8_805b_bf70cdf5d0dd, 9),
+];
+
+const CUSTOM_VERSIONS_5_2: &[(u128, i32)] = &[
+    (0x82e77c4e_3323_43a5_b46b_13c597310df3, 0),
+    (0xfcf57afa_5076_4283_b9a9_e658ffa02d32, 76),
+    (0x24bb7af3_5646_4f83_1f2f_2dc249ad96ff, 5),
+    (0xfb26e412_1f15_4b4d_9372_550a961d2f70, 3),
+    (0x9c54d522_a826_4fbe_9421_074661b482d0, 47),
+    (0xb0d832e4_1f89_4f0d_accf_7eb736fd4aa2, 10),
+    (0xe1c64328_a22c_4d53_a36c_8e866417bd8c, 0),
+    (0x375ec13c_06e4_48fb_b500_84f0262a717e, 4),
+    (0xe4b068ed_f494_42e9_a231_da0b2e46bb41, 40),
+    (0xcffc743f_43b0_4480_9391_14df171d2073, 37),
+    (0xb02b49b5_bb20_44e9_a304_32b752e40360, 3),
+    (0xa4e4105c_59a1_49b5_a7c5_40c4547edfee, 0),
+    (0x39c831c9_5ae6_47dc_9a44_9c173e1c8e7c, 0),
+    (0x78f01b33_ebea_4f98_b9b4_84eaccb95aa2, 20),
+    (0x6631380f_2d4d_43e0_8009_cf276956a95a, 0),
+    (0x12f88b9f_8875_4afc_a67c_d90c383abd29, 48),
+    (0x7b5ae74c_d270_4c10_a958_57980b212a5a, 13),
+    (0xd7296918_1dd6_4bdd_9de2_64a83cc13884, 3),
+    (0xc2a15278_bfe7_4afe_6c17_90ff531df755, 1),
+    (0x6eaca3d4_40ec_4cc1_b786_8bed09428fc5, 3),
+    (0x29e575dd_e0a3_4627_9d10_d276232cdcea, 17),
+    (0xaf43a65d_7fd3_4947_9873_3e8ed9c1bb05, 15),
+    (0x6b266cec_1ec7_4b8f_a30b_e4d90942fc07, 1),
+    (0x0df73d61_a23f_47ea_b727_89e90c41499a, 1),
+    (0x601d1886_ac64_4f84_aa16_d3de0deac7d6, 119),
+    (0xe7086368_6b23_4c58_8439_1b7016265e91, 44),
+    (0x9dffbcd6_494f_0158_e221_12823c92a888, 10),
+    (0xf2aed0ac_9afe_416f_8664_aa7ffa26d6fc, 1),
+    (0x174f1f0b_b4c6_45a5_b13f_2ee8d0fb917d, 10),
+    (0x35f94a83_e258_406c_a318_09f59610247c, 41),
+    (0xb68fc16e_8b1b_42e2_b453_215c058844fe, 1),
+    (0xb2e18506_4273_cfc2_a54e_f4bb758bba07, 1),
+    (0x64f58936_fd1b_42ba_ba96_7289d5d0fa4e, 1),
+    (0x6f0ed827_a609_4895_9c91_998d90180ea4, 2),
+    (0x717f9ee7_e9b0_493a_88b3_91321b388107, 8),
+    (0x54683250_8099_48af_8bc8_9896fbadf9b7, 0),
+    (0x430c4d19_7154_4970_8769_9b69df90b0e5, 15),
+    (0xaafe32bd_5395_4c14_b66a_5e251032d1dd, 1),
+    (0x23afe18e_4ce1_4e58_8d61_c252b953beb7, 11),
+    (0xa462b7ea_f499_4e3a_99c1_ec1f8224e1b2, 4),
+    (0x2eb5fdbd_01ac_4d10_8136_f38f3393a5da, 5),
+    (0x509d354f_f6e6_492f_a749_85b2073c631c, 0),
+    (0x4a56eb40_10f5_11dc_92d3_347eb2c96ae7, 2),
+    (0xd78a4a00_e858_4697_baa8_19b5487d46b4, 18),
+    (0x5579f886_933a_4c1f_83ba_087b6361b92f, 2),
+    (0x612fbe52_da53_400b_910d_4f919fb1857c, 1),
+    (0xa4237a36_caea_41c9_8fa2_18f858681bf3, 4),
+    (0x804e3f75_7088_4b49_a4d6_8c063c7eb6dc, 5),
+    (0xfb680af2_59ef_4ba3_baa8_19b573c8443d, 2),
+    (0x9950b70e_b41a_4e17_bbcc_fa0d57817fd6, 1),
+    (0x697dd581_e64f_41ab_aa4a_51ecbeb7b628, 101),
+    (0xd89b5e42_24bd_4d46_8412_aca8df641779, 45),
+    (0x59da5d52_1232_4948_b878_597870b8e98b, 11),
+    (0x5b4c06b7_2463_4af8_805b_bf70cdf5d0dd, 9),
+];
+
+const CUSTOM_VERSIONS_5_3: &[(u128, i32)] = &[
+    (0x82e77c4e_3323_43a5_b46b_13c597310df3, 0),
+    (0xfcf57afa_5076_4283_b9a9_e658ffa02d32, 76),
+    (0x24bb7af3_5646_4f83_1f2f_2dc249ad96ff, 5),
+    (0xfb26e412_1f15_4b4d_9372_550a961d2f70, 3),
+    (0x9c54d522_a826_4fbe_9421_074661b482d0, 47),
+    (0xb0d832e4_1f89_4f0d_accf_7eb736fd4aa2, 10),
+    (0xe1c64328_a22c_4d53_a36c_8e866417bd8c, 0),
+    (0x375ec13c_06e4_48fb_b500_84f0262a717e, 4),
+    (0xe4b068ed_f494_42e9_a231_da0b2e46bb41, 40),
+    (0xcffc743f_43b0_4480_9391_14df171d2073, 37),
+    (0xb02b49b5_bb20_44e9_a304_32b752e40360, 3),
+    (0xa4e4105c_59a1_49b5_a7c5_40c4547edfee, 0),
+    (0x39c831c9_5ae6_47dc_9a44_9c173e1c8e7c, 0),
+    (0x78f01b33_ebea_4f98_b9b4_84eaccb95aa2, 20),
+    (0x6631380f_2d4d_43e0_8009_cf276956a95a, 0),
+    (0x12f88b9f_8875_4afc_a67c_d90c383abd29, 48),
+    (0x7b5ae74c_d270_4c10_a958_57980b212a5a, 13),
+    (0xd7296918_1dd6_4bdd_9de2_64a83cc13884, 3),
+    (0xc2a15278_bfe7_4afe_6c17_90ff531df755, 1),
+    (0x6eaca3d4_40ec_4cc1_b786_8bed09428fc5, 3),
+    (0x29e575dd_e0a3_4627_9d10_d276232cdcea, 17),
+    (0xaf43a65d_7fd3_4947_9873_3e8ed9c1bb05, 15),
+    (0x6b266cec_1ec7_4b8f_a30b_e4d90942fc07, 1),
+    (0x0df73d61_a23f_47ea_b727_89e90c41499a, 1),
+    (0x601d1886_ac64_4f84_aa16_d3de0deac7d6, 132),
+    (0xe7086368_6b23_4c58_8439_1b7016265e91, 48),
+    (0x9dffbcd6_494f_0158_e221_12823c92a888, 10),
+    (0xf2aed0ac_9afe_416f_8664_aa7ffa26d6fc, 1),
+    (0x174f1f0b_b4c6_45a5_b13f_2ee8d0fb917d, 10),
+    (0x35f94a83_e258_406c_a318_09f59610247c, 41),
+    (0xb68fc16e_8b1b_42e2_b453_215c058844fe, 1),
+    (0xb2e18506_4273_cfc2_a54e_f4bb758bba07, 1),
+    (0x64f58936_fd1b_42ba_ba96_7289d5d0fa4e, 1),
+    (0x6f0ed827_a609_4895_9c91_998d90180ea4, 2),
+    (0x717f9ee7_e9b0_493a_88b3_91321b388107, 8),
+    (0x54683250_8099_48af_8bc8_9896fbadf9b7, 0),
+    (0x430c4d19_7154_4970_8769_9b69df90b0e5, 15),
+    (0xaafe32bd_5395_4c14_b66a_5e251032d1dd, 1),
+    (0x23afe18e_4ce1_4e58_8d61_c252b953beb7, 11),
+    (0xa462b7ea_f499_4e3a_99c1_ec1f8224e1b2, 4),
+    (0x2eb5fdbd_01ac_4d10_8136_f38f3393a5da, 5),
+    (0x509d354f_f6e6_492f_a749_85b2073c631c, 0),
+    (0x4a56eb40_10f5_11dc_92d3_347eb2c96ae7, 2),
+    (0xd78a4a00_e858_4697_baa8_19b5487d46b4, 18),
+    (0x5579f886_933a_4c1f_83ba_087b6361b92f, 2),
+    (0x612fbe52_da53_400b_910d_4f919fb1857c, 1),
+    (0xa4237a36_caea_41c9_8fa2_18f858681bf3, 4),
+    (0x804e3f75_7088_4b49_a4d6_8c063c7eb6dc, 5),
+    (0xfb680af2_59ef_4ba3_baa8_19b573c8443d, 2),
+    (0x9950b70e_b41a_4e17_bbcc_fa0d57817fd6, 1),
+    (0x697dd581_e64f_41ab_aa4a_51ecbeb7b628, 110),
+    (0xd89b5e42_24bd_4d46_8412_aca8df641779, 49),
+    (0x59da5d52_1232_4948_b878_597870b8e98b, 11),
+    (0x5b4c06b7_2463_4af8_805b_bf70cdf5d0dd, 10),
+];
+
+const CUSTOM_VERSIONS_5_4: &[(u128, i32)] = &[
+    (0x82e77c4e_3323_43a5_b46b_13c597310df3, 0),
+    (0xfcf57afa_5076_4283_b9a9_e658ffa02d32, 76),
+    (0x24bb7af3_5646_4f83_1f2f_2dc249ad96ff, 5),
+    (0xfb26e412_1f15_4b4d_9372_550a961d2f70, 3),
+    (0x9c54d522_a826_4fbe_9421_074661b482d0, 47),
+    (0xb0d832e4_1f89_4f0d_accf_7eb736fd4aa2, 10),
+    (0xe1c64328_a22c_4d53_a36c_8e866417bd8c, 0),
+    (0x375ec13c_06e4_48fb_b500_84f0262a717e, 4),
+    (0xe4b068ed_f494_42e9_a231_da0b2e46bb41, 40),
+    (0xcffc743f_43b0_4480_9391_14df171d2073, 37),
+    (0xb02b49b5_bb20_44e9_a304_32b752e40360, 3),
+    (0xa4e4105c_59a1_49b5_a7c5_40c4547edfee, 0),
+    (0x39c831c9_5ae6_47dc_9a44_9c173e1c8e7c, 0),
+    (0x78f01b33_ebea_4f98_b9b4_84eaccb95aa2, 20),
+    (0x6631380f_2d4d_43e0_8009_cf276956a95a, 0),
+    (0x12f88b9f_8875_4afc_a67c_d90c383abd29, 48),
+    (0x7b5ae74c_d270_4c10_a958_57980b212a5a, 13),
+    (0xd7296918_1dd6_4bdd_9de2_64a83cc13884, 3),
+    (0xc2a15278_bfe7_4afe_6c17_90ff531df755, 1),
+    (0x6eaca3d4_40ec_4cc1_b786_8bed09428fc5, 3),
+    (0x29e575dd_e0a3_4627_9d10_d276232cdcea, 17),
+    (0xaf43a65d_7fd3_4947_9873_3e8ed9c1bb05, 15),
+    (0x6b266cec_1ec7_4b8f_a30b_e4d90942fc07, 1),
+    (0x0df73d61_a23f_47ea_b727_89e90c41499a, 1),
+    (0x601d1886_ac64_4f84_aa16_d3de0deac7d6, 146),
+    (0xe7086368_6b23_4c58_8439_1b7016265e91, 52),
+    (0x9dffbcd6_494f_0158_e221_12823c92a888, 10),
+    (0xf2aed0ac_9afe_416f_8664_aa7ffa26d6fc, 1),
+    (0x174f1f0b_b4c6_45a5_b13f_2ee8d0fb917d, 10),
+    (0x35f94a83_e258_406c_a318_09f59610247c, 41),
+    (0xb68fc16e_8b1b_42e2_b453_215c058844fe, 1),
+    (0xb2e18506_4273_cfc2_a54e_f4bb758bba07, 1),
+    (0x64f58936_fd1b_42ba_ba96_7289d5d0fa4e, 1),
+    (0x6f0ed827_a609_4895_9c91_998d90180ea4, 2),
+    (0x717f9ee7_e9b0_493a_88b3_91321b388107, 8),
+    (0x54683250_8099_48af_8bc8_9896fbadf9b7, 0),
+    (0x430c4d19_7154_4970_8769_9b69df90b0e5, 15),
+    (0xaafe32bd_5395_4c14_b66a_5e251032d1dd, 1),
+    (0x23afe18e_4ce1_4e58_8d61_c252b953beb7, 11),
+    (0xa462b7ea_f499_4e3a_99c1_ec1f8224e1b2, 4),
+    (0x2eb5fdbd_01ac_4d10_8136_f38f3393a5da, 5),
+    (0x509d354f_f6e6_492f_a749_85b2073c631c, 0),
+    (0x4a56eb40_10f5_11dc_92d3_347eb2c96ae7, 2),
+    (0xd78a4a00_e858_4697_baa8_19b5487d46b4, 18),
+    (0x5579f886_933a_4c1f_83ba_087b6361b92f, 2),
+    (0x612fbe52_da53_400b_910d_4f919fb1857c, 1),
+    (0xa4237a36_caea_41c9_8fa2_18f858681bf3, 4),
+    (0x804e3f75_7088_4b49_a4d6_8c063c7eb6dc, 5),
+    (0xfb680af2_59ef_4ba3_baa8_19b573c8443d, 2),
+    (0x9950b70e_b41a_4e17_bbcc_fa0d57817fd6, 1),
+    (0x697dd581_e64f_41ab_aa4a_51ecbeb7b628, 118),
+    (0xd89b5e42_24bd_4d46_8412_aca8df641779, 54),
+    (0x59da5d52_1232_4948_b878_597870b8e98b, 11),
+    (0x5b4c06b7_2463_4af8_805b_bf70cdf5d0dd, 10),
+];
@@ -0,0 +1,128 @@
+use md5::{Digest, Md5};
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+
+/// Hash function of a [`Checksum`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChecksumAlgorithm {
+    /// CRC-32 stored as little endian u32
+    Crc32,
+    Md5,
+    Sha1,
+}
+impl ChecksumAlgorithm {
+    /// Length of the checksum in bytes
+    pub fn size(self) -> usize {
+        match self {
+            Self::Crc32 => 4,
+            Self::Md5 => 16,
+            Self::Sha1 => 20,
+        }
+    }
+    fn from_len(len: usize) -> Option<Self> {
+        [Self::Crc32, Self::Md5, Self::Sha1]
+            .into_iter()
+            .find(|a| a.size() == len)
+    }
+    pub fn compute(self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Crc32 => crc32fast::hash(data).to_le_bytes().to_vec(),
+            Self::Md5 => Md5::digest(data).to_vec(),
+            Self::Sha1 => Sha1::digest(data).to_vec(),
+        }
+    }
+}
+
+/// Checksums of all [`ChecksumAlgorithm`]s computed incrementally
+#[derive(Clone, Default)]
+pub(crate) struct Hashers {
+    crc32: crc32fast::Hasher,
+    md5: Md5,
+    sha1: Sha1,
+}
+impl Hashers {
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        self.crc32.update(data);
+        self.md5.update(data);
+        self.sha1.update(data);
+    }
+    pub(crate) fn finish(self, algorithm: ChecksumAlgorithm) -> Vec<u8> {
+        match algorithm {
+            ChecksumAlgorithm::Crc32 => self.crc32.finalize().to_le_bytes().to_vec(),
+            ChecksumAlgorithm::Md5 => self.md5.finalize().to_vec(),
+            ChecksumAlgorithm::Sha1 => self.sha1.finalize().to_vec(),
+        }
+    }
+}
+
+/// Location of a [`Checksum`] relative to the save data it covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChecksumPosition {
+    /// Preceding the GVAS magic
+    Header,
+    /// Following the save
+    Footer,
+}
+
+/// Checksum of the save data stored alongside it which is recomputed on write
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checksum {
+    pub algorithm: ChecksumAlgorithm,
+    pub position: ChecksumPosition,
+}
+impl Checksum {
+    /// Detects a checksum header by the GVAS magic following it
+    pub(crate) fn detect_header(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"GVAS") {
+            return None;
+        }
+        [4, 16, 20]
+            .into_iter()
+            .find(|&len| data.get(len..).is_some_and(|d| d.starts_with(b"GVAS")))
+            .and_then(ChecksumAlgorithm::from_len)
+            .map(|algorithm| Self {
+                algorithm,
+                position: ChecksumPosition::Header,
+            })
+    }
+    /// Detects a checksum footer from the data following the root properties of a save which
+    /// is normally `[0; 4]`
+    pub(crate) fn detect_footer(trailing: &[u8]) -> Option<Self> {
+        match trailing {
+            [0, 0, 0, 0, checksum @ ..] => {
+                ChecksumAlgorithm::from_len(checksum.len()).map(|algorithm| Self {
+                    algorithm,
+                    position: ChecksumPosition::Footer,
+                })
+            }
+            _ => None,
+        }
+    }
+    /// Splits `data` into the save data and stored checksum
+    pub fn split<'a>(&self, data: &'a [u8]) -> Option<(&'a [u8], &'a [u8])> {
+        let len = self.algorithm.size();
+        if data.len() < len {
+            return None;
+        }
+        Some(match self.position {
+            ChecksumPosition::Header => {
+                let (checksum, body) = data.split_at(len);
+                (body, checksum)
+            }
+            ChecksumPosition::Footer => data.split_at(data.len() - len),
+        })
+    }
+    /// Whether the checksum stored in `data` matches the save data
+    pub fn verify(&self, data: &[u8]) -> bool {
+        self.split(data)
+            .is_some_and(|(body, checksum)| self.algorithm.compute(body) == checksum)
+    }
+    /// Adds the checksum of `body` to it
+    pub fn apply(&self, body: &[u8]) -> Vec<u8> {
+        let checksum = self.algorithm.compute(body);
+        match self.position {
+            ChecksumPosition::Header => [&checksum[..], body].concat(),
+            ChecksumPosition::Footer => [body, &checksum[..]].concat(),
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use std::any::Any;
+use std::fmt::Debug;
+use std::io::{Read, Write};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Limits, TResult};
+
+/// Format wrapping the GVAS data of a save e.g. to compress it. Containers other than the
+/// built-in ones are detected by [`crate::Save::read`] once they are added to
+/// [`crate::ReadOptions::containers`].
+pub trait SaveContainer: DynContainer + Debug + Send + Sync {
+    /// Whether `data` starts with the magic of this container
+    fn detect(&self, data: &[u8]) -> bool;
+    /// Extracts the GVAS data along with the container parameters required to wrap it again.
+    /// Containers which are not built in return [`Container::Custom`]. Data is only extracted
+    /// within [`Limits::check_allocation`] so a decompression bomb fails early.
+    fn unwrap(&self, data: &[u8], limits: &Limits) -> TResult<(Container, Vec<u8>)>;
+    /// Wraps GVAS data
+    fn wrap(&self, data: &[u8]) -> TResult<Vec<u8>>;
+}
+
+/// Cloning and comparison of boxed [`SaveContainer`]s, implemented for all of them which are
+/// `Clone` and `PartialEq`
+pub trait DynContainer {
+    fn clone_box(&self) -> Box<dyn SaveContainer>;
+    fn eq_dyn(&self, other: &dyn SaveContainer) -> bool;
+    fn as_any(&self) -> &dyn Any;
+}
+impl<T: SaveContainer + Clone + PartialEq + 'static> DynContainer for T {
+    fn clone_box(&self) -> Box<dyn SaveContainer> {
+        Box::new(self.clone())
+    }
+    fn eq_dyn(&self, other: &dyn SaveContainer) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+impl Clone for Box<dyn SaveContainer> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+impl PartialEq for dyn SaveContainer {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_dyn(other)
+    }
+}
+
+/// Container a save was wrapped in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Container {
+    Palworld(PalworldContainer),
+    Chunked(ChunkedContainer),
+    LengthPrefixed(LengthPrefixedContainer),
+    /// Container added to [`crate::ReadOptions::containers`]. It is not serialized, saves
+    /// wrapped in one can only be written back directly.
+    #[serde(skip)]
+    Custom(Box<dyn SaveContainer>),
+}
+impl Container {
+    /// Built-in containers which are detected by default
+    pub fn builtin() -> Vec<Box<dyn SaveContainer>> {
+        vec![
+            Box::new(PalworldContainer::default()),
+            Box::new(ChunkedContainer::default()),
+            Box::new(LengthPrefixedContainer),
+        ]
+    }
+    /// Unwraps `data` with the first of `containers` detecting it, `None` if none does
+    pub fn unwrap_with(
+        containers: &[Box<dyn SaveContainer>],
+        data: &[u8],
+        limits: &Limits,
+    ) -> TResult<Option<(Self, Vec<u8>)>> {
+        containers
+            .iter()
+            .find(|container| container.detect(data))
+            .map(|container| container.unwrap(data, limits))
+            .transpose()
+    }
+    /// Wraps GVAS data
+    pub fn wrap(&self, data: &[u8]) -> TResult<Vec<u8>> {
+        match self {
+            Self::Palworld(container) => container.wrap(data),
+            Self::Chunked(container) => container.wrap(data),
+            Self::LengthPrefixed(container) => container.wrap(data),
+            Self::Custom(container) => container.wrap(data),
+        }
+    }
+}
+
+/// Decompresses `data` declared to hold `len` bytes, stopping right after them so a bogus
+/// declaration is noticed without decompressing all of the data
+fn inflate(data: &[u8], len: u64) -> TResult<Vec<u8>> {
+    let mut buf = vec![];
+    ZlibDecoder::new(data)
+        .take(len.saturating_add(1))
+        .read_to_end(&mut buf)?;
+    Ok(buf)
+}
+fn deflate(data: &[u8]) -> TResult<Vec<u8>> {
+    let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+    encoder.write_all(data)?;
+    Ok(encoder.finish()?)
+}
+
+/// Palworld `PlZ` container: a small header followed by the zlib compressed save
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PalworldContainer {
+    /// 0x31 if the save is compressed once, 0x32 if it is compressed twice
+    pub save_type: u8,
+}
+impl Default for PalworldContainer {
+    fn default() -> Self {
+        Self { save_type: 0x32 }
+    }
+}
+impl PalworldContainer {
+    const MAGIC: &'static [u8] = b"PlZ";
+}
+impl SaveContainer for PalworldContainer {
+    fn detect(&self, data: &[u8]) -> bool {
+        data.get(8..11) == Some(Self::MAGIC)
+    }
+    fn unwrap(&self, mut data: &[u8], limits: &Limits) -> TResult<(Container, Vec<u8>)> {
+        let uncompressed_len = data.read_u32::<LE>()?;
+        // length of the data compressed once
+        let compressed_len = data.read_u32::<LE>()?;
+        let mut magic = [0; 3];
+        data.read_exact(&mut magic)?;
+        let save_type = data.read_u8()?;
+        limits.check_allocation(uncompressed_len as u64)?;
+        let gvas = match save_type {
+            0x31 => inflate(data, uncompressed_len as u64)?,
+            0x32 => {
+                limits.check_allocation(compressed_len as u64)?;
+                let compressed = inflate(data, compressed_len as u64)?;
+                if compressed.len() != compressed_len as usize {
+                    return Err(Error::Other(format!(
+                        "PlZ header declares {compressed_len} compressed bytes but found {}",
+                        compressed.len()
+                    )));
+                }
+                inflate(&compressed, uncompressed_len as u64)?
+            }
+            _ => {
+                return Err(Error::Other(format!(
+                    "unsupported PlZ save type 0x{save_type:x}"
+                )))
+            }
+        };
+        if gvas.len() != uncompressed_len as usize {
+            return Err(Error::Other(format!(
+                "PlZ header declares {uncompressed_len} uncompressed bytes but found {}",
+                gvas.len()
+            )));
+        }
+        Ok((Container::Palworld(Self { save_type }), gvas))
+    }
+    fn wrap(&self, data: &[u8]) -> TResult<Vec<u8>> {
+        let mut compressed = deflate(data)?;
+        let compressed_len = compressed.len();
+        if self.save_type == 0x32 {
+            compressed = deflate(&compressed)?;
+        }
+        let mut buf = vec![];
+        buf.write_u32::<LE>(data.len() as u32)?;
+        buf.write_u32::<LE>(compressed_len as u32)?;
+        buf.write_all(Self::MAGIC)?;
+        buf.write_u8(self.save_type)?;
+        buf.write_all(&compressed)?;
+        Ok(buf)
+    }
+}
+
+/// Unreal Engine compressed archive (`FArchive::SerializeCompressed`): a package file tag
+/// followed by a summary of the zlib compressed blocks (`FCompressedChunkInfo`) and the blocks
+/// themselves. Consecutive archives are concatenated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkedContainer {
+    /// Maximum uncompressed size of each block
+    pub block_size: u64,
+}
+impl Default for ChunkedContainer {
+    fn default() -> Self {
+        // LOADING_COMPRESSION_CHUNK_SIZE
+        Self {
+            block_size: 0x20000,
+        }
+    }
+}
+impl ChunkedContainer {
+    const PACKAGE_FILE_TAG: u64 = 0x9E2A83C1;
+}
+impl SaveContainer for ChunkedContainer {
+    fn detect(&self, data: &[u8]) -> bool {
+        data.get(..8) == Some(&Self::PACKAGE_FILE_TAG.to_le_bytes())
+    }
+    fn unwrap(&self, mut data: &[u8], limits: &Limits) -> TResult<(Container, Vec<u8>)> {
+        let mut block_size = None;
+        let mut gvas = vec![];
+        while !data.is_empty() {
+            if data.read_u64::<LE>()? != Self::PACKAGE_FILE_TAG {
+                return Err(Error::Other("expected package file tag".into()));
+            }
+            let size = data.read_u64::<LE>()?;
+            if size == 0 {
+                return Err(Error::Other("compressed block size of 0".into()));
+            }
+            block_size.get_or_insert(size);
+            let _compressed_size = data.read_u64::<LE>()?;
+            let uncompressed_size = data.read_u64::<LE>()?;
+            limits.check_allocation((gvas.len() as u64).saturating_add(uncompressed_size))?;
+            let mut blocks = vec![];
+            for _ in 0..uncompressed_size.div_ceil(size) {
+                let compressed = data.read_u64::<LE>()?;
+                let uncompressed = data.read_u64::<LE>()?;
+                blocks.push((compressed, uncompressed));
+            }
+            for (compressed, uncompressed) in blocks {
+                let compressed = data.get(..compressed as usize).ok_or_else(|| {
+                    Error::from(std::io::Error::from(std::io::ErrorKind::UnexpectedEof))
+                })?;
+                limits.check_allocation((gvas.len() as u64).saturating_add(uncompressed))?;
+                let block = inflate(compressed, uncompressed)?;
+                if block.len() != uncompressed as usize {
+                    return Err(Error::Other(format!(
+                        "compressed block declares {uncompressed} uncompressed bytes but found {}",
+                        block.len()
+                    )));
+                }
+                gvas.extend(block);
+                data = &data[compressed.len()..];
+            }
+        }
+        let block_size = block_size.ok_or_else(|| Error::Other("empty archive".into()))?;
+        Ok((Container::Chunked(Self { block_size }), gvas))
+    }
+    fn wrap(&self, data: &[u8]) -> TResult<Vec<u8>> {
+        if self.block_size == 0 {
+            return Err(Error::Other("compressed block size of 0".into()));
+        }
+        let blocks = data
+            .chunks(self.block_size as usize)
+            .map(|block| Ok((deflate(block)?, block.len())))
+            .collect::<TResult<Vec<_>>>()?;
+        let compressed_size: usize = blocks.iter().map(|(block, _)| block.len()).sum();
+
+        let mut buf = vec![];
+        buf.write_u64::<LE>(Self::PACKAGE_FILE_TAG)?;
+        buf.write_u64::<LE>(self.block_size)?;
+        buf.write_u64::<LE>(compressed_size as u64)?;
+        buf.write_u64::<LE>(data.len() as u64)?;
+        for (block, uncompressed) in &blocks {
+            buf.write_u64::<LE>(block.len() as u64)?;
+            buf.write_u64::<LE>(*uncompressed as u64)?;
+        }
+        for (block, _) in &blocks {
+            buf.write_all(block)?;
+        }
+        Ok(buf)
+    }
+}
+
+/// GVAS data preceded by its length as a u32
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LengthPrefixedContainer;
+impl SaveContainer for LengthPrefixedContainer {
+    fn detect(&self, data: &[u8]) -> bool {
+        data.get(4..8) == Some(b"GVAS")
+            && u32::try_from(data.len() - 4).is_ok_and(|len| data[..4] == len.to_le_bytes())
+    }
+    fn unwrap(&self, mut data: &[u8], _limits: &Limits) -> TResult<(Container, Vec<u8>)> {
+        let len = data.read_u32::<LE>()?;
+        if len as usize != data.len() {
+            return Err(Error::Other(format!(
+                "length prefix declares {len} bytes but found {}",
+                data.len()
+            )));
+        }
+        Ok((Container::LengthPrefixed(Self), data.to_vec()))
+    }
+    fn wrap(&self, data: &[u8]) -> TResult<Vec<u8>> {
+        let len = u32::try_from(data.len())
+            .map_err(|_| Error::Other(format!("{} bytes exceed the length prefix", data.len())))?;
+        let mut buf = Vec::with_capacity(4 + data.len());
+        buf.write_u32::<LE>(len)?;
+        buf.write_all(data)?;
+        Ok(buf)
+    }
+}
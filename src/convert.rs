@@ -0,0 +1,517 @@
+use crate::{
+    Box, Box2D, Byte, ByteArray, Color, Error, FString, GameplayTag, GameplayTagContainer,
+    IntPoint, IntVector, IntVector2, LinearColor, Matrix, PerPlatformFloat, PerPlatformInt, Plane,
+    Properties, Property, PropertyType, PropertyValue, Quat, Rotator, StructType, StructValue,
+    TResult, UniqueNetIdRepl, ValueArray, ValueVec, Vector, Vector2D, Vector2f, Vector3f, Vector4,
+};
+
+impl<'a> Property<'a> {
+    /// Name of the property type e.g. `IntProperty`
+    pub fn type_name(&self) -> &str {
+        match self {
+            Property::Raw { type_name, .. } => type_name,
+            _ => self.get_type().map_or("", |t| t.get_name()),
+        }
+    }
+    fn mismatch(&self, expected: &str) -> Error {
+        Error::TypeMismatch {
+            expected: expected.to_owned(),
+            found: self.type_name().to_owned(),
+        }
+    }
+    /// Value of a `StrProperty`, `NameProperty`, `ObjectProperty` or `EnumProperty`
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Property::Str { value, .. }
+            | Property::Name { value, .. }
+            | Property::Object { value, .. }
+            | Property::Enum { value, .. } => Some(value.as_str()),
+            _ => None,
+        }
+    }
+    /// Replaces the value of a `StrProperty`, `NameProperty`, `ObjectProperty` or
+    /// `EnumProperty` keeping its tag
+    pub fn set_str(&mut self, new: impl Into<FString<'a>>) -> TResult<()> {
+        match self {
+            Property::Str { value, .. }
+            | Property::Name { value, .. }
+            | Property::Object { value, .. }
+            | Property::Enum { value, .. } => {
+                *value = new.into();
+                Ok(())
+            }
+            _ => Err(self.mismatch("StrProperty")),
+        }
+    }
+    /// Value of a `UInt8Property` or a `ByteProperty` which is not an enum
+    pub fn as_u8(&self) -> Option<u8> {
+        match self {
+            Property::UInt8 { value, .. }
+            | Property::Byte {
+                value: Byte::Byte(value),
+                ..
+            } => Some(*value),
+            _ => None,
+        }
+    }
+    /// Replaces the value of a `UInt8Property` or a `ByteProperty` which is not an enum keeping
+    /// its tag
+    pub fn set_u8(&mut self, new: u8) -> TResult<()> {
+        match self {
+            Property::UInt8 { value, .. }
+            | Property::Byte {
+                value: Byte::Byte(value),
+                ..
+            } => {
+                *value = new;
+                Ok(())
+            }
+            _ => Err(self.mismatch("ByteProperty")),
+        }
+    }
+    pub fn as_struct(&self) -> Option<&StructValue<'a>> {
+        match self {
+            Property::Struct { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_struct_mut(&mut self) -> Option<&mut StructValue<'a>> {
+        match self {
+            Property::Struct { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+    /// Replaces the value of a `StructProperty` of the same kind keeping its tag
+    pub fn set_struct(&mut self, new: StructValue<'a>) -> TResult<()> {
+        match self {
+            Property::Struct { value, .. }
+                if std::mem::discriminant(value) == std::mem::discriminant(&new) =>
+            {
+                *value = new;
+                Ok(())
+            }
+            _ => Err(self.mismatch("StructProperty")),
+        }
+    }
+    /// Elements of an `ArrayProperty` of `T`
+    pub fn as_array<T: ArrayElement<'a>>(&self) -> Option<&[T]> {
+        match self {
+            Property::Array { value, .. } => T::elements(value),
+            _ => None,
+        }
+    }
+    pub fn as_array_mut<T: ArrayElement<'a>>(&mut self) -> Option<&mut Vec<T>> {
+        match self {
+            Property::Array { value, .. } => T::elements_mut(value),
+            _ => None,
+        }
+    }
+    /// Replaces the elements of an `ArrayProperty` of `T` keeping its tag
+    pub fn set_array<T: ArrayElement<'a>>(&mut self, new: Vec<T>) -> TResult<()> {
+        match self.as_array_mut() {
+            Some(value) => {
+                *value = new;
+                Ok(())
+            }
+            None => Err(self.mismatch("ArrayProperty")),
+        }
+    }
+}
+
+impl<'a> PropertyValue<'a> {
+    /// Value of a `StrProperty`, `NameProperty`, `ObjectProperty` or `EnumProperty`
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::Str(value)
+            | PropertyValue::Name(value)
+            | PropertyValue::Object(value)
+            | PropertyValue::Enum(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Value of a `UInt8Property` or a `ByteProperty` which is not an enum
+    pub fn as_u8(&self) -> Option<u8> {
+        match self {
+            PropertyValue::UInt8(value) | PropertyValue::Byte(Byte::Byte(value)) => Some(*value),
+            _ => None,
+        }
+    }
+    pub fn as_struct(&self) -> Option<&StructValue<'a>> {
+        match self {
+            PropertyValue::Struct(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_struct_mut(&mut self) -> Option<&mut StructValue<'a>> {
+        match self {
+            PropertyValue::Struct(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> StructValue<'a> {
+    /// Properties of a user defined struct
+    pub fn as_properties(&self) -> Option<&Properties<'a>> {
+        match self {
+            StructValue::Struct(properties) => Some(properties),
+            _ => None,
+        }
+    }
+    pub fn as_properties_mut(&mut self) -> Option<&mut Properties<'a>> {
+        match self {
+            StructValue::Struct(properties) => Some(properties),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! primitives {
+    ($($t:ty, $variant:ident, $property_type:ident, $as:ident, $set:ident;)*) => {
+        impl Property<'_> {
+            $(
+                #[doc = concat!("Value of a `", stringify!($property_type), "`")]
+                pub fn $as(&self) -> Option<$t> {
+                    match self {
+                        Property::$variant { value, .. } => Some(*value),
+                        _ => None,
+                    }
+                }
+                #[doc = concat!("Replaces the value of a `", stringify!($property_type), "` keeping its tag")]
+                pub fn $set(&mut self, new: $t) -> TResult<()> {
+                    match self {
+                        Property::$variant { value, .. } => {
+                            *value = new;
+                            Ok(())
+                        }
+                        _ => Err(self.mismatch(stringify!($property_type))),
+                    }
+                }
+            )*
+        }
+        impl PropertyValue<'_> {
+            $(
+                #[doc = concat!("Value of a `", stringify!($property_type), "`")]
+                pub fn $as(&self) -> Option<$t> {
+                    match self {
+                        PropertyValue::$variant(value) => Some(*value),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+        $(
+            impl From<$t> for Property<'_> {
+                fn from(value: $t) -> Self {
+                    Property::$variant {
+                        id: None,
+                        value,
+                        complete_type: None,
+                        trailing: vec![],
+                    }
+                }
+            }
+            impl From<$t> for PropertyValue<'_> {
+                fn from(value: $t) -> Self {
+                    PropertyValue::$variant(value)
+                }
+            }
+            impl TryFrom<Property<'_>> for $t {
+                type Error = Error;
+                fn try_from(property: Property) -> TResult<Self> {
+                    property
+                        .$as()
+                        .ok_or_else(|| property.mismatch(stringify!($property_type)))
+                }
+            }
+            impl From<Vec<$t>> for Property<'_> {
+                fn from(value: Vec<$t>) -> Self {
+                    Property::Array {
+                        array_type: PropertyType::$property_type,
+                        id: None,
+                        value: ValueArray::Base(ValueVec::$variant(value)),
+                        complete_type: None,
+                        trailing: vec![],
+                    }
+                }
+            }
+        )*
+    };
+}
+primitives! {
+    i8, Int8, Int8Property, as_i8, set_i8;
+    i16, Int16, Int16Property, as_i16, set_i16;
+    i32, Int, IntProperty, as_i32, set_i32;
+    i64, Int64, Int64Property, as_i64, set_i64;
+    u16, UInt16, UInt16Property, as_u16, set_u16;
+    u32, UInt32, UInt32Property, as_u32, set_u32;
+    u64, UInt64, UInt64Property, as_u64, set_u64;
+    f32, Float, FloatProperty, as_f32, set_f32;
+    f64, Double, DoubleProperty, as_f64, set_f64;
+    bool, Bool, BoolProperty, as_bool, set_bool;
+}
+
+/// Bytes are converted to and from `ByteProperty` which is far more common than `UInt8Property`
+impl From<u8> for Property<'_> {
+    fn from(value: u8) -> Self {
+        Property::Byte {
+            id: None,
+            value: Byte::Byte(value),
+            enum_type: "None".into(),
+            complete_type: None,
+            trailing: vec![],
+        }
+    }
+}
+impl From<u8> for PropertyValue<'_> {
+    fn from(value: u8) -> Self {
+        PropertyValue::Byte(Byte::Byte(value))
+    }
+}
+impl TryFrom<Property<'_>> for u8 {
+    type Error = Error;
+    fn try_from(property: Property) -> TResult<Self> {
+        property
+            .as_u8()
+            .ok_or_else(|| property.mismatch("ByteProperty"))
+    }
+}
+impl From<Vec<u8>> for Property<'_> {
+    fn from(value: Vec<u8>) -> Self {
+        Property::Array {
+            array_type: PropertyType::ByteProperty,
+            id: None,
+            value: ValueArray::Base(ValueVec::Byte(ByteArray::Byte(value.into()))),
+            complete_type: None,
+            trailing: vec![],
+        }
+    }
+}
+
+/// Strings are converted to `StrProperty`
+impl From<String> for Property<'_> {
+    fn from(value: String) -> Self {
+        Property::Str {
+            id: None,
+            value: value.into(),
+            complete_type: None,
+            trailing: vec![],
+        }
+    }
+}
+impl From<&str> for Property<'_> {
+    fn from(value: &str) -> Self {
+        value.to_owned().into()
+    }
+}
+impl From<String> for PropertyValue<'_> {
+    fn from(value: String) -> Self {
+        PropertyValue::Str(value.into())
+    }
+}
+impl From<&str> for PropertyValue<'_> {
+    fn from(value: &str) -> Self {
+        PropertyValue::Str(value.to_owned().into())
+    }
+}
+impl TryFrom<Property<'_>> for String {
+    type Error = Error;
+    fn try_from(property: Property) -> TResult<Self> {
+        match property {
+            Property::Str { value, .. }
+            | Property::Name { value, .. }
+            | Property::Object { value, .. }
+            | Property::Enum { value, .. } => Ok(value.into_string()),
+            property => Err(property.mismatch("StrProperty")),
+        }
+    }
+}
+impl From<Vec<String>> for Property<'_> {
+    fn from(value: Vec<String>) -> Self {
+        Property::Array {
+            array_type: PropertyType::StrProperty,
+            id: None,
+            value: ValueArray::Base(ValueVec::Str(
+                value.into_iter().map(FString::from).collect(),
+            )),
+            complete_type: None,
+            trailing: vec![],
+        }
+    }
+}
+
+impl<'a, T: ArrayElement<'a>> TryFrom<Property<'a>> for Vec<T> {
+    type Error = Error;
+    fn try_from(mut property: Property<'a>) -> TResult<Self> {
+        match property.as_array_mut() {
+            Some(value) => Ok(std::mem::take(value)),
+            None => Err(property.mismatch("ArrayProperty")),
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Type of the elements of an `ArrayProperty`, see [`Property::as_array`]
+pub trait ArrayElement<'a>: sealed::Sealed + Sized {
+    fn elements<'r>(array: &'r ValueArray<'a>) -> Option<&'r [Self]>;
+    fn elements_mut<'r>(array: &'r mut ValueArray<'a>) -> Option<&'r mut Vec<Self>>;
+}
+
+macro_rules! array_elements {
+    ($lt:lifetime; $($t:ty, $value:ident => $pattern:pat,)*) => {
+        $(
+            impl<$lt> sealed::Sealed for $t {}
+            impl<$lt> ArrayElement<$lt> for $t {
+                fn elements<'r>(array: &'r ValueArray<$lt>) -> Option<&'r [Self]> {
+                    match array {
+                        $pattern => Some($value),
+                        #[allow(unreachable_patterns)]
+                        _ => None,
+                    }
+                }
+                fn elements_mut<'r>(array: &'r mut ValueArray<$lt>) -> Option<&'r mut Vec<Self>> {
+                    match array {
+                        $pattern => Some($value),
+                        #[allow(unreachable_patterns)]
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+array_elements! {
+    'a;
+    i8, value => ValueArray::Base(ValueVec::Int8(value)),
+    i16, value => ValueArray::Base(ValueVec::Int16(value)),
+    i32, value => ValueArray::Base(ValueVec::Int(value)),
+    i64, value => ValueArray::Base(ValueVec::Int64(value)),
+    u16, value => ValueArray::Base(ValueVec::UInt16(value)),
+    u32, value => ValueArray::Base(ValueVec::UInt32(value)),
+    u64, value => ValueArray::Base(ValueVec::UInt64(value)),
+    f32, value => ValueArray::Base(ValueVec::Float(value)),
+    f64, value => ValueArray::Base(ValueVec::Double(value)),
+    bool, value => ValueArray::Base(ValueVec::Bool(value)),
+    FString<'a>, value => ValueArray::Base(
+        ValueVec::Str(value)
+            | ValueVec::Name(value)
+            | ValueVec::Object(value)
+            | ValueVec::Enum(value)
+            | ValueVec::Byte(ByteArray::Label(value))
+    ),
+    StructValue<'a>, value => ValueArray::Struct { value, .. },
+}
+
+impl sealed::Sealed for u8 {}
+/// Byte arrays which are borrowed from the save are copied when they are accessed mutably
+impl<'a> ArrayElement<'a> for u8 {
+    fn elements<'r>(array: &'r ValueArray<'a>) -> Option<&'r [Self]> {
+        match array {
+            ValueArray::Base(ValueVec::UInt8(value) | ValueVec::EnumByte(value)) => Some(value),
+            ValueArray::Base(ValueVec::Byte(ByteArray::Byte(value))) => Some(value),
+            _ => None,
+        }
+    }
+    fn elements_mut<'r>(array: &'r mut ValueArray<'a>) -> Option<&'r mut Vec<Self>> {
+        match array {
+            ValueArray::Base(ValueVec::UInt8(value) | ValueVec::EnumByte(value)) => Some(value),
+            ValueArray::Base(ValueVec::Byte(ByteArray::Byte(value))) => Some(value.to_mut()),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! structs {
+    ($a:lifetime; $($t:ident $(<$lt:lifetime>)?, $as:ident, $as_mut:ident;)*) => {
+        impl<$a> StructValue<$a> {
+            /// Name of the struct for errors
+            fn struct_name(&self) -> &'static str {
+                match self {
+                    $(StructValue::$t(_) => stringify!($t),)*
+                    _ => "other struct",
+                }
+            }
+            $(
+                pub fn $as(&self) -> Option<&$t$(<$lt>)?> {
+                    match self {
+                        StructValue::$t(value) => Some(value),
+                        _ => None,
+                    }
+                }
+                pub fn $as_mut(&mut self) -> Option<&mut $t$(<$lt>)?> {
+                    match self {
+                        StructValue::$t(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+        $(
+            impl<$a> From<$t$(<$lt>)?> for StructValue<$a> {
+                fn from(value: $t$(<$lt>)?) -> Self {
+                    StructValue::$t(value)
+                }
+            }
+            impl<$a> From<$t$(<$lt>)?> for Property<$a> {
+                fn from(value: $t$(<$lt>)?) -> Self {
+                    Property::Struct {
+                        id: None,
+                        value: StructValue::$t(value),
+                        struct_type: StructType::$t,
+                        struct_id: uuid::Uuid::nil(),
+                        complete_type: None,
+                        trailing: vec![],
+                    }
+                }
+            }
+            impl<$a> TryFrom<StructValue<$a>> for $t$(<$lt>)? {
+                type Error = Error;
+                fn try_from(value: StructValue<$a>) -> TResult<Self> {
+                    match value {
+                        StructValue::$t(value) => Ok(value),
+                        _ => Err(Error::TypeMismatch {
+                            expected: stringify!($t).to_owned(),
+                            found: value.struct_name().to_owned(),
+                        }),
+                    }
+                }
+            }
+            impl<$a> TryFrom<Property<$a>> for $t$(<$lt>)? {
+                type Error = Error;
+                fn try_from(property: Property<$a>) -> TResult<Self> {
+                    match property {
+                        Property::Struct { value, .. } => value.try_into(),
+                        property => Err(property.mismatch("StructProperty")),
+                    }
+                }
+            }
+        )*
+    };
+}
+structs! {
+    'a;
+    Vector2D, as_vector2d, as_vector2d_mut;
+    Vector, as_vector, as_vector_mut;
+    IntVector, as_int_vector, as_int_vector_mut;
+    Box, as_box, as_box_mut;
+    IntPoint, as_int_point, as_int_point_mut;
+    Quat, as_quat, as_quat_mut;
+    LinearColor, as_linear_color, as_linear_color_mut;
+    Color, as_color, as_color_mut;
+    Rotator, as_rotator, as_rotator_mut;
+    GameplayTagContainer<'a>, as_gameplay_tag_container, as_gameplay_tag_container_mut;
+    Vector4, as_vector4, as_vector4_mut;
+    Plane, as_plane, as_plane_mut;
+    Matrix, as_matrix, as_matrix_mut;
+    Box2D, as_box2d, as_box2d_mut;
+    IntVector2, as_int_vector2, as_int_vector2_mut;
+    Vector3f, as_vector3f, as_vector3f_mut;
+    Vector2f, as_vector2f, as_vector2f_mut;
+    UniqueNetIdRepl<'a>, as_unique_net_id_repl, as_unique_net_id_repl_mut;
+    PerPlatformFloat, as_per_platform_float, as_per_platform_float_mut;
+    PerPlatformInt, as_per_platform_int, as_per_platform_int_mut;
+    GameplayTag<'a>, as_gameplay_tag, as_gameplay_tag_mut;
+}
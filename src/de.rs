@@ -0,0 +1,442 @@
+use indexmap::IndexMap;
+use serde::de::{
+    value::StrDeserializer, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::{forward_to_deserialize_any, Deserialize, Serialize};
+
+use crate::{
+    Byte, ByteArray, Error, MapEntry, Properties, Property, PropertyValue, StructValue, TResult,
+    ValueArray, ValueRef, ValueSet, ValueVec,
+};
+
+impl serde::de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::Other(msg.to_string())
+    }
+}
+
+/// Options for [`from_properties_with_options`]
+#[derive(Debug, Default, Clone)]
+pub struct FromPropertiesOptions {
+    /// Match properties of Blueprint structs such as `Health_7_B8CD81CD4E138D8E06FBBA8056FE4C85`
+    /// by their name without the suffix (`Health`)
+    pub ignore_guid_suffixes: bool,
+}
+
+/// Deserializes `T` from `properties` whose names are matched against the field names of `T`.
+///
+/// Arrays and sets map to sequences (e.g. `Vec` or `HashSet`), maps to maps (e.g. `HashMap`)
+/// and enum values such as `EColor::Red` to unit variants of Rust enums named after the last
+/// segment (`Red`). Properties which share a name but differ in their array index map to a
+/// sequence ordered by index. Other values are deserialized from their serde representation.
+pub fn from_properties<'de, T: Deserialize<'de>>(properties: &'de Properties<'de>) -> TResult<T> {
+    from_properties_with_options(properties, &FromPropertiesOptions::default())
+}
+
+/// Deserializes `T` from `properties` using the provided [`FromPropertiesOptions`], see
+/// [`from_properties`]
+pub fn from_properties_with_options<'de, T: Deserialize<'de>>(
+    properties: &'de Properties,
+    options: &FromPropertiesOptions,
+) -> TResult<T> {
+    T::deserialize(Deserializer {
+        node: Node::Properties(properties),
+        options,
+    })
+}
+
+/// Strips the `_<id>_<GUID>` Blueprint editors append to the names of struct members
+fn strip_guid_suffix(name: &str) -> &str {
+    let Some((rest, guid)) = name.rsplit_once('_') else {
+        return name;
+    };
+    let Some((stripped, id)) = rest.rsplit_once('_') else {
+        return name;
+    };
+    let is_guid = guid.len() == 32 && guid.bytes().all(|b| b.is_ascii_hexdigit());
+    let is_id = !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit());
+    if is_guid && is_id && !stripped.is_empty() {
+        stripped
+    } else {
+        name
+    }
+}
+
+/// Strips the enum type from enum values e.g. `EColor::Red`
+fn variant_name(value: &str) -> &str {
+    value.rsplit("::").next().unwrap_or(value)
+}
+
+enum Node<'de> {
+    Properties(&'de Properties<'de>),
+    /// Properties sharing a name ordered by array index
+    Group(Vec<&'de Property<'de>>),
+    Value(ValueRef<'de>),
+}
+
+struct Deserializer<'de, 'o> {
+    node: Node<'de>,
+    options: &'o FromPropertiesOptions,
+}
+impl<'de, 'o> Deserializer<'de, 'o> {
+    fn new(value: ValueRef<'de>, options: &'o FromPropertiesOptions) -> Self {
+        Self {
+            node: Node::Value(value),
+            options,
+        }
+    }
+    /// Name of the enum value if the node is one
+    fn enum_value(&self) -> Option<&'de str> {
+        match &self.node {
+            Node::Value(ValueRef::Property(Property::Enum { value, .. }))
+            | Node::Value(ValueRef::Property(Property::Byte {
+                value: Byte::Label(value),
+                ..
+            }))
+            | Node::Value(ValueRef::Value(PropertyValue::Enum(value)))
+            | Node::Value(ValueRef::Value(PropertyValue::Byte(Byte::Label(value)))) => Some(value),
+            Node::Value(ValueRef::Element(
+                ValueVec::Enum(values) | ValueVec::Byte(ByteArray::Label(values)),
+                index,
+            )) => Some(&values[*index]),
+            _ => None,
+        }
+    }
+}
+
+/// Deserializes a value which has no direct equivalent from its serde representation
+fn bridge<'de, V: Visitor<'de>, T: Serialize>(value: &T, visitor: V) -> TResult<V::Value> {
+    let value = serde_json::to_value(value).map_err(<Error as serde::de::Error>::custom)?;
+    serde::Deserializer::deserialize_any(value, visitor)
+        .map_err(<Error as serde::de::Error>::custom)
+}
+
+fn visit_struct<'de, V: Visitor<'de>>(
+    value: &'de StructValue,
+    options: &FromPropertiesOptions,
+    visitor: V,
+) -> TResult<V::Value> {
+    match value {
+        StructValue::Struct(properties) => {
+            visitor.visit_map(PropertiesAccess::new(properties, options))
+        }
+        value => {
+            // the representation of StructValue is tagged with the struct type
+            let value =
+                match serde_json::to_value(value).map_err(<Error as serde::de::Error>::custom)? {
+                    serde_json::Value::Object(map) if map.len() == 1 => {
+                        map.into_iter().next().unwrap().1
+                    }
+                    value => value,
+                };
+            serde::Deserializer::deserialize_any(value, visitor)
+                .map_err(<Error as serde::de::Error>::custom)
+        }
+    }
+}
+
+fn visit_property<'de, V: Visitor<'de>>(
+    property: &'de Property,
+    options: &FromPropertiesOptions,
+    visitor: V,
+) -> TResult<V::Value> {
+    match property {
+        Property::Int8 { value, .. } => visitor.visit_i8(*value),
+        Property::Int16 { value, .. } => visitor.visit_i16(*value),
+        Property::Int { value, .. } => visitor.visit_i32(*value),
+        Property::Int64 { value, .. } => visitor.visit_i64(*value),
+        Property::UInt8 { value, .. } => visitor.visit_u8(*value),
+        Property::UInt16 { value, .. } => visitor.visit_u16(*value),
+        Property::UInt32 { value, .. } => visitor.visit_u32(*value),
+        Property::UInt64 { value, .. } => visitor.visit_u64(*value),
+        Property::Float { value, .. } => visitor.visit_f32(*value),
+        Property::Double { value, .. } => visitor.visit_f64(*value),
+        Property::Bool { value, .. } => visitor.visit_bool(*value),
+        Property::Byte { value, .. } => match value {
+            Byte::Byte(value) => visitor.visit_u8(*value),
+            Byte::Label(value) => visitor.visit_borrowed_str(value),
+        },
+        Property::Enum { value, .. }
+        | Property::Str { value, .. }
+        | Property::Name { value, .. }
+        | Property::Object { value, .. } => visitor.visit_borrowed_str(value),
+        Property::SoftObject { value, .. } => visitor.visit_borrowed_str(value),
+        Property::FieldPath { value, .. } => bridge(value, visitor),
+        Property::Text { value, .. } => bridge(value, visitor),
+        Property::Delegate { value, .. } => bridge(value, visitor),
+        Property::MulticastDelegate { value, .. } => bridge(value, visitor),
+        Property::MulticastInlineDelegate { value, .. } => bridge(value, visitor),
+        Property::MulticastSparseDelegate { value, .. } => bridge(value, visitor),
+        Property::Struct { value, .. } => visit_struct(value, options, visitor),
+        Property::Array { value, .. } => match value {
+            ValueArray::Base(vec) => visit_elements(vec, options, visitor),
+            ValueArray::Struct { value, .. } => visit_structs(value, options, visitor),
+        },
+        Property::Set { value, .. } => match value {
+            ValueSet::Base(vec) => visit_elements(vec, options, visitor),
+            ValueSet::Struct(value) => visit_structs(value, options, visitor),
+        },
+        Property::Map { value, .. } => visitor.visit_map(EntriesAccess {
+            entries: value.iter(),
+            value: None,
+            options,
+        }),
+        Property::Raw { type_name, .. } => Err(Error::Other(format!(
+            "cannot deserialize {type_name} which could not be decoded"
+        ))),
+    }
+}
+
+fn visit_value<'de, V: Visitor<'de>>(
+    value: &'de PropertyValue,
+    options: &FromPropertiesOptions,
+    visitor: V,
+) -> TResult<V::Value> {
+    match value {
+        PropertyValue::Int(value) => visitor.visit_i32(*value),
+        PropertyValue::Int8(value) => visitor.visit_i8(*value),
+        PropertyValue::Int16(value) => visitor.visit_i16(*value),
+        PropertyValue::Int64(value) => visitor.visit_i64(*value),
+        PropertyValue::UInt8(value) => visitor.visit_u8(*value),
+        PropertyValue::UInt16(value) => visitor.visit_u16(*value),
+        PropertyValue::UInt32(value) => visitor.visit_u32(*value),
+        PropertyValue::UInt64(value) => visitor.visit_u64(*value),
+        PropertyValue::Float(value) => visitor.visit_f32(*value),
+        PropertyValue::Double(value) => visitor.visit_f64(*value),
+        PropertyValue::Bool(value) => visitor.visit_bool(*value),
+        PropertyValue::Byte(Byte::Byte(value)) => visitor.visit_u8(*value),
+        PropertyValue::Byte(Byte::Label(value))
+        | PropertyValue::Enum(value)
+        | PropertyValue::Name(value)
+        | PropertyValue::Str(value)
+        | PropertyValue::Object(value) => visitor.visit_borrowed_str(value),
+        PropertyValue::SoftObject(value, _) | PropertyValue::SoftObjectPath(value, _, _) => {
+            visitor.visit_borrowed_str(value)
+        }
+        PropertyValue::Text(value) => bridge(value, visitor),
+        PropertyValue::FieldPath(value) => bridge(value, visitor),
+        PropertyValue::Delegate(value) => bridge(value, visitor),
+        PropertyValue::MulticastDelegate(value) => bridge(value, visitor),
+        PropertyValue::MulticastInlineDelegate(value) => bridge(value, visitor),
+        PropertyValue::MulticastSparseDelegate(value) => bridge(value, visitor),
+        PropertyValue::Struct(value) => visit_struct(value, options, visitor),
+    }
+}
+
+fn visit_element<'de, V: Visitor<'de>>(
+    vec: &'de ValueVec,
+    index: usize,
+    visitor: V,
+) -> TResult<V::Value> {
+    match vec {
+        ValueVec::Int8(v) => visitor.visit_i8(v[index]),
+        ValueVec::Int16(v) => visitor.visit_i16(v[index]),
+        ValueVec::Int(v) => visitor.visit_i32(v[index]),
+        ValueVec::Int64(v) => visitor.visit_i64(v[index]),
+        ValueVec::UInt8(v) | ValueVec::EnumByte(v) => visitor.visit_u8(v[index]),
+        ValueVec::Byte(ByteArray::Byte(v)) => visitor.visit_u8(v[index]),
+        ValueVec::UInt16(v) => visitor.visit_u16(v[index]),
+        ValueVec::UInt32(v) => visitor.visit_u32(v[index]),
+        ValueVec::UInt64(v) => visitor.visit_u64(v[index]),
+        ValueVec::Float(v) => visitor.visit_f32(v[index]),
+        ValueVec::Double(v) => visitor.visit_f64(v[index]),
+        ValueVec::Bool(v) => visitor.visit_bool(v[index]),
+        ValueVec::Byte(ByteArray::Label(v))
+        | ValueVec::Enum(v)
+        | ValueVec::Str(v)
+        | ValueVec::Name(v)
+        | ValueVec::Object(v) => visitor.visit_borrowed_str(&v[index]),
+        ValueVec::SoftObject(v) => visitor.visit_borrowed_str(&v[index].0),
+        ValueVec::Text(v) => bridge(&v[index], visitor),
+        ValueVec::FieldPath(v) => bridge(&v[index], visitor),
+        ValueVec::Delegate(v) => bridge(&v[index], visitor),
+        ValueVec::MulticastDelegate(v) => bridge(&v[index], visitor),
+        ValueVec::MulticastInlineDelegate(v) => bridge(&v[index], visitor),
+        ValueVec::MulticastSparseDelegate(v) => bridge(&v[index], visitor),
+        ValueVec::Box(v) => bridge(&v[index], visitor),
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for Deserializer<'de, '_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> TResult<V::Value> {
+        match self.node {
+            Node::Properties(properties) => {
+                visitor.visit_map(PropertiesAccess::new(properties, self.options))
+            }
+            Node::Group(properties) => visitor.visit_seq(SeqAccessor {
+                elements: properties.into_iter().map(ValueRef::Property),
+                options: self.options,
+            }),
+            Node::Value(ValueRef::Property(property)) => {
+                visit_property(property, self.options, visitor)
+            }
+            Node::Value(ValueRef::Struct(value)) => visit_struct(value, self.options, visitor),
+            Node::Value(ValueRef::Value(value)) => visit_value(value, self.options, visitor),
+            Node::Value(ValueRef::Element(vec, index)) => visit_element(vec, index, visitor),
+        }
+    }
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> TResult<V::Value> {
+        visitor.visit_some(self)
+    }
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> TResult<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> TResult<V::Value> {
+        match self.enum_value() {
+            Some(value) => {
+                let variant: StrDeserializer<Error> = variant_name(value).into_deserializer();
+                visitor.visit_enum(variant)
+            }
+            None => self.deserialize_any(visitor),
+        }
+    }
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> TResult<V::Value> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+/// Properties grouped by name
+struct PropertiesAccess<'de, 'o> {
+    properties: indexmap::map::IntoIter<&'de str, Vec<&'de Property<'de>>>,
+    value: Option<Vec<&'de Property<'de>>>,
+    options: &'o FromPropertiesOptions,
+}
+impl<'de, 'o> PropertiesAccess<'de, 'o> {
+    fn new(properties: &'de Properties, options: &'o FromPropertiesOptions) -> Self {
+        let mut grouped: IndexMap<&str, Vec<(u32, &Property)>> = IndexMap::new();
+        for (key, property) in &properties.0 {
+            let name = match options.ignore_guid_suffixes {
+                true => strip_guid_suffix(&key.1),
+                false => &key.1,
+            };
+            grouped.entry(name).or_default().push((key.0, property));
+        }
+        let grouped = grouped
+            .into_iter()
+            .map(|(name, mut group)| {
+                group.sort_by_key(|(index, _)| *index);
+                (name, group.into_iter().map(|(_, p)| p).collect())
+            })
+            .collect::<IndexMap<_, _>>();
+        Self {
+            properties: grouped.into_iter(),
+            value: None,
+            options,
+        }
+    }
+}
+impl<'de> MapAccess<'de> for PropertiesAccess<'de, '_> {
+    type Error = Error;
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> TResult<Option<K::Value>> {
+        let Some((name, group)) = self.properties.next() else {
+            return Ok(None);
+        };
+        self.value = Some(group);
+        let name: serde::de::value::BorrowedStrDeserializer<Error> =
+            serde::de::value::BorrowedStrDeserializer::new(name);
+        seed.deserialize(name).map(Some)
+    }
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> TResult<V::Value> {
+        let group = self
+            .value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        let node = match group[..] {
+            [property] => Node::Value(ValueRef::Property(property)),
+            _ => Node::Group(group),
+        };
+        seed.deserialize(Deserializer {
+            node,
+            options: self.options,
+        })
+    }
+}
+
+fn visit_elements<'de, V: Visitor<'de>>(
+    vec: &'de ValueVec,
+    options: &FromPropertiesOptions,
+    visitor: V,
+) -> TResult<V::Value> {
+    visitor.visit_seq(SeqAccessor {
+        elements: (0..vec.len()).map(|index| ValueRef::Element(vec, index)),
+        options,
+    })
+}
+
+fn visit_structs<'de, V: Visitor<'de>>(
+    values: &'de [StructValue],
+    options: &FromPropertiesOptions,
+    visitor: V,
+) -> TResult<V::Value> {
+    visitor.visit_seq(SeqAccessor {
+        elements: values.iter().map(ValueRef::Struct),
+        options,
+    })
+}
+
+/// Elements of an array or set
+struct SeqAccessor<'o, I> {
+    elements: I,
+    options: &'o FromPropertiesOptions,
+}
+impl<'de, I> SeqAccess<'de> for SeqAccessor<'_, I>
+where
+    I: ExactSizeIterator<Item = ValueRef<'de>>,
+{
+    type Error = Error;
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> TResult<Option<T::Value>> {
+        match self.elements.next() {
+            Some(element) => seed
+                .deserialize(Deserializer::new(element, self.options))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+/// Entries of a map
+struct EntriesAccess<'de, 'o> {
+    entries: std::slice::Iter<'de, MapEntry<'de>>,
+    value: Option<&'de PropertyValue<'de>>,
+    options: &'o FromPropertiesOptions,
+}
+impl<'de> MapAccess<'de> for EntriesAccess<'de, '_> {
+    type Error = Error;
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> TResult<Option<K::Value>> {
+        let Some(entry) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.value = Some(&entry.value);
+        seed.deserialize(Deserializer::new(ValueRef::Value(&entry.key), self.options))
+            .map(Some)
+    }
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> TResult<V::Value> {
+        let value = self
+            .value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        seed.deserialize(Deserializer::new(ValueRef::Value(value), self.options))
+    }
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
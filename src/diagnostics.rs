@@ -0,0 +1,85 @@
+/// Kind of a [`Diagnostic`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// Magic of the header is not "GVAS"
+    NonStandardMagic,
+    /// Struct type of a container element was not specified in [`crate::Types`] and had to be
+    /// inferred
+    InferredStructType,
+    /// Data following the root properties which was not parsed
+    TrailingData,
+    /// Property whose value did not take up exactly the size declared by its tag
+    SizeMismatch,
+    /// String which was not valid UTF-8 or UTF-16 and was decoded lossily
+    LossyString,
+    /// Property which could not be decoded and was kept as [`crate::Property::Raw`]
+    RawFallback,
+    /// Checksum stored alongside the save which does not match it
+    ChecksumMismatch,
+}
+
+/// Non-fatal issue encountered while reading a save
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// Path of the property being read including indices of container elements
+    pub path: String,
+    pub offset: usize,
+    pub message: String,
+}
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "offset {}: ", self.offset)?;
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+type Callback = Box<dyn FnMut(&Diagnostic)>;
+
+/// Collects [`Diagnostic`]s and optionally passes each to a callback as soon as it is reported
+#[derive(Default)]
+pub struct Diagnostics {
+    diagnostics: Vec<Diagnostic>,
+    callback: Option<Callback>,
+}
+impl Diagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_callback(callback: impl FnMut(&Diagnostic) + 'static) -> Self {
+        Self {
+            diagnostics: vec![],
+            callback: Some(Box::new(callback)),
+        }
+    }
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        if let Some(callback) = &mut self.callback {
+            callback(&diagnostic);
+        }
+        self.diagnostics.push(diagnostic);
+    }
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.diagnostics.len()
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, Diagnostic> {
+        self.diagnostics.iter()
+    }
+}
+impl std::fmt::Debug for Diagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(&self.diagnostics).finish()
+    }
+}
+impl<'a> IntoIterator for &'a Diagnostics {
+    type Item = &'a Diagnostic;
+    type IntoIter = std::slice::Iter<'a, Diagnostic>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.diagnostics.iter()
+    }
+}
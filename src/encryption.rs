@@ -0,0 +1,176 @@
+use aes::cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, KeyInit};
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, TResult};
+
+const BLOCK: usize = 16;
+
+/// 128, 192 or 256 bit AES key
+#[derive(Clone, PartialEq, Eq)]
+pub struct AesKey(Vec<u8>);
+impl AesKey {
+    pub fn new(key: &[u8]) -> TResult<Self> {
+        match key.len() {
+            16 | 24 | 32 => Ok(Self(key.to_vec())),
+            len => Err(Error::Other(format!(
+                "AES key must be 16, 24 or 32 bytes, found {len}"
+            ))),
+        }
+    }
+    fn cipher(&self) -> Cipher {
+        match self.0.len() {
+            16 => Cipher::Aes128(aes::Aes128::new(GenericArray::from_slice(&self.0))),
+            24 => Cipher::Aes192(aes::Aes192::new(GenericArray::from_slice(&self.0))),
+            _ => Cipher::Aes256(aes::Aes256::new(GenericArray::from_slice(&self.0))),
+        }
+    }
+}
+impl std::str::FromStr for AesKey {
+    type Err = Error;
+    /// Parses a hex encoded key with an optional `0x` prefix
+    fn from_str(s: &str) -> TResult<Self> {
+        let s = s.trim();
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        Self::new(&hex::decode(s).map_err(|e| Error::Other(format!("invalid AES key: {e}")))?)
+    }
+}
+impl std::fmt::Debug for AesKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AesKey({} bits)", self.0.len() * 8)
+    }
+}
+
+enum Cipher {
+    Aes128(aes::Aes128),
+    Aes192(aes::Aes192),
+    Aes256(aes::Aes256),
+}
+impl Cipher {
+    fn encrypt(&self, block: &mut [u8]) {
+        let block = GenericArray::from_mut_slice(block);
+        match self {
+            Self::Aes128(c) => c.encrypt_block(block),
+            Self::Aes192(c) => c.encrypt_block(block),
+            Self::Aes256(c) => c.encrypt_block(block),
+        }
+    }
+    fn decrypt(&self, block: &mut [u8]) {
+        let block = GenericArray::from_mut_slice(block);
+        match self {
+            Self::Aes128(c) => c.decrypt_block(block),
+            Self::Aes192(c) => c.decrypt_block(block),
+            Self::Aes256(c) => c.decrypt_block(block),
+        }
+    }
+}
+
+/// AES block cipher mode of operation
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AesMode {
+    #[default]
+    Ecb,
+    Cbc {
+        #[serde(with = "hex")]
+        iv: [u8; BLOCK],
+    },
+}
+
+/// Padding of the plain text to a multiple of the AES block size. It cannot be detected reliably
+/// and has to be known along with the key.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AesPadding {
+    /// Padded with zeros. They cannot be told apart from zeros ending the save so as many of
+    /// them as the save allows are stripped and the rest are restored by padding on write.
+    #[default]
+    Zero,
+    Pkcs7,
+}
+impl AesPadding {
+    /// Length of the zero padding ending `data` which is at most one byte less than a block
+    pub(crate) fn zero_padding(data: &[u8]) -> usize {
+        data.iter()
+            .rev()
+            .take(BLOCK - 1)
+            .take_while(|&&b| b == 0)
+            .count()
+    }
+}
+
+/// AES encryption of a save. The key is not part of it and has to be supplied separately via
+/// [`crate::ReadOptions`] and [`crate::WriteOptions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Encryption {
+    pub mode: AesMode,
+    pub padding: AesPadding,
+}
+impl Encryption {
+    /// Decrypts `data` and strips PKCS#7 padding. Zero padding is left to the caller which
+    /// knows where the save ends.
+    pub fn decrypt(&self, key: &AesKey, data: &[u8]) -> TResult<Vec<u8>> {
+        if !data.len().is_multiple_of(BLOCK) {
+            return Err(Error::Other(format!(
+                "AES encrypted data must be a multiple of {BLOCK} bytes, found {}",
+                data.len()
+            )));
+        }
+        let cipher = key.cipher();
+        let mut plain = data.to_vec();
+        let mut previous = match self.mode {
+            AesMode::Ecb => None,
+            AesMode::Cbc { iv } => Some(iv),
+        };
+        for block in plain.chunks_exact_mut(BLOCK) {
+            let encrypted: [u8; BLOCK] = block.try_into().unwrap();
+            cipher.decrypt(block);
+            if let Some(previous) = &mut previous {
+                for (b, p) in block.iter_mut().zip(previous.iter()) {
+                    *b ^= p;
+                }
+                *previous = encrypted;
+            }
+        }
+        if self.padding == AesPadding::Pkcs7 {
+            match plain.last() {
+                Some(&n)
+                    if (1..=BLOCK as u8).contains(&n)
+                        && plain[plain.len() - n as usize..].iter().all(|&b| b == n) =>
+                {
+                    plain.truncate(plain.len() - n as usize);
+                }
+                _ => {
+                    return Err(Error::Other(
+                        "invalid PKCS#7 padding, check the AES key, mode and padding".into(),
+                    ))
+                }
+            }
+        }
+        Ok(plain)
+    }
+    pub fn encrypt(&self, key: &AesKey, data: &[u8]) -> TResult<Vec<u8>> {
+        let mut buf = data.to_vec();
+        match self.padding {
+            AesPadding::Zero => buf.resize(data.len().next_multiple_of(BLOCK), 0),
+            AesPadding::Pkcs7 => {
+                let n = BLOCK - data.len() % BLOCK;
+                buf.resize(data.len() + n, n as u8);
+            }
+        }
+        let cipher = key.cipher();
+        let mut previous = match self.mode {
+            AesMode::Ecb => None,
+            AesMode::Cbc { iv } => Some(iv),
+        };
+        for block in buf.chunks_exact_mut(BLOCK) {
+            if let Some(previous) = &previous {
+                for (b, p) in block.iter_mut().zip(previous.iter()) {
+                    *b ^= p;
+                }
+            }
+            cipher.encrypt(block);
+            if let Some(previous) = &mut previous {
+                previous.copy_from_slice(block);
+            }
+        }
+        Ok(buf)
+    }
+}
@@ -1,3 +1,7 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::{Limit, StructType};
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("bad magic value (GVAS)")]
@@ -8,15 +12,119 @@ pub enum Error {
     UnknownPropertyMeta(String),
     #[error("unknown vec type: {0}")]
     UnknownVecType(String),
+    #[error("unable to infer StructType for \"{0}\", specify it with a type hint")]
+    UninferredStructType(String),
+    #[error(
+        "value is {actual} bytes but the tag declares {expected}{}",
+        .struct_type.as_ref().map(|t| format!(" (read as struct type {t:?})")).unwrap_or_default()
+    )]
+    SizeMismatch {
+        expected: u32,
+        actual: u64,
+        /// Struct type the value was read as, if any
+        struct_type: Option<StructType>,
+    },
+    #[error("{limit} of {value} exceeds the limit of {max}")]
+    LimitExceeded { limit: Limit, value: u64, max: u64 },
+    #[error("expected {expected}, found {found}")]
+    TypeMismatch { expected: String, found: String },
     #[error("io error: {0}")]
     Io(#[from] std::io::Error),
     #[error("{0}")]
     Other(String),
+    /// Error which occurred while reading the value of a property
+    #[error("{context}: {error}")]
+    Property {
+        context: Box<PropertyContext>,
+        error: Box<Error>,
+    },
+}
+
+/// Property being read when an error occurred
+#[derive(Debug)]
+pub struct PropertyContext {
+    /// Path of the property including indices of container elements e.g. `.PropList[12].Rotation`
+    pub path: String,
+    pub property_type: String,
+    /// Size of the value as declared by the property tag
+    pub size: u32,
+    /// Offset of the start of the property tag
+    pub offset: usize,
+}
+impl std::fmt::Display for PropertyContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({}, size {}, tag at offset {})",
+            self.path, self.property_type, self.size, self.offset
+        )
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
-#[error("at offset {offset}: {error}")]
 pub struct ParseError {
     pub offset: usize,
     pub error: Error,
+    /// Innermost property being read when the error occurred
+    pub property: Option<Box<PropertyContext>>,
+    /// Bytes surrounding `offset` starting at `window_offset`
+    pub window: Vec<u8>,
+    pub window_offset: usize,
+}
+impl ParseError {
+    pub(crate) const WINDOW: usize = 16;
+
+    pub(crate) fn new(error: Error, offset: usize, data: &[u8]) -> Self {
+        let window_offset = offset.min(data.len()).saturating_sub(Self::WINDOW);
+        let window_end = (offset + Self::WINDOW).min(data.len());
+        let window = data[window_offset..window_end].to_vec();
+        Self::with_window(error, offset, window_offset, window)
+    }
+    /// Error at the position of `stream` with the window read by seeking back. The window is
+    /// left empty if that is not possible.
+    pub(crate) fn from_stream<S: Read + Seek>(error: Error, stream: &mut S) -> Self {
+        let offset = stream.stream_position().unwrap_or_default();
+        let mut window_offset = offset.saturating_sub(Self::WINDOW as u64);
+        let mut window = vec![];
+        let len = offset - window_offset + Self::WINDOW as u64;
+        if stream.seek(SeekFrom::Start(window_offset)).is_err()
+            || stream.by_ref().take(len).read_to_end(&mut window).is_err()
+        {
+            window_offset = offset;
+            window.clear();
+        }
+        Self::with_window(error, offset as usize, window_offset as usize, window)
+    }
+    fn with_window(error: Error, offset: usize, window_offset: usize, window: Vec<u8>) -> Self {
+        let (property, error) = match error {
+            Error::Property { context, error } => (Some(context), *error),
+            error => (None, error),
+        };
+        Self {
+            offset,
+            error,
+            property,
+            window,
+            window_offset,
+        }
+    }
+}
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "at offset {}: {}", self.offset, self.error)?;
+        if let Some(property) = &self.property {
+            write!(f, "\n  while reading {property}")?;
+        }
+        if !self.window.is_empty() {
+            write!(f, "\n  {:08x}:", self.window_offset)?;
+            for (i, b) in self.window.iter().enumerate() {
+                if self.window_offset + i == self.offset {
+                    write!(f, " [{b:02x}]")?;
+                } else {
+                    write!(f, " {b:02x}")?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
@@ -27,11 +27,49 @@ match save.root.properties["NumberOfGamesPlayed"] {
 ```
 */
 
+mod builder;
+mod checksum;
+mod container;
+mod convert;
+mod de;
+mod diagnostics;
+mod encryption;
 mod error;
+mod limits;
+mod options;
+mod patch;
+mod path;
+mod seek;
+mod ser;
+mod stream;
+mod strings;
 
-pub use error::{Error, ParseError};
+pub use builder::{EngineVersion, SaveBuilder};
+pub use checksum::{Checksum, ChecksumAlgorithm, ChecksumPosition};
+pub use container::{
+    ChunkedContainer, Container, DynContainer, LengthPrefixedContainer, PalworldContainer,
+    SaveContainer,
+};
+pub use convert::ArrayElement;
+pub use de::{from_properties, from_properties_with_options, FromPropertiesOptions};
+pub use diagnostics::{Diagnostic, DiagnosticKind, Diagnostics};
+pub use encryption::{AesKey, AesMode, AesPadding, Encryption};
+pub use error::{Error, ParseError, PropertyContext};
+use limits::Usage;
+pub use limits::{Limit, Limits};
+pub use options::{ReadOptions, StringEncoding, WriteOptions};
+pub use patch::Patch;
+use patch::Spans;
+use path::Path;
+pub use path::{ValueMut, ValueRef};
+use seek::{Input, SaveStream, SeekReader};
+pub use ser::{to_properties, to_properties_with_hints, TypeHint, TypeHints};
+pub use stream::{PropertyEvent, PropertyReader};
+pub use strings::{FString, StringFormat};
 
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::io::{Read, Seek, Write};
 
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
@@ -47,36 +85,6 @@ trait Writable<W> {
     fn write(&self, writer: &mut Context<W>) -> TResult<()>;
 }
 
-struct SeekReader<R: Read> {
-    reader: R,
-    read_bytes: usize,
-}
-
-impl<R: Read> SeekReader<R> {
-    fn new(reader: R) -> Self {
-        Self {
-            reader,
-            read_bytes: 0,
-        }
-    }
-}
-impl<R: Read> Seek for SeekReader<R> {
-    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
-        match pos {
-            std::io::SeekFrom::Current(0) => Ok(self.read_bytes as u64),
-            _ => unimplemented!(),
-        }
-    }
-}
-impl<R: Read> Read for SeekReader<R> {
-    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        self.reader.read(buf).map(|s| {
-            self.read_bytes += s;
-            s
-        })
-    }
-}
-
 fn read_optional_uuid<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Option<uuid::Uuid>> {
     Ok(if reader.read_u8()? > 0 {
         Some(uuid::Uuid::read(reader)?)
@@ -94,21 +102,110 @@ fn write_optional_uuid<W: Write>(writer: &mut Context<W>, id: Option<uuid::Uuid>
     Ok(())
 }
 
-fn read_string<R: Read + Seek>(reader: &mut Context<R>) -> TResult<String> {
+/// Capacity reserved up front for single byte strings which covers nearly all names
+const STRING_CAPACITY: usize = 256;
+/// Maximum length of an FName in characters including the null terminator
+const NAME_SIZE: u64 = 1024;
+
+fn read_string<'d, R: Input<'d>>(reader: &mut Context<R>) -> TResult<FString<'d>> {
+    let offset = reader.stream_position()?;
     let len = reader.read_i32::<LE>()?;
+    reader.string_length(len.unsigned_abs() as u64 * if len < 0 { 2 } else { 1 })?;
     if len < 0 {
-        let chars = read_array((-len) as u32, reader, |r| Ok(r.read_u16::<LE>()?))?;
+        let chars = (0..len.unsigned_abs())
+            .map(|_| reader.read_u16::<LE>())
+            .collect::<Result<Vec<_>, _>>()?;
+        let length = chars.iter().position(|&c| c == 0).unwrap_or(chars.len());
+        let string = reader.decode_string(
+            offset,
+            &chars[..length],
+            String::from_utf16,
+            String::from_utf16_lossy,
+        )?;
+        let trailing: Vec<u8> = chars[length..]
+            .iter()
+            .flat_map(|c| c.to_le_bytes())
+            .collect();
+        Ok(FString::read(string.into(), true, &trailing))
+    } else if let Some(chars) = reader.stream.lend(len as usize) {
+        // borrow the string from the input if it is valid UTF-8
         let length = chars.iter().position(|&c| c == 0).unwrap_or(chars.len());
-        Ok(String::from_utf16(&chars[..length]).unwrap())
+        let string = match std::str::from_utf8(&chars[..length]) {
+            Ok(string) => Cow::Borrowed(string),
+            Err(_) => reader
+                .decode_string(
+                    offset,
+                    &chars[..length],
+                    |c| std::str::from_utf8(c).map(str::to_owned),
+                    |c| String::from_utf8_lossy(c).into_owned(),
+                )?
+                .into(),
+        };
+        Ok(FString::read(string, false, &chars[length..]))
     } else {
-        let mut chars = vec![0; len as usize];
-        reader.read_exact(&mut chars)?;
+        // read incrementally so a bogus length fails at the end of the stream instead of
+        // allocating up front
+        let mut chars = Vec::with_capacity((len as usize).min(STRING_CAPACITY));
+        reader.take(len as u64).read_to_end(&mut chars)?;
+        if chars.len() != len as usize {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
         let length = chars.iter().position(|&c| c == 0).unwrap_or(chars.len());
-        Ok(String::from_utf8_lossy(&chars[..length]).into_owned())
+        // only the uncommon case of anything but a single terminator requires a copy
+        let trailing = (chars[length..] != [0]).then(|| chars.split_off(length));
+        chars.truncate(length);
+        // reuse the buffer for the common case of valid UTF-8
+        let string = match String::from_utf8(chars) {
+            Ok(string) => string,
+            Err(e) => reader.decode_string(
+                offset,
+                e.as_bytes(),
+                |c| std::str::from_utf8(c).map(str::to_owned),
+                |c| String::from_utf8_lossy(c).into_owned(),
+            )?,
+        };
+        let trailing = trailing.as_deref().unwrap_or(&[0]);
+        Ok(FString::read(string.into(), false, trailing))
+    }
+}
+/// String which can be written, only [`FString`]s carry a format
+trait WriteString {
+    fn value(&self) -> &str;
+    fn format(&self) -> Option<&StringFormat>;
+}
+impl WriteString for str {
+    fn value(&self) -> &str {
+        self
+    }
+    fn format(&self) -> Option<&StringFormat> {
+        None
+    }
+}
+impl WriteString for String {
+    fn value(&self) -> &str {
+        self
+    }
+    fn format(&self) -> Option<&StringFormat> {
+        None
     }
 }
-fn write_string<W: Write>(writer: &mut Context<W>, string: &str) -> TResult<()> {
-    if string.is_empty() {
+impl WriteString for FString<'_> {
+    fn value(&self) -> &str {
+        self
+    }
+    fn format(&self) -> Option<&StringFormat> {
+        FString::format(self)
+    }
+}
+fn write_string<W: Write, S: WriteString + ?Sized>(
+    writer: &mut Context<W>,
+    string: &S,
+) -> TResult<()> {
+    let format = string.format();
+    let string = string.value();
+    if let Some(format) = format {
+        write_string_encoded(writer, string, format.utf16, &format.trailing)?;
+    } else if string.is_empty() {
         writer.write_u32::<LE>(0)?;
     } else {
         write_string_trailing(writer, string, None)?;
@@ -117,9 +214,11 @@ fn write_string<W: Write>(writer: &mut Context<W>, string: &str) -> TResult<()>
 }
 
 fn read_string_trailing<R: Read + Seek>(reader: &mut Context<R>) -> TResult<(String, Vec<u8>)> {
+    let offset = reader.stream_position()?;
     let len = reader.read_i32::<LE>()?;
+    reader.string_length(len.unsigned_abs() as u64 * if len < 0 { 2 } else { 1 })?;
     if len < 0 {
-        let bytes = (-len) as usize * 2;
+        let bytes = len.unsigned_abs() as usize * 2;
         let mut chars = vec![];
         let mut rest = vec![];
         let mut read = 0;
@@ -137,7 +236,13 @@ fn read_string_trailing<R: Read + Seek>(reader: &mut Context<R>) -> TResult<(Str
             rest.push(reader.read_u8()?);
             read += 1;
         }
-        Ok((String::from_utf16(&chars).unwrap(), rest))
+        let string = reader.decode_string(
+            offset,
+            &chars[..],
+            String::from_utf16,
+            String::from_utf16_lossy,
+        )?;
+        Ok((string, rest))
     } else {
         let bytes = len as usize;
         let mut chars = vec![];
@@ -157,7 +262,13 @@ fn read_string_trailing<R: Read + Seek>(reader: &mut Context<R>) -> TResult<(Str
             rest.push(reader.read_u8()?);
             read += 1;
         }
-        Ok((String::from_utf8(chars).unwrap(), rest))
+        let string = reader.decode_string(
+            offset,
+            &chars[..],
+            |c| std::str::from_utf8(c).map(str::to_owned),
+            |c| String::from_utf8_lossy(c).into_owned(),
+        )?;
+        Ok((string, rest))
     }
 }
 fn write_string_trailing<W: Write>(
@@ -165,41 +276,55 @@ fn write_string_trailing<W: Write>(
     string: &str,
     trailing: Option<&[u8]>,
 ) -> TResult<()> {
-    if string.is_empty() || string.is_ascii() {
-        writer.write_u32::<LE>(
-            (string.as_bytes().len() + trailing.map(|t| t.len()).unwrap_or(1)) as u32,
-        )?;
-        writer.write_all(string.as_bytes())?;
-        writer.write_all(trailing.unwrap_or(&[0]))?;
+    let single_byte = match writer.write_options.string_encoding {
+        // trailing bytes were read with the original encoding and only fit that one
+        _ if trailing.is_some() => string.is_ascii(),
+        StringEncoding::Auto => string.is_ascii(),
+        StringEncoding::Utf16 => false,
+    };
+    if string.is_empty() || single_byte {
+        write_string_encoded(writer, string, false, trailing.unwrap_or(&[0]))
     } else {
+        write_string_encoded(writer, string, true, trailing.unwrap_or(&[0, 0]))
+    }
+}
+/// Writes a string followed by `trailing` which includes the terminator, if any
+fn write_string_encoded<W: Write>(
+    writer: &mut Context<W>,
+    string: &str,
+    utf16: bool,
+    trailing: &[u8],
+) -> TResult<()> {
+    if utf16 {
         let chars: Vec<u16> = string.encode_utf16().collect();
-        writer.write_i32::<LE>(
-            -((chars.len() + trailing.map(|t| t.len()).unwrap_or(2) / 2) as i32),
-        )?;
+        writer.write_i32::<LE>(-((chars.len() + trailing.len() / 2) as i32))?;
         for c in chars {
             writer.write_u16::<LE>(c)?;
         }
-        writer.write_all(trailing.unwrap_or(&[0, 0]))?;
+    } else {
+        writer.write_u32::<LE>((string.len() + trailing.len()) as u32)?;
+        writer.write_all(string.as_bytes())?;
     }
+    writer.write_all(trailing)?;
     Ok(())
 }
 
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct PropertyKey(pub u32, pub String);
-impl From<String> for PropertyKey {
+pub struct PropertyKey<'a>(pub u32, pub FString<'a>);
+impl From<String> for PropertyKey<'_> {
     fn from(value: String) -> Self {
-        Self(0, value)
+        Self(0, value.into())
     }
 }
-impl From<&str> for PropertyKey {
-    fn from(value: &str) -> Self {
-        Self(0, value.to_string())
+impl<'a> From<&'a str> for PropertyKey<'a> {
+    fn from(value: &'a str) -> Self {
+        Self(0, value.into())
     }
 }
 
 struct PropertyKeyVisitor;
 impl<'de> Visitor<'de> for PropertyKeyVisitor {
-    type Value = PropertyKey;
+    type Value = PropertyKey<'static>;
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         formatter.write_str(
             "a property key in the form of key name and index seperated by '_' e.g. property_2",
@@ -214,10 +339,10 @@ impl<'de> Visitor<'de> for PropertyKeyVisitor {
             .ok_or_else(|| serde::de::Error::custom("property key does not contain a '_'"))?;
         let index: u32 = index_str.parse().map_err(serde::de::Error::custom)?;
 
-        Ok(PropertyKey(index, name_str.to_string()))
+        Ok(PropertyKey(index, name_str.to_owned().into()))
     }
 }
-impl<'de> Deserialize<'de> for PropertyKey {
+impl<'de> Deserialize<'de> for PropertyKey<'_> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -225,7 +350,7 @@ impl<'de> Deserialize<'de> for PropertyKey {
         deserializer.deserialize_str(PropertyKeyVisitor)
     }
 }
-impl Serialize for PropertyKey {
+impl Serialize for PropertyKey<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -235,40 +360,44 @@ impl Serialize for PropertyKey {
 }
 
 #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
-pub struct Properties(pub indexmap::IndexMap<PropertyKey, Property>);
-impl Properties {
-    fn insert(&mut self, k: impl Into<PropertyKey>, v: Property) -> Option<Property> {
+pub struct Properties<'a>(pub indexmap::IndexMap<PropertyKey<'a>, Property<'a>>);
+impl<'a> Properties<'a> {
+    fn insert(&mut self, k: impl Into<PropertyKey<'a>>, v: Property<'a>) -> Option<Property<'a>> {
         self.0.insert(k.into(), v)
     }
 }
-impl<K> std::ops::Index<K> for Properties
+impl<'a, K> std::ops::Index<K> for Properties<'a>
 where
-    K: Into<PropertyKey>,
+    K: Into<PropertyKey<'a>>,
 {
-    type Output = Property;
+    type Output = Property<'a>;
     fn index(&self, index: K) -> &Self::Output {
         self.0.index(&index.into())
     }
 }
-impl<K> std::ops::IndexMut<K> for Properties
+impl<'a, K> std::ops::IndexMut<K> for Properties<'a>
 where
-    K: Into<PropertyKey>,
+    K: Into<PropertyKey<'a>>,
 {
-    fn index_mut(&mut self, index: K) -> &mut Property {
+    fn index_mut(&mut self, index: K) -> &mut Property<'a> {
         self.0.index_mut(&index.into())
     }
 }
-impl<'a> IntoIterator for &'a Properties {
-    type Item = <&'a indexmap::IndexMap<PropertyKey, Property> as IntoIterator>::Item;
-    type IntoIter = <&'a indexmap::IndexMap<PropertyKey, Property> as IntoIterator>::IntoIter;
+impl<'a, 'p> IntoIterator for &'a Properties<'p> {
+    type Item = <&'a indexmap::IndexMap<PropertyKey<'p>, Property<'p>> as IntoIterator>::Item;
+    type IntoIter =
+        <&'a indexmap::IndexMap<PropertyKey<'p>, Property<'p>> as IntoIterator>::IntoIter;
     fn into_iter(self) -> Self::IntoIter {
         self.0.iter()
     }
 }
 
-fn read_properties_until_none<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Properties> {
+fn read_properties_until_none<'d, R: Input<'d>>(
+    reader: &mut Context<R>,
+) -> TResult<Properties<'d>> {
     let mut properties = Properties::default();
     while let Some((name, prop)) = read_property(reader)? {
+        reader.allocate(std::mem::size_of::<(PropertyKey, Property)>() as u64)?;
         properties.insert(name, prop);
     }
     Ok(properties)
@@ -284,42 +413,275 @@ fn write_properties_none_terminated<W: Write>(
     Ok(())
 }
 
-fn read_property<R: Read + Seek>(
+fn read_property<'d, R: Input<'d>>(
     reader: &mut Context<R>,
-) -> TResult<Option<(PropertyKey, Property)>> {
+) -> TResult<Option<(PropertyKey<'d>, Property<'d>)>> {
+    let offset = reader.stream_position()?;
     let name = read_string(reader)?;
     if name == "None" {
         Ok(None)
     } else {
-        reader.scope(&name, |reader| {
-            let t = PropertyType::read(reader)?;
-            let size = reader.read_u32::<LE>()?;
-            let index = reader.read_u32::<LE>()?;
-            let value = Property::read(reader, t, size)?;
-            Ok(Some((PropertyKey(index, name.clone()), value)))
+        reader.nested(|reader| {
+            reader.scope(&name, |reader| {
+                let (index, size_offset, size, value) = if reader.property_tag_complete_type_name()
+                {
+                    read_complete_tag_property(reader, offset)?
+                } else {
+                    let type_name = read_string(reader)?;
+                    let size_offset = reader.stream_position()?;
+                    let size = reader.read_u32::<LE>()?;
+                    let index = reader.read_u32::<LE>()?;
+                    let value = reader
+                        .array_index(index, |r| read_legacy_tag_value(r, &type_name, size))
+                        .map_err(|e| reader.property_error(&type_name, size, offset, e))?;
+                    (index, size_offset, size, value)
+                };
+                let end = reader.stream_position()?;
+                reader.array_index(index, |r| r.record_property(offset, end));
+                reader.record_size(size_offset, 4, end.saturating_sub(size as u64), end);
+                Ok(Some((PropertyKey(index, name.clone()), value)))
+            })
         })
     }
 }
+/// Reads the type specific tag data and value of a property in the legacy layout
+fn read_legacy_tag_value<'d, R: Input<'d>>(
+    reader: &mut Context<R>,
+    type_name: &str,
+    size: u32,
+) -> TResult<Property<'d>> {
+    let start = reader.stream_position()?;
+    let allocated = reader.usage.allocated();
+    match PropertyType::from_name(type_name) {
+        Ok(t) => Property::read(reader, t, size).or_else(|e| {
+            reader.raw_fallback(start, allocated, e, |reader| {
+                Property::read_raw(reader, type_name.to_owned(), size)
+            })
+        }),
+        Err(e @ Error::UnknownPropertyType(_)) => {
+            reader.raw_fallback(start, allocated, e, |reader| {
+                Property::read_raw(reader, type_name.to_owned(), size)
+            })
+        }
+        Err(e) => Err(e),
+    }
+}
+/// Skips the type specific tag data preceding the value in the legacy layout
+fn skip_tag_data<'d, R: Input<'d>>(reader: &mut Context<R>, t: &PropertyType) -> TResult<()> {
+    match t {
+        PropertyType::BoolProperty => {
+            reader.read_u8()?;
+        }
+        PropertyType::ByteProperty
+        | PropertyType::EnumProperty
+        | PropertyType::ArrayProperty
+        | PropertyType::SetProperty => {
+            read_string(reader)?;
+        }
+        PropertyType::MapProperty => {
+            read_string(reader)?;
+            read_string(reader)?;
+        }
+        PropertyType::StructProperty => {
+            read_string(reader)?;
+            uuid::Uuid::read(reader)?;
+        }
+        _ => {}
+    }
+    read_optional_uuid(reader)?;
+    Ok(())
+}
+/// Reads the remainder of a property tag in the UE 5.4+ layout and the value following it
+fn read_complete_tag_property<'d, R: Input<'d>>(
+    reader: &mut Context<R>,
+    offset: u64,
+) -> TResult<(u32, u64, u32, Property<'d>)> {
+    let (tag, size_offset, size, index) = read_complete_tag(reader)?;
+    let property_type = tag.type_name.name.clone();
+    let value = reader
+        .array_index(index, |r| {
+            read_complete_tag_value(r, tag.type_name, size, tag.flags, index, tag.id)
+        })
+        .map_err(|e| reader.property_error(&property_type, size, offset, e))?;
+    Ok((index, size_offset, size, value))
+}
+/// Reads the remainder of a property tag in the UE 5.4+ layout along with the offset of the size
+/// field, the size of the value and the array index
+fn read_complete_tag<'d, R: Input<'d>>(
+    reader: &mut Context<R>,
+) -> TResult<(CompleteTag<'d>, u64, u32, u32)> {
+    let type_name = PropertyTypeName::read(reader)?;
+    let size_offset = reader.stream_position()?;
+    let size = reader.read_u32::<LE>()?;
+    let flags = reader.read_u8()?;
+    let index = if flags & HAS_ARRAY_INDEX != 0 {
+        reader.read_u32::<LE>()?
+    } else {
+        0
+    };
+    let id = if flags & HAS_PROPERTY_GUID != 0 {
+        Some(uuid::Uuid::read(reader)?)
+    } else {
+        None
+    };
+    if flags & (HAS_PROPERTY_EXTENSIONS | SKIPPED_SERIALIZE) != 0 {
+        return Err(Error::Other(format!(
+            "unsupported property tag flags 0x{flags:x}"
+        )));
+    }
+    let tag = CompleteTag {
+        type_name,
+        flags,
+        id,
+    };
+    Ok((tag, size_offset, size, index))
+}
+fn read_complete_tag_value<'d, R: Input<'d>>(
+    reader: &mut Context<R>,
+    type_name: PropertyTypeName<'d>,
+    size: u32,
+    flags: u8,
+    index: u32,
+    id: Option<uuid::Uuid>,
+) -> TResult<Property<'d>> {
+    let start = reader.stream_position()?;
+    let allocated = reader.usage.allocated();
+    Ok(match PropertyType::from_name(&type_name.name) {
+        Ok(t) if type_name.is_known() => {
+            let tag = CompleteTag {
+                type_name,
+                flags,
+                id,
+            };
+            let mut value = match Property::read_tagged(reader, t, size, Some(&tag)) {
+                Err(e) => {
+                    return reader.raw_fallback(start, allocated, e, |reader| {
+                        read_complete_tag_raw(reader, tag.type_name, size, flags, index, id)
+                    })
+                }
+                Ok(value) => value,
+            };
+            if value.tag_flags(index) != flags {
+                return Err(Error::Other(format!(
+                    "unexpected property tag flags 0x{flags:x}"
+                )));
+            }
+            if value.derive_complete_type() != tag.type_name {
+                if let Some(complete_type) = value.complete_type_mut() {
+                    *complete_type = Some(tag.type_name);
+                }
+            }
+            value
+        }
+        _ => read_complete_tag_raw(reader, type_name, size, flags, index, id)?,
+    })
+}
+/// Reads a property value in the UE 5.4+ layout as [`Property::Raw`]
+fn read_complete_tag_raw<'d, R: Input<'d>>(
+    reader: &mut Context<R>,
+    type_name: PropertyTypeName<'d>,
+    size: u32,
+    flags: u8,
+    index: u32,
+    id: Option<uuid::Uuid>,
+) -> TResult<Property<'d>> {
+    // keep everything but the root type name and size as opaque tag data
+    let mut tag_bytes = vec![];
+    reader.stream(&mut tag_bytes, |writer| -> TResult<()> {
+        writer.write_u32::<LE>(type_name.parameters.len() as u32)?;
+        for parameter in &type_name.parameters {
+            parameter.write(writer)?;
+        }
+        writer.write_u8(flags)?;
+        if flags & HAS_ARRAY_INDEX != 0 {
+            writer.write_u32::<LE>(index)?;
+        }
+        if let Some(id) = id {
+            id.write(writer)?;
+        }
+        Ok(())
+    })?;
+    let value_bytes = reader.read_bytes(size as usize)?;
+    Ok(Property::Raw {
+        type_name: type_name.name.into_string(),
+        tag_bytes: tag_bytes.into(),
+        value_bytes,
+    })
+}
 fn write_property<W: Write>(
     prop: (&PropertyKey, &Property),
     writer: &mut Context<W>,
 ) -> TResult<()> {
     write_string(writer, &prop.0 .1)?;
-    prop.1.get_type().write(writer)?;
+    if writer.property_tag_complete_type_name() {
+        return write_complete_tag_property(prop, writer);
+    }
+    match prop.1.get_type() {
+        Some(t) => t.write(writer)?,
+        None => write_string(writer, prop.1.type_name())?,
+    }
 
     let mut buf = vec![];
     let size = writer.stream(&mut buf, |writer| prop.1.write(writer))?;
+    buf.extend(prop.1.trailing());
+    let size = size + prop.1.trailing().len();
 
     writer.write_u32::<LE>(size as u32)?;
     writer.write_u32::<LE>(prop.0 .0)?;
     writer.write_all(&buf[..])?;
     Ok(())
 }
+/// Writes a property tag in the UE 5.4+ layout followed by the property value
+fn write_complete_tag_property<W: Write>(
+    prop: (&PropertyKey, &Property),
+    writer: &mut Context<W>,
+) -> TResult<()> {
+    if let Property::Raw {
+        type_name,
+        tag_bytes,
+        value_bytes,
+    } = prop.1
+    {
+        // the size is located between the type name parameters and the rest of the tag
+        let mut parameters = std::io::Cursor::new(tag_bytes);
+        writer.stream(&mut parameters, |reader| {
+            let count = reader.read_u32::<LE>()?;
+            read_array(count, reader, PropertyTypeName::read)
+        })?;
+        let split = parameters.position() as usize;
+        write_string(writer, type_name)?;
+        writer.write_all(&tag_bytes[..split])?;
+        writer.write_u32::<LE>(value_bytes.len() as u32)?;
+        writer.write_all(&tag_bytes[split..])?;
+        writer.write_all(value_bytes)?;
+        return Ok(());
+    }
+    match prop.1.complete_type() {
+        Some(complete_type) => complete_type.write(writer)?,
+        None => prop.1.derive_complete_type().write(writer)?,
+    }
+
+    let mut buf = vec![];
+    writer.stream(&mut buf, |writer| prop.1.write(writer))?;
+    buf.extend(prop.1.trailing());
+
+    writer.write_u32::<LE>(buf.len() as u32)?;
+    writer.write_u8(prop.1.tag_flags(prop.0 .0))?;
+    if prop.0 .0 != 0 {
+        writer.write_u32::<LE>(prop.0 .0)?;
+    }
+    if let Some(id) = prop.1.id() {
+        id.write(writer)?;
+    }
+    writer.write_all(&buf[..])?;
+    Ok(())
+}
 
 fn read_array<T, F, R: Read + Seek>(length: u32, reader: &mut Context<R>, f: F) -> TResult<Vec<T>>
 where
     F: Fn(&mut Context<R>) -> TResult<T>,
 {
+    reader.elements::<T>(length)?;
     (0..length).map(|_| f(reader)).collect()
 }
 
@@ -360,10 +722,11 @@ impl Types {
     pub fn new() -> Self {
         Self::default()
     }
-    /// Add a new type at the given path
+    /// Add a new type at the given path e.g. `.UnlockedItemSkins.Skins.Key`. The syntax is the
+    /// same as for [`Properties::get_path`], indices and keys of container elements are ignored
+    /// as the type applies to all of them.
     pub fn add(&mut self, path: String, t: StructType) {
-        // TODO: Handle escaping of '.' in property names
-        // probably should store keys as Vec<String>
+        let path = Path::parse(&path).map_or(path, |p| p.type_path());
         self.types.insert(path, t);
     }
 }
@@ -375,6 +738,17 @@ enum Scope<'p, 'n> {
         parent: &'p Scope<'p, 'p>,
         name: &'n str,
     },
+    /// Element of a container. Only part of the displayed path, [`Types`] apply to all elements
+    Index {
+        parent: &'p Scope<'p, 'p>,
+        index: usize,
+    },
+    /// Array index of the property in [`PropertyKey`], displayed like in [`Properties::get_path`]
+    /// if it is not 0
+    ArrayIndex {
+        parent: &'p Scope<'p, 'p>,
+        index: u32,
+    },
 }
 
 impl<'p, 'n> Scope<'p, 'n> {
@@ -382,8 +756,31 @@ impl<'p, 'n> Scope<'p, 'n> {
         match self {
             Self::Root => "".into(),
             Self::Node { parent, name } => {
-                format!("{}.{}", parent.path(), name)
+                format!("{}.{}", parent.path(), path::escape(name))
             }
+            Self::Index { parent, .. } | Self::ArrayIndex { parent, .. } => parent.path(),
+        }
+    }
+    fn display_path(&self) -> String {
+        match self {
+            Self::Root => "".into(),
+            Self::Node { parent, name } => {
+                format!("{}.{}", parent.display_path(), path::escape(name))
+            }
+            Self::Index { parent, index } => {
+                format!("{}[{}]", parent.display_path(), index)
+            }
+            Self::ArrayIndex { parent, index: 0 } => parent.display_path(),
+            Self::ArrayIndex { parent, index } => {
+                format!("{}#{}", parent.display_path(), index)
+            }
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            Self::Root => "",
+            Self::Node { name, .. } => name,
+            Self::Index { parent, .. } | Self::ArrayIndex { parent, .. } => parent.name(),
         }
     }
 }
@@ -392,8 +789,19 @@ impl<'p, 'n> Scope<'p, 'n> {
 struct Context<'stream, 'header, 'types, 'scope, S> {
     stream: &'stream mut S,
     header: Option<&'header Header>,
-    types: &'types Types,
+    options: &'types ReadOptions,
+    write_options: &'types WriteOptions,
+    diagnostics: &'types RefCell<Diagnostics>,
+    /// Byte spans of properties and size fields being recorded for [`Patch`]
+    spans: Option<&'types RefCell<Spans>>,
     scope: &'scope Scope<'scope, 'scope>,
+    /// Position following the type specific tag data of the property being read
+    value_start: Option<u64>,
+    /// Whether [`ReadOptions::raw_fallback`] applies. Disabled while trying candidate struct
+    /// types so a wrong candidate fails instead of being kept as [`Property::Raw`].
+    recover: bool,
+    /// Resources used so far to enforce [`ReadOptions::limits`]
+    usage: &'types Usage,
 }
 impl<R: Read> Read for Context<'_, '_, '_, '_, R> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
@@ -415,6 +823,7 @@ impl<W: Write> Write for Context<'_, '_, '_, '_, W> {
 }
 
 impl<'stream, 'header, 'types, 'scope, S> Context<'stream, 'header, 'types, 'scope, S> {
+    #[cfg(test)]
     fn run<F, T>(stream: &'stream mut S, f: F) -> T
     where
         F: FnOnce(&mut Context<'stream, '_, '_, 'scope, S>) -> T,
@@ -422,19 +831,69 @@ impl<'stream, 'header, 'types, 'scope, S> Context<'stream, 'header, 'types, 'sco
         f(&mut Context::<'stream, '_, '_, 'scope> {
             stream,
             header: None,
-            types: &Types::new(),
+            options: &ReadOptions::new(),
+            write_options: &WriteOptions::new(),
+            diagnostics: &RefCell::new(Diagnostics::new()),
+            spans: None,
+            scope: &Scope::Root,
+            value_start: None,
+            recover: true,
+            usage: &Usage::default(),
+        })
+    }
+    fn run_with_options<'o, F, T>(
+        options: &'o ReadOptions,
+        diagnostics: &'o RefCell<Diagnostics>,
+        stream: &'stream mut S,
+        f: F,
+    ) -> T
+    where
+        F: FnOnce(&mut Context<'stream, '_, '_, 'scope, S>) -> T,
+    {
+        Self::run_with_spans(options, diagnostics, None, stream, f)
+    }
+    fn run_with_spans<'o, F, T>(
+        options: &'o ReadOptions,
+        diagnostics: &'o RefCell<Diagnostics>,
+        spans: Option<&'o RefCell<Spans>>,
+        stream: &'stream mut S,
+        f: F,
+    ) -> T
+    where
+        F: FnOnce(&mut Context<'stream, '_, '_, 'scope, S>) -> T,
+    {
+        f(&mut Context::<'stream, '_, '_, 'scope> {
+            stream,
+            header: None,
+            options,
+            write_options: &WriteOptions::new(),
+            diagnostics,
+            spans,
             scope: &Scope::Root,
+            value_start: None,
+            recover: true,
+            usage: &Usage::default(),
         })
     }
-    fn run_with_types<F, T>(types: &'types Types, stream: &'stream mut S, f: F) -> T
+    fn run_with_write_options<'o, F, T>(
+        write_options: &'o WriteOptions,
+        stream: &'stream mut S,
+        f: F,
+    ) -> T
     where
-        F: FnOnce(&mut Context<'stream, '_, 'types, 'scope, S>) -> T,
+        F: FnOnce(&mut Context<'stream, '_, '_, 'scope, S>) -> T,
     {
-        f(&mut Context::<'stream, '_, 'types, 'scope> {
+        f(&mut Context::<'stream, '_, '_, 'scope> {
             stream,
             header: None,
-            types,
+            options: &ReadOptions::new(),
+            write_options,
+            diagnostics: &RefCell::new(Diagnostics::new()),
+            spans: None,
             scope: &Scope::Root,
+            value_start: None,
+            recover: true,
+            usage: &Usage::default(),
         })
     }
     fn scope<'name, F, T>(&mut self, name: &'name str, f: F) -> T
@@ -444,13 +903,59 @@ impl<'stream, 'header, 'types, 'scope, S> Context<'stream, 'header, 'types, 'sco
         f(&mut Context {
             stream: self.stream,
             header: self.header,
-            types: self.types,
+            options: self.options,
+            write_options: self.write_options,
+            diagnostics: self.diagnostics,
+            spans: self.spans,
+            value_start: None,
+            recover: self.recover,
+            usage: self.usage,
             scope: &Scope::Node {
                 name,
                 parent: self.scope,
             },
         })
     }
+    fn index<F, T>(&mut self, index: usize, f: F) -> T
+    where
+        F: FnOnce(&mut Context<'_, '_, 'types, '_, S>) -> T,
+    {
+        f(&mut Context {
+            stream: self.stream,
+            header: self.header,
+            options: self.options,
+            write_options: self.write_options,
+            diagnostics: self.diagnostics,
+            spans: self.spans,
+            value_start: None,
+            recover: self.recover,
+            usage: self.usage,
+            scope: &Scope::Index {
+                index,
+                parent: self.scope,
+            },
+        })
+    }
+    fn array_index<F, T>(&mut self, index: u32, f: F) -> T
+    where
+        F: FnOnce(&mut Context<'_, '_, 'types, '_, S>) -> T,
+    {
+        f(&mut Context {
+            stream: self.stream,
+            header: self.header,
+            options: self.options,
+            write_options: self.write_options,
+            diagnostics: self.diagnostics,
+            spans: self.spans,
+            value_start: None,
+            recover: self.recover,
+            usage: self.usage,
+            scope: &Scope::ArrayIndex {
+                index,
+                parent: self.scope,
+            },
+        })
+    }
     fn header<'h, F, T>(&mut self, header: &'h Header, f: F) -> T
     where
         F: FnOnce(&mut Context<'_, '_, 'types, '_, S>) -> T,
@@ -458,7 +963,13 @@ impl<'stream, 'header, 'types, 'scope, S> Context<'stream, 'header, 'types, 'sco
         f(&mut Context {
             stream: self.stream,
             header: Some(header),
-            types: self.types,
+            options: self.options,
+            write_options: self.write_options,
+            diagnostics: self.diagnostics,
+            spans: self.spans,
+            value_start: None,
+            recover: self.recover,
+            usage: self.usage,
             scope: self.scope,
         })
     }
@@ -469,38 +980,198 @@ impl<'stream, 'header, 'types, 'scope, S> Context<'stream, 'header, 'types, 'sco
         f(&mut Context {
             stream,
             header: self.header,
-            types: self.types,
+            options: self.options,
+            write_options: self.write_options,
+            diagnostics: self.diagnostics,
+            // offsets in another stream do not refer to the save
+            spans: None,
+            value_start: None,
+            recover: self.recover,
+            usage: self.usage,
             scope: self.scope,
         })
     }
     fn path(&self) -> String {
         self.scope.path()
     }
+    fn display_path(&self) -> String {
+        self.scope.display_path()
+    }
+    fn name(&self) -> &str {
+        self.scope.name()
+    }
+    /// Annotates an error with the property being read unless a nested property already did
+    fn property_error(&self, property_type: &str, size: u32, offset: u64, error: Error) -> Error {
+        match error {
+            Error::Property { .. } => error,
+            error => Error::Property {
+                context: std::boxed::Box::new(error::PropertyContext {
+                    path: self.display_path(),
+                    property_type: property_type.to_owned(),
+                    size,
+                    offset: offset as usize,
+                }),
+                error: std::boxed::Box::new(error),
+            },
+        }
+    }
+    fn large_world_coordinates(&self) -> bool {
+        self.header
+            .is_some_and(|header| header.large_world_coordinates())
+    }
+    fn property_tag_complete_type_name(&self) -> bool {
+        self.header
+            .is_some_and(|header| header.property_tag_complete_type_name())
+    }
     fn get_type(&self) -> Option<&'types StructType> {
-        self.types.types.get(&self.path())
+        self.options.types.types.get(&self.path())
+    }
+    /// Struct type named by a tag which does not say whether the struct is serialized natively.
+    /// UE writes a GameplayTag as a property list holding its `TagName`, the native form of some
+    /// games is only read if [`Types`] has it at this path.
+    fn tagged_struct_type(&self, struct_type: StructType) -> StructType {
+        match struct_type {
+            StructType::GameplayTag if self.get_type() != Some(&StructType::GameplayTag) => {
+                StructType::Struct(Some(struct_type.get_name().to_owned()))
+            }
+            struct_type => struct_type,
+        }
     }
-}
-impl<'stream, 'header, 'types, 'scope, R: Read + Seek>
-    Context<'stream, 'header, 'types, 'scope, R>
-{
-    fn get_type_or<'t>(&mut self, t: &'t StructType) -> TResult<&'t StructType>
+    fn warn(&self, kind: DiagnosticKind, offset: u64, message: String) {
+        self.diagnostics.borrow_mut().push(Diagnostic {
+            kind,
+            path: self.display_path(),
+            offset: offset as usize,
+            message,
+        });
+    }
+    fn inferred_type<'t>(
+        &self,
+        offset: u64,
+        t: &StructType,
+        ambiguous: impl IntoIterator<Item = &'t StructType>,
+    ) -> TResult<()> {
+        if !self.options.allow_inferred_types {
+            return Err(Error::Other(format!(
+                "StructType for \"{}\" unspecified, specify it with a type hint",
+                self.path()
+            )));
+        }
+        self.warn(
+            DiagnosticKind::InferredStructType,
+            offset,
+            format!(
+                "StructType for \"{}\" unspecified, inferred {:?}{}",
+                self.path(),
+                t,
+                ambiguous
+                    .into_iter()
+                    .map(|t| format!("{t:?}"))
+                    .reduce(|a, b| format!("{a}, {b}"))
+                    .map(|a| format!(" which is ambiguous as {a} fit as well"))
+                    .unwrap_or_default()
+            ),
+        );
+        Ok(())
+    }
+    /// Records the extent of the property being read for [`Patch`]
+    fn record_property(&self, start: u64, end: u64) {
+        if let Some(spans) = self.spans {
+            spans
+                .borrow_mut()
+                .add_property(self.display_path(), start, end);
+        }
+    }
+    /// Records a size field of `width` bytes at `offset` declaring the length of `start..end`
+    fn record_size(&self, offset: u64, width: u8, start: u64, end: u64) {
+        if let Some(spans) = self.spans {
+            spans.borrow_mut().add_size(offset, width, start, end);
+        }
+    }
+    /// Keeps a property which failed to read with `error` as [`Property::Raw`] if
+    /// [`ReadOptions::raw_fallback`] is set by rewinding to `start` where the raw data begins and
+    /// reading it with `read_raw`. What was allocated since `allocated` bytes were is dropped
+    /// and no longer counts against [`ReadOptions::limits`]. Fails with `error` if the raw data
+    /// cannot be read either unless reading it exceeded the limits.
+    fn raw_fallback<'d>(
+        &mut self,
+        start: u64,
+        allocated: u64,
+        error: Error,
+        read_raw: impl FnOnce(&mut Self) -> TResult<Property<'d>>,
+    ) -> TResult<Property<'d>>
     where
-        'types: 't,
+        S: Seek,
     {
-        let offset = self.stream.stream_position()?;
-        Ok(self.get_type().unwrap_or_else(|| {
-            eprintln!(
-                "offset {}: StructType for \"{}\" unspecified, assuming {:?}",
-                offset,
-                self.path(),
-                t
-            );
-            t
-        }))
+        if !self.options.raw_fallback || !self.recover {
+            return Err(error);
+        }
+        self.seek(std::io::SeekFrom::Start(start))?;
+        self.usage.restore(allocated);
+        match read_raw(self) {
+            Ok(property) => {
+                self.warn(DiagnosticKind::RawFallback, start, error.to_string());
+                Ok(property)
+            }
+            // a limit applies no matter how the data is read
+            Err(limit @ Error::LimitExceeded { .. }) => Err(limit),
+            Err(_) => Err(error),
+        }
+    }
+    /// Reads `len` bytes which are borrowed if the input lends them and copied otherwise
+    fn read_bytes<'d>(&mut self, len: usize) -> TResult<Cow<'d, [u8]>>
+    where
+        S: Input<'d>,
+    {
+        if let Some(bytes) = self.stream.lend(len) {
+            return Ok(Cow::Borrowed(bytes));
+        }
+        self.allocate(len as u64)?;
+        let mut bytes = vec![0; len];
+        self.read_exact(&mut bytes)?;
+        Ok(Cow::Owned(bytes))
+    }
+    /// Accounts for `bytes` about to be allocated against [`ReadOptions::limits`]
+    fn allocate(&self, bytes: u64) -> TResult<()> {
+        self.usage.allocate(&self.options.limits, bytes)
+    }
+    /// Accounts for a collection of `count` elements of type `T` against
+    /// [`ReadOptions::limits`]
+    fn elements<T>(&self, count: u32) -> TResult<()> {
+        let size = std::mem::size_of::<T>();
+        self.usage.elements(&self.options.limits, count, size)
+    }
+    /// Accounts for a string of `len` bytes against [`ReadOptions::limits`]
+    fn string_length(&self, len: u64) -> TResult<()> {
+        self.usage.string(&self.options.limits, len)
+    }
+    /// Runs `f` one level of nesting deeper as limited by [`ReadOptions::limits`]
+    fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> TResult<T>) -> TResult<T> {
+        self.usage.enter(&self.options.limits)?;
+        let result = f(self);
+        self.usage.exit();
+        result
+    }
+    /// Decodes string data according to [`ReadOptions::lossy_strings`]
+    fn decode_string<D: Copy, E: std::fmt::Display>(
+        &self,
+        offset: u64,
+        data: D,
+        decode: impl FnOnce(D) -> Result<String, E>,
+        decode_lossy: impl FnOnce(D) -> String,
+    ) -> TResult<String> {
+        match decode(data) {
+            Ok(string) => Ok(string),
+            Err(e) if self.options.lossy_strings => {
+                self.warn(DiagnosticKind::LossyString, offset, e.to_string());
+                Ok(decode_lossy(data))
+            }
+            Err(e) => Err(Error::Other(e.to_string())),
+        }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PropertyType {
     IntProperty,
     Int8Property,
@@ -531,7 +1202,7 @@ pub enum PropertyType {
     StructProperty,
 }
 impl PropertyType {
-    fn get_name(&self) -> &str {
+    fn get_name(&self) -> &'static str {
         match &self {
             PropertyType::Int8Property => "Int8Property",
             PropertyType::Int16Property => "Int16Property",
@@ -562,9 +1233,11 @@ impl PropertyType {
             PropertyType::StructProperty => "StructProperty",
         }
     }
-    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
-        let t = read_string(reader)?;
-        match t.as_str() {
+    fn read<'d, R: Input<'d>>(reader: &mut Context<R>) -> TResult<Self> {
+        Self::from_name(&read_string(reader)?)
+    }
+    fn from_name(t: &str) -> TResult<Self> {
+        match t {
             "Int8Property" => Ok(PropertyType::Int8Property),
             "Int16Property" => Ok(PropertyType::Int16Property),
             "IntProperty" => Ok(PropertyType::IntProperty),
@@ -601,6 +1274,114 @@ impl PropertyType {
     }
 }
 
+// EPropertyTagFlags
+const HAS_ARRAY_INDEX: u8 = 0x01;
+const HAS_PROPERTY_GUID: u8 = 0x02;
+const HAS_PROPERTY_EXTENSIONS: u8 = 0x04;
+const HAS_BINARY_OR_NATIVE_SERIALIZE: u8 = 0x08;
+const BOOL_TRUE: u8 = 0x10;
+const SKIPPED_SERIALIZE: u8 = 0x20;
+
+/// Complete type name of a property as stored in property tags since UE 5.4 (FPropertyTypeName).
+/// Inner types of containers and paths of structs, enums and classes are stored as parameters
+/// e.g. `StructProperty(Vector(/Script/CoreUObject))`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PropertyTypeName<'a> {
+    pub name: FString<'a>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<PropertyTypeName<'a>>,
+}
+impl<'a> PropertyTypeName<'a> {
+    fn new(name: impl Into<FString<'a>>) -> Self {
+        Self {
+            name: name.into(),
+            parameters: vec![],
+        }
+    }
+    /// Object paths such as `/Script/CoreUObject.Vector` are stored as the object name with the
+    /// package as parameter
+    fn from_path(path: &str) -> Self {
+        match path.rsplit_once('.') {
+            Some((package, name)) => Self {
+                name: name.to_owned().into(),
+                parameters: vec![Self::new(package.to_owned())],
+            },
+            None => Self::new(path.to_owned()),
+        }
+    }
+    fn to_path(&self) -> String {
+        match self.parameters.first() {
+            Some(package) => format!("{}.{}", package.name, self.name),
+            None => self.name.to_string(),
+        }
+    }
+    fn parameter(&self, index: usize) -> TResult<&PropertyTypeName<'a>> {
+        self.parameters
+            .get(index)
+            .ok_or_else(|| Error::Other(format!("missing parameter {index} of type {}", self.name)))
+    }
+    /// Whether the type and the types of any container elements can be read
+    fn is_known(&self) -> bool {
+        match PropertyType::from_name(&self.name) {
+            Ok(
+                PropertyType::ArrayProperty | PropertyType::SetProperty | PropertyType::MapProperty,
+            ) => self.parameters.iter().all(Self::is_known),
+            Ok(_) => true,
+            Err(_) => false,
+        }
+    }
+    /// Struct type and struct GUID from the parameters of a `StructProperty` type
+    fn struct_type(&self, native: bool) -> TResult<(StructType, uuid::Uuid)> {
+        let struct_type = StructType::from_type_name(self.parameter(0)?, native);
+        let struct_id = match self.parameters.get(1) {
+            Some(id) => uuid::Uuid::parse_str(&id.name)
+                .map_err(|e| Error::Other(format!("invalid struct GUID {:?}: {e}", id.name)))?,
+            None => uuid::Uuid::nil(),
+        };
+        Ok((struct_type, struct_id))
+    }
+    fn read<R: Input<'a>>(reader: &mut Context<R>) -> TResult<Self> {
+        Ok(Self {
+            name: read_string(reader)?,
+            parameters: read_array(reader.read_u32::<LE>()?, reader, Self::read)?,
+        })
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        write_string(writer, &self.name)?;
+        writer.write_u32::<LE>(self.parameters.len() as u32)?;
+        for parameter in &self.parameters {
+            parameter.write(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Tag data of a property in the UE 5.4+ layout. In the legacy layout the equivalent data is
+/// stored inline preceding the value.
+struct CompleteTag<'a> {
+    type_name: PropertyTypeName<'a>,
+    flags: u8,
+    id: Option<uuid::Uuid>,
+}
+fn read_tag_id<R: Read + Seek>(
+    reader: &mut Context<R>,
+    tag: Option<&CompleteTag>,
+) -> TResult<Option<uuid::Uuid>> {
+    let id = match tag {
+        Some(tag) => tag.id,
+        None => read_optional_uuid(reader)?,
+    };
+    reader.value_start = Some(reader.stream_position()?);
+    Ok(id)
+}
+fn write_tag_id<W: Write>(writer: &mut Context<W>, id: Option<uuid::Uuid>) -> TResult<()> {
+    if writer.property_tag_complete_type_name() {
+        Ok(())
+    } else {
+        write_optional_uuid(writer, id)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum StructType {
     Guid,
@@ -617,6 +1398,19 @@ pub enum StructType {
     Color,
     SoftObjectPath,
     GameplayTagContainer,
+    Vector4,
+    Plane,
+    Matrix,
+    Box2D,
+    IntVector2,
+    Vector3f,
+    Vector2f,
+    SoftClassPath,
+    FrameNumber,
+    UniqueNetIdRepl,
+    PerPlatformFloat,
+    PerPlatformInt,
+    GameplayTag,
     Struct(Option<String>),
 }
 impl From<&str> for StructType {
@@ -636,6 +1430,19 @@ impl From<&str> for StructType {
             "Color" => StructType::Color,
             "SoftObjectPath" => StructType::SoftObjectPath,
             "GameplayTagContainer" => StructType::GameplayTagContainer,
+            "Vector4" => StructType::Vector4,
+            "Plane" => StructType::Plane,
+            "Matrix" => StructType::Matrix,
+            "Box2D" => StructType::Box2D,
+            "IntVector2" => StructType::IntVector2,
+            "Vector3f" => StructType::Vector3f,
+            "Vector2f" => StructType::Vector2f,
+            "SoftClassPath" => StructType::SoftClassPath,
+            "FrameNumber" => StructType::FrameNumber,
+            "UniqueNetIdRepl" => StructType::UniqueNetIdRepl,
+            "PerPlatformFloat" => StructType::PerPlatformFloat,
+            "PerPlatformInt" => StructType::PerPlatformInt,
+            "GameplayTag" => StructType::GameplayTag,
             "Struct" => StructType::Struct(None),
             _ => StructType::Struct(Some(t.to_owned())),
         }
@@ -658,44 +1465,252 @@ impl From<String> for StructType {
             "Color" => StructType::Color,
             "SoftObjectPath" => StructType::SoftObjectPath,
             "GameplayTagContainer" => StructType::GameplayTagContainer,
+            "Vector4" => StructType::Vector4,
+            "Plane" => StructType::Plane,
+            "Matrix" => StructType::Matrix,
+            "Box2D" => StructType::Box2D,
+            "IntVector2" => StructType::IntVector2,
+            "Vector3f" => StructType::Vector3f,
+            "Vector2f" => StructType::Vector2f,
+            "SoftClassPath" => StructType::SoftClassPath,
+            "FrameNumber" => StructType::FrameNumber,
+            "UniqueNetIdRepl" => StructType::UniqueNetIdRepl,
+            "PerPlatformFloat" => StructType::PerPlatformFloat,
+            "PerPlatformInt" => StructType::PerPlatformInt,
+            "GameplayTag" => StructType::GameplayTag,
             "Struct" => StructType::Struct(None),
             _ => StructType::Struct(Some(t)),
         }
     }
 }
 impl StructType {
-    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
-        Ok(read_string(reader)?.into())
+    fn read<'d, R: Input<'d>>(reader: &mut Context<R>) -> TResult<Self> {
+        Ok(read_string(reader)?.into_string().into())
+    }
+    fn get_name(&self) -> &str {
+        match &self {
+            StructType::Guid => "Guid",
+            StructType::DateTime => "DateTime",
+            StructType::Timespan => "Timespan",
+            StructType::Vector2D => "Vector2D",
+            StructType::Vector => "Vector",
+            StructType::IntVector => "IntVector",
+            StructType::Box => "Box",
+            StructType::IntPoint => "IntPoint",
+            StructType::Quat => "Quat",
+            StructType::Rotator => "Rotator",
+            StructType::LinearColor => "LinearColor",
+            StructType::Color => "Color",
+            StructType::SoftObjectPath => "SoftObjectPath",
+            StructType::GameplayTagContainer => "GameplayTagContainer",
+            StructType::Vector4 => "Vector4",
+            StructType::Plane => "Plane",
+            StructType::Matrix => "Matrix",
+            StructType::Box2D => "Box2D",
+            StructType::IntVector2 => "IntVector2",
+            StructType::Vector3f => "Vector3f",
+            StructType::Vector2f => "Vector2f",
+            StructType::SoftClassPath => "SoftClassPath",
+            StructType::FrameNumber => "FrameNumber",
+            StructType::UniqueNetIdRepl => "UniqueNetIdRepl",
+            StructType::PerPlatformFloat => "PerPlatformFloat",
+            StructType::PerPlatformInt => "PerPlatformInt",
+            StructType::GameplayTag => "GameplayTag",
+            StructType::Struct(Some(t)) => t,
+            _ => unreachable!(),
+        }
     }
     fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
-        write_string(
-            writer,
-            match &self {
-                StructType::Guid => "Guid",
-                StructType::DateTime => "DateTime",
-                StructType::Timespan => "Timespan",
-                StructType::Vector2D => "Vector2D",
-                StructType::Vector => "Vector",
-                StructType::IntVector => "IntVector",
-                StructType::Box => "Box",
-                StructType::IntPoint => "IntPoint",
-                StructType::Quat => "Quat",
-                StructType::Rotator => "Rotator",
-                StructType::LinearColor => "LinearColor",
-                StructType::Color => "Color",
-                StructType::SoftObjectPath => "SoftObjectPath",
-                StructType::GameplayTagContainer => "GameplayTagContainer",
-                StructType::Struct(Some(t)) => t,
-                _ => unreachable!(),
-            },
-        )?;
+        write_string(writer, self.get_name())?;
         Ok(())
     }
+    /// Package of the engine struct corresponding to a built-in struct type
+    fn package(&self) -> Option<&'static str> {
+        match self {
+            StructType::GameplayTagContainer | StructType::GameplayTag => {
+                Some("/Script/GameplayTags")
+            }
+            StructType::UniqueNetIdRepl => Some("/Script/Engine"),
+            StructType::Struct(_) => None,
+            _ => Some("/Script/CoreUObject"),
+        }
+    }
+    /// Built-in struct types are only used if the struct lives in the engine package and is
+    /// serialized natively. Otherwise the full path is kept as the struct name.
+    fn from_type_name(type_name: &PropertyTypeName, native: bool) -> Self {
+        let struct_type = StructType::from(type_name.name.as_str());
+        let package = type_name.parameters.first().map(|p| p.name.as_str());
+        if native && struct_type.package().is_some() && struct_type.package() == package {
+            struct_type
+        } else {
+            StructType::Struct(Some(type_name.to_path()))
+        }
+    }
+    /// Struct types to try when the type of set elements or map keys/values is not known,
+    /// starting with `default`
+    fn candidates(default: StructType) -> Vec<StructType> {
+        let mut candidates = vec![default];
+        for t in [
+            StructType::Guid,
+            StructType::Struct(None),
+            StructType::Vector,
+            StructType::Vector2D,
+            StructType::IntVector,
+            StructType::IntPoint,
+            StructType::Rotator,
+            StructType::Quat,
+            StructType::Box,
+            StructType::LinearColor,
+            StructType::Color,
+            StructType::DateTime,
+            StructType::Timespan,
+            StructType::SoftObjectPath,
+            StructType::GameplayTagContainer,
+            StructType::Vector4,
+            StructType::Plane,
+            StructType::Matrix,
+            StructType::Box2D,
+            StructType::IntVector2,
+            StructType::Vector3f,
+            StructType::Vector2f,
+            StructType::FrameNumber,
+            StructType::SoftClassPath,
+            StructType::UniqueNetIdRepl,
+        ] {
+            if !candidates.contains(&t) {
+                candidates.push(t);
+            }
+        }
+        candidates
+    }
+    /// Size of the struct if it is the same for every value
+    fn fixed_size(&self, large_world_coordinates: bool) -> Option<u32> {
+        let real = if large_world_coordinates { 8 } else { 4 };
+        Some(match self {
+            StructType::Guid | StructType::LinearColor => 16,
+            StructType::DateTime
+            | StructType::Timespan
+            | StructType::IntPoint
+            | StructType::IntVector2
+            | StructType::Vector2f => 8,
+            StructType::IntVector | StructType::Vector3f => 12,
+            StructType::Color | StructType::FrameNumber => 4,
+            StructType::Vector2D => 2 * real,
+            StructType::Vector | StructType::Rotator => 3 * real,
+            StructType::Quat | StructType::Vector4 | StructType::Plane => 4 * real,
+            StructType::Matrix => 16 * real,
+            StructType::Box => 6 * real + 1,
+            StructType::Box2D => 4 * real + 4,
+            _ => return None,
+        })
+    }
+    /// Whether values of both struct types always take up the same number of bytes and so
+    /// either can be read from the same data
+    fn same_size(&self, other: &StructType, large_world_coordinates: bool) -> bool {
+        let size = self.fixed_size(large_world_coordinates);
+        size.is_some() && size == other.fixed_size(large_world_coordinates)
+    }
+    fn complete_type(&self) -> PropertyTypeName<'static> {
+        match self.package() {
+            Some(package) => PropertyTypeName {
+                name: self.get_name().to_owned().into(),
+                parameters: vec![PropertyTypeName::new(package)],
+            },
+            None => PropertyTypeName::from_path(self.get_name()),
+        }
+    }
 }
 
-type DateTime = u64;
-type Timespan = i64;
-type Int8 = i8;
+/// Size of a value of `size` bytes without the `header` bytes preceding the elements
+fn value_size(size: u32, header: u32) -> TResult<u32> {
+    size.checked_sub(header).ok_or_else(|| {
+        Error::Other(format!(
+            "property size {size} is smaller than the {header} bytes preceding the elements"
+        ))
+    })
+}
+
+/// Tries each candidate to read a container value of `size` bytes and returns the first one
+/// which succeeds and consumes exactly all of the bytes. Also returns the later candidates which
+/// are the `same_size` as it and succeed as well, the result is ambiguous if there are any.
+fn infer_struct_types<'d, R: Input<'d>, C, T, F>(
+    reader: &mut Context<R>,
+    size: u32,
+    candidates: Vec<C>,
+    same_size: impl Fn(&C, &C) -> bool,
+    f: F,
+) -> TResult<(C, T, Vec<C>)>
+where
+    F: Fn(&mut Context<std::io::Cursor<&Cow<'d, [u8]>>>, &C) -> TResult<T>,
+{
+    let start = reader.stream_position()?;
+    // values can only borrow from the data if the input lends it
+    let buf = match reader.stream.lend(size as usize) {
+        Some(data) => Cow::Borrowed(data),
+        None => {
+            reader.allocate(size as u64)?;
+            let mut buf = vec![0; size as usize];
+            reader.read_exact(&mut buf)?;
+            Cow::Owned(buf)
+        }
+    };
+    let attempt = |reader: &Context<R>, candidate: &C| {
+        let mut cursor = std::io::Cursor::new(&buf);
+        // diagnostics are only reported and allocations only kept once the candidate is accepted
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let allocated = reader.usage.allocated();
+        let result = f(
+            &mut Context {
+                stream: &mut cursor,
+                header: reader.header,
+                options: reader.options,
+                write_options: reader.write_options,
+                diagnostics: &diagnostics,
+                spans: None,
+                scope: reader.scope,
+                value_start: None,
+                recover: false,
+                usage: reader.usage,
+            },
+            candidate,
+        );
+        match result {
+            Ok(value) if cursor.position() == buf.len() as u64 => {
+                Some((value, diagnostics.into_inner()))
+            }
+            _ => {
+                reader.usage.restore(allocated);
+                None
+            }
+        }
+    };
+    let mut candidates = candidates.into_iter();
+    let (candidate, value, diagnostics) = candidates
+        .by_ref()
+        .find_map(|c| attempt(reader, &c).map(|(value, diagnostics)| (c, value, diagnostics)))
+        .ok_or_else(|| Error::UninferredStructType(reader.path()))?;
+    for diagnostic in diagnostics.iter() {
+        reader.diagnostics.borrow_mut().push(Diagnostic {
+            offset: diagnostic.offset + start as usize,
+            ..diagnostic.clone()
+        });
+    }
+    let ambiguous = candidates
+        .filter(|c| same_size(&candidate, c))
+        .filter(|c| {
+            let allocated = reader.usage.allocated();
+            let fits = attempt(reader, c).is_some();
+            reader.usage.restore(allocated);
+            fits
+        })
+        .collect();
+    Ok((candidate, value, ambiguous))
+}
+
+type DateTime = u64;
+type Timespan = i64;
+type FrameNumber = i32;
+type Int8 = i8;
 type Int16 = i16;
 type Int = i32;
 type Int64 = i64;
@@ -706,25 +1721,62 @@ type UInt64 = u64;
 type Float = f32;
 type Double = f64;
 type Bool = bool;
-type Enum = String;
+type Enum<'a> = FString<'a>;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct MapEntry {
-    pub key: PropertyValue,
-    pub value: PropertyValue,
+pub struct MapEntry<'a> {
+    pub key: PropertyValue<'a>,
+    pub value: PropertyValue<'a>,
+}
+/// Type information of map keys and values which is required to read them but not stored in the
+/// legacy property tag
+#[derive(Debug, Clone, PartialEq)]
+enum InnerType {
+    Simple,
+    /// `ByteProperty` with an enum, stored as a label rather than a raw byte
+    Enum,
+    Struct(StructType),
+}
+impl InnerType {
+    fn same_size(&self, other: &InnerType, large_world_coordinates: bool) -> bool {
+        match (self, other) {
+            (InnerType::Struct(a), InnerType::Struct(b)) => a.same_size(b, large_world_coordinates),
+            _ => false,
+        }
+    }
 }
-impl MapEntry {
-    fn read<R: Read + Seek>(
+
+impl<'a> MapEntry<'a> {
+    fn read<R: Input<'a>>(
         reader: &mut Context<R>,
         key_type: &PropertyType,
-        key_struct_type: Option<&StructType>,
+        key_inner_type: &InnerType,
         value_type: &PropertyType,
-        value_struct_type: Option<&StructType>,
-    ) -> TResult<MapEntry> {
-        let key = PropertyValue::read(reader, key_type, key_struct_type)?;
-        let value = PropertyValue::read(reader, value_type, value_struct_type)?;
+        value_inner_type: &InnerType,
+    ) -> TResult<Self> {
+        let key = reader.scope("Key", |r| PropertyValue::read(r, key_type, key_inner_type))?;
+        let value = reader.scope("Value", |r| {
+            PropertyValue::read(r, value_type, value_inner_type)
+        })?;
         Ok(Self { key, value })
     }
+    fn read_entries<R: Input<'a>>(
+        reader: &mut Context<R>,
+        key_type: &PropertyType,
+        key_inner_type: &InnerType,
+        value_type: &PropertyType,
+        value_inner_type: &InnerType,
+    ) -> TResult<Vec<Self>> {
+        let count = reader.read_u32::<LE>()?;
+        reader.elements::<MapEntry>(count)?;
+        (0..count as usize)
+            .map(|i| {
+                reader.index(i, |r| {
+                    MapEntry::read(r, key_type, key_inner_type, value_type, value_inner_type)
+                })
+            })
+            .collect()
+    }
     fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
         self.key.write(writer)?;
         self.value.write(writer)?;
@@ -733,12 +1785,12 @@ impl MapEntry {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct FieldPath {
-    path: Vec<String>,
-    owner: String,
+pub struct FieldPath<'a> {
+    path: Vec<FString<'a>>,
+    owner: FString<'a>,
 }
-impl FieldPath {
-    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+impl<'a> FieldPath<'a> {
+    fn read<R: Input<'a>>(reader: &mut Context<R>) -> TResult<Self> {
         Ok(Self {
             path: read_array(reader.read_u32::<LE>()?, reader, read_string)?,
             owner: read_string(reader)?,
@@ -755,12 +1807,12 @@ impl FieldPath {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Delegate {
-    name: String,
-    path: String,
+pub struct Delegate<'a> {
+    name: FString<'a>,
+    path: FString<'a>,
 }
-impl Delegate {
-    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+impl<'a> Delegate<'a> {
+    fn read<R: Input<'a>>(reader: &mut Context<R>) -> TResult<Self> {
         Ok(Self {
             name: read_string(reader)?,
             path: read_string(reader)?,
@@ -774,9 +1826,9 @@ impl Delegate {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct MulticastDelegate(Vec<Delegate>);
-impl MulticastDelegate {
-    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+pub struct MulticastDelegate<'a>(Vec<Delegate<'a>>);
+impl<'a> MulticastDelegate<'a> {
+    fn read<R: Input<'a>>(reader: &mut Context<R>) -> TResult<Self> {
         Ok(Self(read_array(
             reader.read_u32::<LE>()?,
             reader,
@@ -793,9 +1845,9 @@ impl MulticastDelegate {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct MulticastInlineDelegate(Vec<Delegate>);
-impl MulticastInlineDelegate {
-    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+pub struct MulticastInlineDelegate<'a>(Vec<Delegate<'a>>);
+impl<'a> MulticastInlineDelegate<'a> {
+    fn read<R: Input<'a>>(reader: &mut Context<R>) -> TResult<Self> {
         Ok(Self(read_array(
             reader.read_u32::<LE>()?,
             reader,
@@ -812,9 +1864,9 @@ impl MulticastInlineDelegate {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct MulticastSparseDelegate(Vec<Delegate>);
-impl MulticastSparseDelegate {
-    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+pub struct MulticastSparseDelegate<'a>(Vec<Delegate<'a>>);
+impl<'a> MulticastSparseDelegate<'a> {
+    fn read<R: Input<'a>>(reader: &mut Context<R>) -> TResult<Self> {
         Ok(Self(read_array(
             reader.read_u32::<LE>()?,
             reader,
@@ -863,7 +1915,7 @@ pub struct Quat {
 }
 impl Quat {
     fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
-        if reader.header.as_ref().unwrap().large_world_coordinates() {
+        if reader.large_world_coordinates() {
             Ok(Self {
                 x: reader.read_f64::<LE>()?,
                 y: reader.read_f64::<LE>()?,
@@ -880,7 +1932,7 @@ impl Quat {
         }
     }
     fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
-        if writer.header.as_ref().unwrap().large_world_coordinates() {
+        if writer.large_world_coordinates() {
             writer.write_f64::<LE>(self.x)?;
             writer.write_f64::<LE>(self.y)?;
             writer.write_f64::<LE>(self.z)?;
@@ -902,7 +1954,7 @@ pub struct Rotator {
 }
 impl Rotator {
     fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
-        if reader.header.as_ref().unwrap().large_world_coordinates() {
+        if reader.large_world_coordinates() {
             Ok(Self {
                 x: reader.read_f64::<LE>()?,
                 y: reader.read_f64::<LE>()?,
@@ -917,7 +1969,7 @@ impl Rotator {
         }
     }
     fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
-        if writer.header.as_ref().unwrap().large_world_coordinates() {
+        if writer.large_world_coordinates() {
             writer.write_f64::<LE>(self.x)?;
             writer.write_f64::<LE>(self.y)?;
             writer.write_f64::<LE>(self.z)?;
@@ -961,7 +2013,7 @@ pub struct Vector {
 }
 impl Vector {
     fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
-        if reader.header.as_ref().unwrap().large_world_coordinates() {
+        if reader.large_world_coordinates() {
             Ok(Self {
                 x: reader.read_f64::<LE>()?,
                 y: reader.read_f64::<LE>()?,
@@ -976,7 +2028,7 @@ impl Vector {
         }
     }
     fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
-        if writer.header.as_ref().unwrap().large_world_coordinates() {
+        if writer.large_world_coordinates() {
             writer.write_f64::<LE>(self.x)?;
             writer.write_f64::<LE>(self.y)?;
             writer.write_f64::<LE>(self.z)?;
@@ -995,7 +2047,7 @@ pub struct Vector2D {
 }
 impl Vector2D {
     fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
-        if reader.header.as_ref().unwrap().large_world_coordinates() {
+        if reader.large_world_coordinates() {
             Ok(Self {
                 x: reader.read_f64::<LE>()?,
                 y: reader.read_f64::<LE>()?,
@@ -1008,7 +2060,7 @@ impl Vector2D {
         }
     }
     fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
-        if writer.header.as_ref().unwrap().large_world_coordinates() {
+        if writer.large_world_coordinates() {
             writer.write_f64::<LE>(self.x)?;
             writer.write_f64::<LE>(self.y)?;
         } else {
@@ -1080,11 +2132,274 @@ impl IntPoint {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct GameplayTag {
-    pub name: String,
+pub struct Vector4 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+impl Vector4 {
+    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+        if reader.large_world_coordinates() {
+            Ok(Self {
+                x: reader.read_f64::<LE>()?,
+                y: reader.read_f64::<LE>()?,
+                z: reader.read_f64::<LE>()?,
+                w: reader.read_f64::<LE>()?,
+            })
+        } else {
+            Ok(Self {
+                x: reader.read_f32::<LE>()? as f64,
+                y: reader.read_f32::<LE>()? as f64,
+                z: reader.read_f32::<LE>()? as f64,
+                w: reader.read_f32::<LE>()? as f64,
+            })
+        }
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        if writer.large_world_coordinates() {
+            writer.write_f64::<LE>(self.x)?;
+            writer.write_f64::<LE>(self.y)?;
+            writer.write_f64::<LE>(self.z)?;
+            writer.write_f64::<LE>(self.w)?;
+        } else {
+            writer.write_f32::<LE>(self.x as f32)?;
+            writer.write_f32::<LE>(self.y as f32)?;
+            writer.write_f32::<LE>(self.z as f32)?;
+            writer.write_f32::<LE>(self.w as f32)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Plane {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+impl Plane {
+    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+        if reader.large_world_coordinates() {
+            Ok(Self {
+                x: reader.read_f64::<LE>()?,
+                y: reader.read_f64::<LE>()?,
+                z: reader.read_f64::<LE>()?,
+                w: reader.read_f64::<LE>()?,
+            })
+        } else {
+            Ok(Self {
+                x: reader.read_f32::<LE>()? as f64,
+                y: reader.read_f32::<LE>()? as f64,
+                z: reader.read_f32::<LE>()? as f64,
+                w: reader.read_f32::<LE>()? as f64,
+            })
+        }
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        if writer.large_world_coordinates() {
+            writer.write_f64::<LE>(self.x)?;
+            writer.write_f64::<LE>(self.y)?;
+            writer.write_f64::<LE>(self.z)?;
+            writer.write_f64::<LE>(self.w)?;
+        } else {
+            writer.write_f32::<LE>(self.x as f32)?;
+            writer.write_f32::<LE>(self.y as f32)?;
+            writer.write_f32::<LE>(self.z as f32)?;
+            writer.write_f32::<LE>(self.w as f32)?;
+        }
+        Ok(())
+    }
+}
+/// Stored row by row, each row being a [`Plane`]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Matrix {
+    pub x_plane: Plane,
+    pub y_plane: Plane,
+    pub z_plane: Plane,
+    pub w_plane: Plane,
+}
+impl Matrix {
+    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+        Ok(Self {
+            x_plane: Plane::read(reader)?,
+            y_plane: Plane::read(reader)?,
+            z_plane: Plane::read(reader)?,
+            w_plane: Plane::read(reader)?,
+        })
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        self.x_plane.write(writer)?;
+        self.y_plane.write(writer)?;
+        self.z_plane.write(writer)?;
+        self.w_plane.write(writer)?;
+        Ok(())
+    }
+}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Box2D {
+    pub min: Vector2D,
+    pub max: Vector2D,
+    pub is_valid: bool,
+}
+impl Box2D {
+    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+        Ok(Self {
+            min: Vector2D::read(reader)?,
+            max: Vector2D::read(reader)?,
+            // unlike FBox this is a bool which is serialized as 32 bits
+            is_valid: reader.read_u32::<LE>()? > 0,
+        })
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        self.min.write(writer)?;
+        self.max.write(writer)?;
+        writer.write_u32::<LE>(self.is_valid as u32)?;
+        Ok(())
+    }
+}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct IntVector2 {
+    pub x: i32,
+    pub y: i32,
+}
+impl IntVector2 {
+    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+        Ok(Self {
+            x: reader.read_i32::<LE>()?,
+            y: reader.read_i32::<LE>()?,
+        })
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        writer.write_i32::<LE>(self.x)?;
+        writer.write_i32::<LE>(self.y)?;
+        Ok(())
+    }
+}
+/// Single precision variant of [`Vector`], unaffected by large world coordinates
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Vector3f {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+impl Vector3f {
+    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+        Ok(Self {
+            x: reader.read_f32::<LE>()?,
+            y: reader.read_f32::<LE>()?,
+            z: reader.read_f32::<LE>()?,
+        })
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        writer.write_f32::<LE>(self.x)?;
+        writer.write_f32::<LE>(self.y)?;
+        writer.write_f32::<LE>(self.z)?;
+        Ok(())
+    }
+}
+/// Single precision variant of [`Vector2D`], unaffected by large world coordinates
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Vector2f {
+    pub x: f32,
+    pub y: f32,
+}
+impl Vector2f {
+    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+        Ok(Self {
+            x: reader.read_f32::<LE>()?,
+            y: reader.read_f32::<LE>()?,
+        })
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        writer.write_f32::<LE>(self.x)?;
+        writer.write_f32::<LE>(self.y)?;
+        Ok(())
+    }
+}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct UniqueNetIdRepl<'a> {
+    pub inner: Option<UniqueNetIdReplInner<'a>>,
+}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct UniqueNetIdReplInner<'a> {
+    pub size: u32,
+    pub contents: FString<'a>,
+    pub type_: FString<'a>,
+}
+impl<'a> UniqueNetIdRepl<'a> {
+    fn read<R: Input<'a>>(reader: &mut Context<R>) -> TResult<Self> {
+        let size = reader.read_u32::<LE>()?;
+        let inner = if size > 0 {
+            Some(UniqueNetIdReplInner {
+                size,
+                contents: read_string(reader)?,
+                type_: read_string(reader)?,
+            })
+        } else {
+            None
+        };
+        Ok(Self { inner })
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        match &self.inner {
+            Some(inner) => {
+                writer.write_u32::<LE>(inner.size)?;
+                write_string(writer, &inner.contents)?;
+                write_string(writer, &inner.type_)?;
+            }
+            None => writer.write_u32::<LE>(0)?,
+        }
+        Ok(())
+    }
+}
+/// Per platform overrides are editor only data and are not present in saves written by games
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct PerPlatformFloat {
+    pub cooked: bool,
+    pub default: f32,
+}
+impl PerPlatformFloat {
+    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+        Ok(Self {
+            cooked: reader.read_u32::<LE>()? > 0,
+            default: reader.read_f32::<LE>()?,
+        })
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        writer.write_u32::<LE>(self.cooked as u32)?;
+        writer.write_f32::<LE>(self.default)?;
+        Ok(())
+    }
+}
+/// See [`PerPlatformFloat`]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct PerPlatformInt {
+    pub cooked: bool,
+    pub default: i32,
 }
-impl GameplayTag {
+impl PerPlatformInt {
     fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+        Ok(Self {
+            cooked: reader.read_u32::<LE>()? > 0,
+            default: reader.read_i32::<LE>()?,
+        })
+    }
+    fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
+        writer.write_u32::<LE>(self.cooked as u32)?;
+        writer.write_i32::<LE>(self.default)?;
+        Ok(())
+    }
+}
+
+/// GameplayTag as serialized natively by some games. UE itself writes the `TagName` as a property
+/// list, this form is only read where [`Types`] has it.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GameplayTag<'a> {
+    pub name: FString<'a>,
+}
+impl<'a> GameplayTag<'a> {
+    fn read<R: Input<'a>>(reader: &mut Context<R>) -> TResult<Self> {
         Ok(Self {
             name: read_string(reader)?,
         })
@@ -1096,11 +2411,11 @@ impl GameplayTag {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct GameplayTagContainer {
-    pub gameplay_tags: Vec<GameplayTag>,
+pub struct GameplayTagContainer<'a> {
+    pub gameplay_tags: Vec<GameplayTag<'a>>,
 }
-impl GameplayTagContainer {
-    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+impl<'a> GameplayTagContainer<'a> {
+    fn read<R: Input<'a>>(reader: &mut Context<R>) -> TResult<Self> {
         Ok(Self {
             gameplay_tags: read_array(reader.read_u32::<LE>()?, reader, GameplayTag::read)?,
         })
@@ -1115,11 +2430,11 @@ impl GameplayTagContainer {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct FFormatArgumentData {
-    name: String,
-    value: FFormatArgumentDataValue,
+pub struct FFormatArgumentData<'a> {
+    name: FString<'a>,
+    value: FFormatArgumentDataValue<'a>,
 }
-impl<R: Read + Seek> Readable<R> for FFormatArgumentData {
+impl<'a, R: Input<'a>> Readable<R> for FFormatArgumentData<'a> {
     fn read(reader: &mut Context<R>) -> TResult<Self> {
         Ok(Self {
             name: read_string(reader)?,
@@ -1127,7 +2442,7 @@ impl<R: Read + Seek> Readable<R> for FFormatArgumentData {
         })
     }
 }
-impl<W: Write> Writable<W> for FFormatArgumentData {
+impl<W: Write> Writable<W> for FFormatArgumentData<'_> {
     fn write(&self, writer: &mut Context<W>) -> TResult<()> {
         write_string(writer, &self.name)?;
         self.value.write(writer)?;
@@ -1137,15 +2452,15 @@ impl<W: Write> Writable<W> for FFormatArgumentData {
 // very similar to FFormatArgumentValue but serializes ints as 32 bits (TODO changes to 64 bit
 // again at some later UE version)
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum FFormatArgumentDataValue {
+pub enum FFormatArgumentDataValue<'a> {
     Int(i32),
     UInt(u32),
     Float(f32),
     Double(f64),
-    Text(std::boxed::Box<Text>),
+    Text(std::boxed::Box<Text<'a>>),
     Gender(u64),
 }
-impl<R: Read + Seek> Readable<R> for FFormatArgumentDataValue {
+impl<'a, R: Input<'a>> Readable<R> for FFormatArgumentDataValue<'a> {
     fn read(reader: &mut Context<R>) -> TResult<Self> {
         let type_ = reader.read_u8()?;
         match type_ {
@@ -1161,7 +2476,7 @@ impl<R: Read + Seek> Readable<R> for FFormatArgumentDataValue {
         }
     }
 }
-impl<W: Write> Writable<W> for FFormatArgumentDataValue {
+impl<W: Write> Writable<W> for FFormatArgumentDataValue<'_> {
     fn write(&self, writer: &mut Context<W>) -> TResult<()> {
         match self {
             Self::Int(value) => {
@@ -1194,16 +2509,16 @@ impl<W: Write> Writable<W> for FFormatArgumentDataValue {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum FFormatArgumentValue {
+pub enum FFormatArgumentValue<'a> {
     Int(i64),
     UInt(u64),
     Float(f32),
     Double(f64),
-    Text(std::boxed::Box<Text>),
+    Text(std::boxed::Box<Text<'a>>),
     Gender(u64),
 }
 
-impl<R: Read + Seek> Readable<R> for FFormatArgumentValue {
+impl<'a, R: Input<'a>> Readable<R> for FFormatArgumentValue<'a> {
     fn read(reader: &mut Context<R>) -> TResult<Self> {
         let type_ = reader.read_u8()?;
         match type_ {
@@ -1219,7 +2534,7 @@ impl<R: Read + Seek> Readable<R> for FFormatArgumentValue {
         }
     }
 }
-impl<W: Write> Writable<W> for FFormatArgumentValue {
+impl<W: Write> Writable<W> for FFormatArgumentValue<'_> {
     fn write(&self, writer: &mut Context<W>) -> TResult<()> {
         match self {
             Self::Int(value) => {
@@ -1288,50 +2603,110 @@ impl<W: Write> Writable<W> for FNumberFormattingOptions {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Text {
+pub struct Text<'a> {
     flags: u32,
-    variant: TextVariant,
+    variant: TextVariant<'a>,
 }
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum TextVariant {
+pub enum TextVariant<'a> {
     // -0x1
     None {
-        culture_invariant: Option<String>,
+        culture_invariant: Option<FString<'a>>,
     },
     // 0x0
     Base {
         namespace: (String, Vec<u8>),
-        key: String,
-        source_string: String,
+        key: FString<'a>,
+        source_string: FString<'a>,
+    },
+    // 0x1
+    NamedFormat {
+        source_format: std::boxed::Box<Text<'a>>,
+        arguments: indexmap::IndexMap<FString<'a>, FFormatArgumentValue<'a>>,
+    },
+    // 0x2
+    OrderedFormat {
+        source_format: std::boxed::Box<Text<'a>>,
+        arguments: Vec<FFormatArgumentValue<'a>>,
     },
     // 0x3
     ArgumentFormat {
         // aka ArgumentDataFormat
-        format_text: std::boxed::Box<Text>,
-        arguments: Vec<FFormatArgumentData>,
+        format_text: std::boxed::Box<Text<'a>>,
+        arguments: Vec<FFormatArgumentData<'a>>,
     },
     // 0x4
     AsNumber {
-        source_value: FFormatArgumentValue,
+        source_value: FFormatArgumentValue<'a>,
+        format_options: Option<FNumberFormattingOptions>,
+        culture_name: FString<'a>,
+    },
+    // 0x5
+    AsPercent {
+        source_value: FFormatArgumentValue<'a>,
+        format_options: Option<FNumberFormattingOptions>,
+        culture_name: FString<'a>,
+    },
+    // 0x6
+    AsCurrency {
+        currency_code: FString<'a>,
+        source_value: FFormatArgumentValue<'a>,
         format_options: Option<FNumberFormattingOptions>,
-        culture_name: String,
+        culture_name: FString<'a>,
     },
     // 0x7
     AsDate {
         source_date_time: DateTime,
         date_style: i8, // TODO EDateTimeStyle::Type
-        time_zone: String,
-        culture_name: String,
+        time_zone: FString<'a>,
+        culture_name: FString<'a>,
+    },
+    // 0x8
+    AsTime {
+        source_date_time: DateTime,
+        time_style: i8,
+        time_zone: FString<'a>,
+        culture_name: FString<'a>,
+    },
+    // 0x9
+    AsDateTime {
+        source_date_time: DateTime,
+        date_style: i8,
+        time_style: i8,
+        /// Only present if `date_style` is EDateTimeStyle::Custom
+        custom_pattern: Option<FString<'a>>,
+        time_zone: FString<'a>,
+        culture_name: FString<'a>,
+    },
+    // 0xa
+    Transform {
+        source_text: std::boxed::Box<Text<'a>>,
+        transform_type: u8, // TODO enum ETransformType
     },
     StringTableEntry {
         // 0xb
-        table: String,
-        key: String,
+        table: FString<'a>,
+        key: FString<'a>,
+    },
+    // 0xc
+    TextGenerator {
+        generator_type: FString<'a>,
+        /// Serialized generator, only present if `generator_type` is not "None"
+        generator_contents: Option<Vec<u8>>,
     },
 }
 
-impl<R: Read + Seek> Readable<R> for Text {
+// EDateTimeStyle::Custom
+const DATE_TIME_STYLE_CUSTOM: i8 = 5;
+
+impl<'a, R: Input<'a>> Readable<R> for Text<'a> {
     fn read(reader: &mut Context<R>) -> TResult<Self> {
+        // texts may be nested in format arguments
+        reader.nested(Text::read_text)
+    }
+}
+impl<'a> Text<'a> {
+    fn read_text<R: Input<'a>>(reader: &mut Context<R>) -> TResult<Self> {
         let flags = reader.read_u32::<LE>()?;
         let text_history_type = reader.read_i8()?;
         let variant = match text_history_type {
@@ -1345,6 +2720,22 @@ impl<R: Read + Seek> Readable<R> for Text {
                 key: read_string(reader)?,
                 source_string: read_string(reader)?,
             }),
+            0x1 => Ok(TextVariant::NamedFormat {
+                source_format: std::boxed::Box::new(Text::read(reader)?),
+                arguments: read_array(reader.read_u32::<LE>()?, reader, |r| {
+                    Ok((read_string(r)?, FFormatArgumentValue::read(r)?))
+                })?
+                .into_iter()
+                .collect(),
+            }),
+            0x2 => Ok(TextVariant::OrderedFormat {
+                source_format: std::boxed::Box::new(Text::read(reader)?),
+                arguments: read_array(
+                    reader.read_u32::<LE>()?,
+                    reader,
+                    FFormatArgumentValue::read,
+                )?,
+            }),
             0x3 => Ok(TextVariant::ArgumentFormat {
                 format_text: std::boxed::Box::new(Text::read(reader)?),
                 arguments: read_array(reader.read_u32::<LE>()?, reader, FFormatArgumentData::read)?,
@@ -1357,18 +2748,68 @@ impl<R: Read + Seek> Readable<R> for Text {
                         .transpose()?,
                 culture_name: read_string(reader)?,
             }),
+            0x5 => Ok(TextVariant::AsPercent {
+                source_value: FFormatArgumentValue::read(reader)?,
+                format_options:
+                    (reader.read_u32::<LE>()? != 0) // bHasFormatOptions
+                        .then(|| FNumberFormattingOptions::read(reader))
+                        .transpose()?,
+                culture_name: read_string(reader)?,
+            }),
+            0x6 => Ok(TextVariant::AsCurrency {
+                currency_code: read_string(reader)?,
+                source_value: FFormatArgumentValue::read(reader)?,
+                format_options:
+                    (reader.read_u32::<LE>()? != 0) // bHasFormatOptions
+                        .then(|| FNumberFormattingOptions::read(reader))
+                        .transpose()?,
+                culture_name: read_string(reader)?,
+            }),
             0x7 => Ok(TextVariant::AsDate {
                 source_date_time: reader.read_u64::<LE>()?,
                 date_style: reader.read_i8()?,
                 time_zone: read_string(reader)?,
                 culture_name: read_string(reader)?,
             }),
+            0x8 => Ok(TextVariant::AsTime {
+                source_date_time: reader.read_u64::<LE>()?,
+                time_style: reader.read_i8()?,
+                time_zone: read_string(reader)?,
+                culture_name: read_string(reader)?,
+            }),
+            0x9 => Ok({
+                let source_date_time = reader.read_u64::<LE>()?;
+                let date_style = reader.read_i8()?;
+                TextVariant::AsDateTime {
+                    source_date_time,
+                    date_style,
+                    time_style: reader.read_i8()?,
+                    custom_pattern: (date_style == DATE_TIME_STYLE_CUSTOM)
+                        .then(|| read_string(reader))
+                        .transpose()?,
+                    time_zone: read_string(reader)?,
+                    culture_name: read_string(reader)?,
+                }
+            }),
+            0xa => Ok(TextVariant::Transform {
+                source_text: std::boxed::Box::new(Text::read(reader)?),
+                transform_type: reader.read_u8()?,
+            }),
             0xb => Ok({
                 TextVariant::StringTableEntry {
                     table: read_string(reader)?,
                     key: read_string(reader)?,
                 }
             }),
+            0xc => Ok({
+                let generator_type = read_string(reader)?;
+                TextVariant::TextGenerator {
+                    generator_contents: (generator_type != "None")
+                        .then(|| read_array(reader.read_u32::<LE>()?, reader, |r| Ok(r.read_u8()?)))
+                        .transpose()?,
+                    generator_type,
+                }
+            }),
             _ => Err(Error::Other(format!(
                 "unimplemented variant for FTextHistory 0x{text_history_type:x}"
             ))),
@@ -1376,7 +2817,7 @@ impl<R: Read + Seek> Readable<R> for Text {
         Ok(Self { flags, variant })
     }
 }
-impl<W: Write> Writable<W> for Text {
+impl<W: Write> Writable<W> for Text<'_> {
     fn write(&self, writer: &mut Context<W>) -> TResult<()> {
         writer.write_u32::<LE>(self.flags)?;
         match &self.variant {
@@ -1400,6 +2841,29 @@ impl<W: Write> Writable<W> for Text {
                 write_string(writer, key)?;
                 write_string(writer, source_string)?;
             }
+            TextVariant::NamedFormat {
+                source_format,
+                arguments,
+            } => {
+                writer.write_i8(0x1)?;
+                source_format.write(writer)?;
+                writer.write_u32::<LE>(arguments.len() as u32)?;
+                for (name, value) in arguments {
+                    write_string(writer, name)?;
+                    value.write(writer)?;
+                }
+            }
+            TextVariant::OrderedFormat {
+                source_format,
+                arguments,
+            } => {
+                writer.write_i8(0x2)?;
+                source_format.write(writer)?;
+                writer.write_u32::<LE>(arguments.len() as u32)?;
+                for a in arguments {
+                    a.write(writer)?;
+                }
+            }
             TextVariant::ArgumentFormat {
                 format_text,
                 arguments,
@@ -1424,6 +2888,34 @@ impl<W: Write> Writable<W> for Text {
                 }
                 write_string(writer, culture_name)?;
             }
+            TextVariant::AsPercent {
+                source_value,
+                format_options,
+                culture_name,
+            } => {
+                writer.write_i8(0x5)?;
+                source_value.write(writer)?;
+                writer.write_u32::<LE>(format_options.is_some() as u32)?;
+                if let Some(format_options) = format_options {
+                    format_options.write(writer)?;
+                }
+                write_string(writer, culture_name)?;
+            }
+            TextVariant::AsCurrency {
+                currency_code,
+                source_value,
+                format_options,
+                culture_name,
+            } => {
+                writer.write_i8(0x6)?;
+                write_string(writer, currency_code)?;
+                source_value.write(writer)?;
+                writer.write_u32::<LE>(format_options.is_some() as u32)?;
+                if let Some(format_options) = format_options {
+                    format_options.write(writer)?;
+                }
+                write_string(writer, culture_name)?;
+            }
             TextVariant::AsDate {
                 source_date_time,
                 date_style,
@@ -1436,11 +2928,60 @@ impl<W: Write> Writable<W> for Text {
                 write_string(writer, time_zone)?;
                 write_string(writer, culture_name)?;
             }
+            TextVariant::AsTime {
+                source_date_time,
+                time_style,
+                time_zone,
+                culture_name,
+            } => {
+                writer.write_i8(0x8)?;
+                writer.write_u64::<LE>(*source_date_time)?;
+                writer.write_i8(*time_style)?;
+                write_string(writer, time_zone)?;
+                write_string(writer, culture_name)?;
+            }
+            TextVariant::AsDateTime {
+                source_date_time,
+                date_style,
+                time_style,
+                custom_pattern,
+                time_zone,
+                culture_name,
+            } => {
+                writer.write_i8(0x9)?;
+                writer.write_u64::<LE>(*source_date_time)?;
+                writer.write_i8(*date_style)?;
+                writer.write_i8(*time_style)?;
+                if let Some(custom_pattern) = custom_pattern {
+                    write_string(writer, custom_pattern)?;
+                }
+                write_string(writer, time_zone)?;
+                write_string(writer, culture_name)?;
+            }
+            TextVariant::Transform {
+                source_text,
+                transform_type,
+            } => {
+                writer.write_i8(0xa)?;
+                source_text.write(writer)?;
+                writer.write_u8(*transform_type)?;
+            }
             TextVariant::StringTableEntry { table, key } => {
                 writer.write_i8(0xb)?;
                 write_string(writer, table)?;
                 write_string(writer, key)?;
             }
+            TextVariant::TextGenerator {
+                generator_type,
+                generator_contents,
+            } => {
+                writer.write_i8(0xc)?;
+                write_string(writer, generator_type)?;
+                if let Some(generator_contents) = generator_contents {
+                    writer.write_u32::<LE>(generator_contents.len() as u32)?;
+                    writer.write_all(generator_contents)?;
+                }
+            }
         }
         Ok(())
     }
@@ -1448,40 +2989,48 @@ impl<W: Write> Writable<W> for Text {
 
 /// Just a plain byte, or an enum in which case the variant will be a String
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum Byte {
+pub enum Byte<'a> {
     Byte(u8),
-    Label(String),
+    Label(FString<'a>),
 }
 /// Vectorized [`Byte`]
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum ByteArray {
-    Byte(Vec<u8>),
-    Label(Vec<String>),
+pub enum ByteArray<'a> {
+    Byte(Cow<'a, [u8]>),
+    Label(Vec<FString<'a>>),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum PropertyValue {
+pub enum PropertyValue<'a> {
     Int(Int),
     Int8(Int8),
     Int16(Int16),
     Int64(Int64),
+    UInt8(UInt8),
     UInt16(UInt16),
     UInt32(UInt32),
+    UInt64(UInt64),
     Float(Float),
     Double(Double),
     Bool(Bool),
-    Byte(Byte),
-    Enum(Enum),
-    Name(String),
-    Str(String),
-    SoftObject(String, String),
-    SoftObjectPath(String, String, String),
-    Object(String),
-    Struct(StructValue),
+    Byte(Byte<'a>),
+    Enum(Enum<'a>),
+    Name(FString<'a>),
+    Str(FString<'a>),
+    SoftObject(FString<'a>, FString<'a>),
+    SoftObjectPath(FString<'a>, FString<'a>, FString<'a>),
+    Object(FString<'a>),
+    Text(Text<'a>),
+    FieldPath(FieldPath<'a>),
+    Delegate(Delegate<'a>),
+    MulticastDelegate(MulticastDelegate<'a>),
+    MulticastInlineDelegate(MulticastInlineDelegate<'a>),
+    MulticastSparseDelegate(MulticastSparseDelegate<'a>),
+    Struct(StructValue<'a>),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum StructValue {
+pub enum StructValue<'a> {
     Guid(uuid::Uuid),
     DateTime(DateTime),
     Timespan(Timespan),
@@ -1494,15 +3043,28 @@ pub enum StructValue {
     LinearColor(LinearColor),
     Color(Color),
     Rotator(Rotator),
-    SoftObjectPath(String, String, String),
-    GameplayTagContainer(GameplayTagContainer),
+    SoftObjectPath(FString<'a>, FString<'a>, FString<'a>),
+    GameplayTagContainer(GameplayTagContainer<'a>),
+    Vector4(Vector4),
+    Plane(Plane),
+    Matrix(Matrix),
+    Box2D(Box2D),
+    IntVector2(IntVector2),
+    Vector3f(Vector3f),
+    Vector2f(Vector2f),
+    SoftClassPath(FString<'a>, FString<'a>, FString<'a>),
+    FrameNumber(FrameNumber),
+    UniqueNetIdRepl(UniqueNetIdRepl<'a>),
+    PerPlatformFloat(PerPlatformFloat),
+    PerPlatformInt(PerPlatformInt),
+    GameplayTag(GameplayTag<'a>),
     /// User defined struct which is simply a list of properties
-    Struct(Properties),
+    Struct(Properties<'a>),
 }
 
 /// Vectorized properties to avoid storing the variant with each value
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum ValueVec {
+pub enum ValueVec<'a> {
     Int8(Vec<Int8>),
     Int16(Vec<Int16>),
     Int(Vec<Int>),
@@ -1514,48 +3076,57 @@ pub enum ValueVec {
     Float(Vec<Float>),
     Double(Vec<Double>),
     Bool(Vec<bool>),
-    Byte(ByteArray),
-    Enum(Vec<Enum>),
-    Str(Vec<String>),
-    Text(Vec<Text>),
-    SoftObject(Vec<(String, String)>),
-    Name(Vec<String>),
-    Object(Vec<String>),
+    Byte(ByteArray<'a>),
+    Enum(Vec<Enum<'a>>),
+    /// Enums stored as their raw byte value rather than by name
+    EnumByte(Vec<UInt8>),
+    Str(Vec<FString<'a>>),
+    Text(Vec<Text<'a>>),
+    SoftObject(Vec<(FString<'a>, FString<'a>)>),
+    Name(Vec<FString<'a>>),
+    Object(Vec<FString<'a>>),
+    FieldPath(Vec<FieldPath<'a>>),
+    Delegate(Vec<Delegate<'a>>),
+    MulticastDelegate(Vec<MulticastDelegate<'a>>),
+    MulticastInlineDelegate(Vec<MulticastInlineDelegate<'a>>),
+    MulticastSparseDelegate(Vec<MulticastSparseDelegate<'a>>),
     Box(Vec<Box>),
 }
 
 /// Encapsulates [`ValueVec`] with a special handling of structs. See also: [`ValueSet`]
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum ValueArray {
-    Base(ValueVec),
+pub enum ValueArray<'a> {
+    Base(ValueVec<'a>),
     Struct {
-        _type: String,
-        name: String,
+        _type: FString<'a>,
+        name: FString<'a>,
         struct_type: StructType,
         id: uuid::Uuid,
-        value: Vec<StructValue>,
+        value: Vec<StructValue<'a>>,
     },
 }
 /// Encapsulates [`ValueVec`] with a special handling of structs. See also: [`ValueArray`]
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum ValueSet {
-    Base(ValueVec),
-    Struct(Vec<StructValue>),
+pub enum ValueSet<'a> {
+    Base(ValueVec<'a>),
+    Struct(Vec<StructValue<'a>>),
 }
 
-impl PropertyValue {
-    fn read<R: Read + Seek>(
+impl<'a> PropertyValue<'a> {
+    fn read<R: Input<'a>>(
         reader: &mut Context<R>,
         t: &PropertyType,
-        st: Option<&StructType>,
-    ) -> TResult<PropertyValue> {
+        inner_type: &InnerType,
+    ) -> TResult<Self> {
         Ok(match t {
             PropertyType::IntProperty => PropertyValue::Int(reader.read_i32::<LE>()?),
             PropertyType::Int8Property => PropertyValue::Int8(reader.read_i8()?),
             PropertyType::Int16Property => PropertyValue::Int16(reader.read_i16::<LE>()?),
             PropertyType::Int64Property => PropertyValue::Int64(reader.read_i64::<LE>()?),
+            PropertyType::UInt8Property => PropertyValue::UInt8(reader.read_u8()?),
             PropertyType::UInt16Property => PropertyValue::UInt16(reader.read_u16::<LE>()?),
             PropertyType::UInt32Property => PropertyValue::UInt32(reader.read_u32::<LE>()?),
+            PropertyType::UInt64Property => PropertyValue::UInt64(reader.read_u64::<LE>()?),
             PropertyType::FloatProperty => PropertyValue::Float(reader.read_f32::<LE>()?),
             PropertyType::DoubleProperty => PropertyValue::Double(reader.read_f64::<LE>()?),
             PropertyType::BoolProperty => PropertyValue::Bool(reader.read_u8()? > 0),
@@ -1565,12 +3136,32 @@ impl PropertyValue {
                 PropertyValue::SoftObject(read_string(reader)?, read_string(reader)?)
             }
             PropertyType::ObjectProperty => PropertyValue::Object(read_string(reader)?),
-            PropertyType::ByteProperty => PropertyValue::Byte(Byte::Label(read_string(reader)?)),
+            PropertyType::ByteProperty => PropertyValue::Byte(match inner_type {
+                InnerType::Enum => Byte::Label(read_string(reader)?),
+                _ => Byte::Byte(reader.read_u8()?),
+            }),
             PropertyType::EnumProperty => PropertyValue::Enum(read_string(reader)?),
-            PropertyType::StructProperty => {
-                PropertyValue::Struct(StructValue::read(reader, st.as_ref().unwrap())?)
+            PropertyType::TextProperty => PropertyValue::Text(Text::read(reader)?),
+            PropertyType::FieldPathProperty => PropertyValue::FieldPath(FieldPath::read(reader)?),
+            PropertyType::DelegateProperty => PropertyValue::Delegate(Delegate::read(reader)?),
+            PropertyType::MulticastDelegateProperty => {
+                PropertyValue::MulticastDelegate(MulticastDelegate::read(reader)?)
+            }
+            PropertyType::MulticastInlineDelegateProperty => {
+                PropertyValue::MulticastInlineDelegate(MulticastInlineDelegate::read(reader)?)
+            }
+            PropertyType::MulticastSparseDelegateProperty => {
+                PropertyValue::MulticastSparseDelegate(MulticastSparseDelegate::read(reader)?)
+            }
+            PropertyType::StructProperty => match inner_type {
+                InnerType::Struct(st) => PropertyValue::Struct(StructValue::read(reader, st)?),
+                _ => return Err(Error::Other("missing StructType of struct value".into())),
+            },
+            PropertyType::ArrayProperty | PropertyType::SetProperty | PropertyType::MapProperty => {
+                return Err(Error::Other(format!(
+                    "nested containers are not supported by Unreal Engine: {t:?}"
+                )))
             }
-            _ => return Err(Error::Other(format!("unimplemented property {t:?}"))),
         })
     }
     fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
@@ -1579,8 +3170,10 @@ impl PropertyValue {
             PropertyValue::Int8(v) => writer.write_i8(*v)?,
             PropertyValue::Int16(v) => writer.write_i16::<LE>(*v)?,
             PropertyValue::Int64(v) => writer.write_i64::<LE>(*v)?,
+            PropertyValue::UInt8(v) => writer.write_u8(*v)?,
             PropertyValue::UInt16(v) => writer.write_u16::<LE>(*v)?,
             PropertyValue::UInt32(v) => writer.write_u32::<LE>(*v)?,
+            PropertyValue::UInt64(v) => writer.write_u64::<LE>(*v)?,
             PropertyValue::Float(v) => writer.write_f32::<LE>(*v)?,
             PropertyValue::Double(v) => writer.write_f64::<LE>(*v)?,
             PropertyValue::Bool(v) => writer.write_u8(u8::from(*v))?,
@@ -1601,13 +3194,19 @@ impl PropertyValue {
                 Byte::Label(l) => write_string(writer, l)?,
             },
             PropertyValue::Enum(v) => write_string(writer, v)?,
+            PropertyValue::Text(v) => v.write(writer)?,
+            PropertyValue::FieldPath(v) => v.write(writer)?,
+            PropertyValue::Delegate(v) => v.write(writer)?,
+            PropertyValue::MulticastDelegate(v) => v.write(writer)?,
+            PropertyValue::MulticastInlineDelegate(v) => v.write(writer)?,
+            PropertyValue::MulticastSparseDelegate(v) => v.write(writer)?,
             PropertyValue::Struct(v) => v.write(writer)?,
         };
         Ok(())
     }
 }
-impl StructValue {
-    fn read<R: Read + Seek>(reader: &mut Context<R>, t: &StructType) -> TResult<StructValue> {
+impl<'a> StructValue<'a> {
+    fn read<R: Input<'a>>(reader: &mut Context<R>, t: &StructType) -> TResult<Self> {
         Ok(match t {
             StructType::Guid => StructValue::Guid(uuid::Uuid::read(reader)?),
             StructType::DateTime => StructValue::DateTime(reader.read_u64::<LE>()?),
@@ -1629,6 +3228,29 @@ impl StructValue {
             StructType::GameplayTagContainer => {
                 StructValue::GameplayTagContainer(GameplayTagContainer::read(reader)?)
             }
+            StructType::Vector4 => StructValue::Vector4(Vector4::read(reader)?),
+            StructType::Plane => StructValue::Plane(Plane::read(reader)?),
+            StructType::Matrix => StructValue::Matrix(Matrix::read(reader)?),
+            StructType::Box2D => StructValue::Box2D(Box2D::read(reader)?),
+            StructType::IntVector2 => StructValue::IntVector2(IntVector2::read(reader)?),
+            StructType::Vector3f => StructValue::Vector3f(Vector3f::read(reader)?),
+            StructType::Vector2f => StructValue::Vector2f(Vector2f::read(reader)?),
+            StructType::SoftClassPath => StructValue::SoftClassPath(
+                read_string(reader)?,
+                read_string(reader)?,
+                read_string(reader)?,
+            ),
+            StructType::FrameNumber => StructValue::FrameNumber(reader.read_i32::<LE>()?),
+            StructType::UniqueNetIdRepl => {
+                StructValue::UniqueNetIdRepl(UniqueNetIdRepl::read(reader)?)
+            }
+            StructType::PerPlatformFloat => {
+                StructValue::PerPlatformFloat(PerPlatformFloat::read(reader)?)
+            }
+            StructType::PerPlatformInt => {
+                StructValue::PerPlatformInt(PerPlatformInt::read(reader)?)
+            }
+            StructType::GameplayTag => StructValue::GameplayTag(GameplayTag::read(reader)?),
 
             StructType::Struct(_) => StructValue::Struct(read_properties_until_none(reader)?),
         })
@@ -1653,19 +3275,72 @@ impl StructValue {
                 write_string(writer, c)?;
             }
             StructValue::GameplayTagContainer(v) => v.write(writer)?,
+            StructValue::Vector4(v) => v.write(writer)?,
+            StructValue::Plane(v) => v.write(writer)?,
+            StructValue::Matrix(v) => v.write(writer)?,
+            StructValue::Box2D(v) => v.write(writer)?,
+            StructValue::IntVector2(v) => v.write(writer)?,
+            StructValue::Vector3f(v) => v.write(writer)?,
+            StructValue::Vector2f(v) => v.write(writer)?,
+            StructValue::SoftClassPath(a, b, c) => {
+                write_string(writer, a)?;
+                write_string(writer, b)?;
+                write_string(writer, c)?;
+            }
+            StructValue::FrameNumber(v) => writer.write_i32::<LE>(*v)?,
+            StructValue::UniqueNetIdRepl(v) => v.write(writer)?,
+            StructValue::PerPlatformFloat(v) => v.write(writer)?,
+            StructValue::PerPlatformInt(v) => v.write(writer)?,
+            StructValue::GameplayTag(v) => v.write(writer)?,
             StructValue::Struct(v) => write_properties_none_terminated(writer, v)?,
         }
         Ok(())
     }
 }
-impl ValueVec {
-    fn read<R: Read + Seek>(
+impl<'a> ValueVec<'a> {
+    pub fn len(&self) -> usize {
+        match self {
+            ValueVec::Int8(v) => v.len(),
+            ValueVec::Int16(v) => v.len(),
+            ValueVec::Int(v) => v.len(),
+            ValueVec::Int64(v) => v.len(),
+            ValueVec::UInt8(v) => v.len(),
+            ValueVec::UInt16(v) => v.len(),
+            ValueVec::UInt32(v) => v.len(),
+            ValueVec::UInt64(v) => v.len(),
+            ValueVec::Float(v) => v.len(),
+            ValueVec::Double(v) => v.len(),
+            ValueVec::Bool(v) => v.len(),
+            ValueVec::Byte(ByteArray::Byte(v)) => v.len(),
+            ValueVec::Byte(ByteArray::Label(v)) => v.len(),
+            ValueVec::Enum(v) => v.len(),
+            ValueVec::EnumByte(v) => v.len(),
+            ValueVec::Str(v) => v.len(),
+            ValueVec::Text(v) => v.len(),
+            ValueVec::SoftObject(v) => v.len(),
+            ValueVec::Name(v) => v.len(),
+            ValueVec::Object(v) => v.len(),
+            ValueVec::FieldPath(v) => v.len(),
+            ValueVec::Delegate(v) => v.len(),
+            ValueVec::MulticastDelegate(v) => v.len(),
+            ValueVec::MulticastInlineDelegate(v) => v.len(),
+            ValueVec::MulticastSparseDelegate(v) => v.len(),
+            ValueVec::Box(v) => v.len(),
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn read<R: Input<'a>>(
         reader: &mut Context<R>,
         t: &PropertyType,
         size: u32,
         count: u32,
-    ) -> TResult<ValueVec> {
+    ) -> TResult<Self> {
         Ok(match t {
+            PropertyType::Int8Property => {
+                ValueVec::Int8(read_array(count, reader, |r| Ok(r.read_i8()?))?)
+            }
             PropertyType::IntProperty => {
                 ValueVec::Int(read_array(count, reader, |r| Ok(r.read_i32::<LE>()?))?)
             }
@@ -1675,12 +3350,18 @@ impl ValueVec {
             PropertyType::Int64Property => {
                 ValueVec::Int64(read_array(count, reader, |r| Ok(r.read_i64::<LE>()?))?)
             }
+            PropertyType::UInt8Property => {
+                ValueVec::UInt8(read_array(count, reader, |r| Ok(r.read_u8()?))?)
+            }
             PropertyType::UInt16Property => {
                 ValueVec::UInt16(read_array(count, reader, |r| Ok(r.read_u16::<LE>()?))?)
             }
             PropertyType::UInt32Property => {
                 ValueVec::UInt32(read_array(count, reader, |r| Ok(r.read_u32::<LE>()?))?)
             }
+            PropertyType::UInt64Property => {
+                ValueVec::UInt64(read_array(count, reader, |r| Ok(r.read_u64::<LE>()?))?)
+            }
             PropertyType::FloatProperty => {
                 ValueVec::Float(read_array(count, reader, |r| Ok(r.read_f32::<LE>()?))?)
             }
@@ -1692,9 +3373,7 @@ impl ValueVec {
             }
             PropertyType::ByteProperty => {
                 if size == count {
-                    ValueVec::Byte(ByteArray::Byte(read_array(count, reader, |r| {
-                        Ok(r.read_u8()?)
-                    })?))
+                    ValueVec::Byte(ByteArray::Byte(reader.read_bytes(count as usize)?))
                 } else {
                     ValueVec::Byte(ByteArray::Label(read_array(count, reader, |r| {
                         read_string(r)
@@ -1702,7 +3381,11 @@ impl ValueVec {
                 }
             }
             PropertyType::EnumProperty => {
-                ValueVec::Enum(read_array(count, reader, |r| read_string(r))?)
+                if size == count && count > 0 {
+                    ValueVec::EnumByte(read_array(count, reader, |r| Ok(r.read_u8()?))?)
+                } else {
+                    ValueVec::Enum(read_array(count, reader, |r| read_string(r))?)
+                }
             }
             PropertyType::StrProperty => ValueVec::Str(read_array(count, reader, read_string)?),
             PropertyType::TextProperty => ValueVec::Text(read_array(count, reader, Text::read)?),
@@ -1715,6 +3398,21 @@ impl ValueVec {
             PropertyType::ObjectProperty => {
                 ValueVec::Object(read_array(count, reader, read_string)?)
             }
+            PropertyType::FieldPathProperty => {
+                ValueVec::FieldPath(read_array(count, reader, FieldPath::read)?)
+            }
+            PropertyType::DelegateProperty => {
+                ValueVec::Delegate(read_array(count, reader, Delegate::read)?)
+            }
+            PropertyType::MulticastDelegateProperty => {
+                ValueVec::MulticastDelegate(read_array(count, reader, MulticastDelegate::read)?)
+            }
+            PropertyType::MulticastInlineDelegateProperty => ValueVec::MulticastInlineDelegate(
+                read_array(count, reader, MulticastInlineDelegate::read)?,
+            ),
+            PropertyType::MulticastSparseDelegateProperty => ValueVec::MulticastSparseDelegate(
+                read_array(count, reader, MulticastSparseDelegate::read)?,
+            ),
             _ => return Err(Error::UnknownVecType(format!("{t:?}"))),
         })
     }
@@ -1789,9 +3487,7 @@ impl ValueVec {
             ValueVec::Byte(v) => match v {
                 ByteArray::Byte(b) => {
                     writer.write_u32::<LE>(b.len() as u32)?;
-                    for b in b {
-                        writer.write_u8(*b)?;
-                    }
+                    writer.write_all(b)?;
                 }
                 ByteArray::Label(l) => {
                     writer.write_u32::<LE>(l.len() as u32)?;
@@ -1806,6 +3502,12 @@ impl ValueVec {
                     write_string(writer, i)?;
                 }
             }
+            ValueVec::EnumByte(v) => {
+                writer.write_u32::<LE>(v.len() as u32)?;
+                for i in v {
+                    writer.write_u8(*i)?;
+                }
+            }
             ValueVec::Str(v) | ValueVec::Object(v) | ValueVec::Name(v) => {
                 writer.write_u32::<LE>(v.len() as u32)?;
                 for i in v {
@@ -1825,6 +3527,36 @@ impl ValueVec {
                     write_string(writer, b)?;
                 }
             }
+            ValueVec::FieldPath(v) => {
+                writer.write_u32::<LE>(v.len() as u32)?;
+                for i in v {
+                    i.write(writer)?;
+                }
+            }
+            ValueVec::Delegate(v) => {
+                writer.write_u32::<LE>(v.len() as u32)?;
+                for i in v {
+                    i.write(writer)?;
+                }
+            }
+            ValueVec::MulticastDelegate(v) => {
+                writer.write_u32::<LE>(v.len() as u32)?;
+                for i in v {
+                    i.write(writer)?;
+                }
+            }
+            ValueVec::MulticastInlineDelegate(v) => {
+                writer.write_u32::<LE>(v.len() as u32)?;
+                for i in v {
+                    i.write(writer)?;
+                }
+            }
+            ValueVec::MulticastSparseDelegate(v) => {
+                writer.write_u32::<LE>(v.len() as u32)?;
+                for i in v {
+                    i.write(writer)?;
+                }
+            }
             ValueVec::Box(v) => {
                 writer.write_u32::<LE>(v.len() as u32)?;
                 for i in v {
@@ -1835,24 +3567,50 @@ impl ValueVec {
         Ok(())
     }
 }
-impl ValueArray {
-    fn read<R: Read + Seek>(
+impl<'a> ValueArray<'a> {
+    fn read<R: Input<'a>>(
         reader: &mut Context<R>,
         t: &PropertyType,
         size: u32,
-    ) -> TResult<ValueArray> {
+        inner: Option<&PropertyTypeName>,
+    ) -> TResult<Self> {
         let count = reader.read_u32::<LE>()?;
         Ok(match t {
             PropertyType::StructProperty => {
-                let _type = read_string(reader)?;
-                let name = read_string(reader)?;
-                let _size = reader.read_u64::<LE>()?;
-                let struct_type = StructType::read(reader)?;
-                let id = uuid::Uuid::read(reader)?;
-                reader.read_u8()?;
+                let mut size_offset = None;
+                let (_type, name, struct_type, id) = match inner {
+                    // UE 5.4+ does not write an inner tag as the struct type is already known
+                    Some(inner) => {
+                        let (struct_type, id) = inner.struct_type(true)?;
+                        let struct_type = reader.tagged_struct_type(struct_type);
+                        (
+                            "StructProperty".into(),
+                            reader.name().to_owned().into(),
+                            struct_type,
+                            id,
+                        )
+                    }
+                    None => {
+                        let _type = read_string(reader)?;
+                        let name = read_string(reader)?;
+                        size_offset = Some(reader.stream_position()?);
+                        let _size = reader.read_u64::<LE>()?;
+                        let struct_type = StructType::read(reader)?;
+                        let struct_type = reader.tagged_struct_type(struct_type);
+                        let id = uuid::Uuid::read(reader)?;
+                        reader.read_u8()?;
+                        (_type, name, struct_type, id)
+                    }
+                };
+                let start = reader.stream_position()?;
+                reader.elements::<StructValue>(count)?;
                 let mut value = vec![];
-                for _ in 0..count {
-                    value.push(StructValue::read(reader, &struct_type)?);
+                for i in 0..count as usize {
+                    value.push(reader.index(i, |r| StructValue::read(r, &struct_type))?);
+                }
+                if let Some(size_offset) = size_offset {
+                    let end = reader.stream_position()?;
+                    reader.record_size(size_offset, 8, start, end);
                 }
                 ValueArray::Struct {
                     _type,
@@ -1875,6 +3633,12 @@ impl ValueArray {
                 value,
             } => {
                 writer.write_u32::<LE>(value.len() as u32)?;
+                if writer.property_tag_complete_type_name() {
+                    for v in value {
+                        v.write(writer)?;
+                    }
+                    return Ok(());
+                }
                 write_string(writer, _type)?;
                 write_string(writer, name)?;
                 let mut buf = vec![];
@@ -1894,18 +3658,23 @@ impl ValueArray {
         Ok(())
     }
 }
-impl ValueSet {
-    fn read<R: Read + Seek>(
+impl<'a> ValueSet<'a> {
+    fn read<R: Input<'a>>(
         reader: &mut Context<R>,
         t: &PropertyType,
         st: Option<&StructType>,
         size: u32,
-    ) -> TResult<ValueSet> {
+    ) -> TResult<Self> {
         let count = reader.read_u32::<LE>()?;
         Ok(match t {
-            PropertyType::StructProperty => ValueSet::Struct(read_array(count, reader, |r| {
-                StructValue::read(r, st.unwrap())
-            })?),
+            PropertyType::StructProperty => {
+                reader.elements::<StructValue>(count)?;
+                ValueSet::Struct(
+                    (0..count as usize)
+                        .map(|i| reader.index(i, |r| StructValue::read(r, st.unwrap())))
+                        .collect::<TResult<_>>()?,
+                )
+            }
             _ => ValueSet::Base(ValueVec::read(reader, t, size, count)?),
         })
     }
@@ -1926,158 +3695,304 @@ impl ValueSet {
 }
 
 /// Properties consist of an ID and a value and are present in [`Root`] and [`StructValue::Struct`]
+///
+/// `complete_type` holds the type name from a UE 5.4+ property tag if it cannot be derived from
+/// the rest of the property (e.g. paths of object classes). `trailing` holds bytes following the
+/// value within the size declared by its tag which were skipped when reading it (see
+/// [`ReadOptions::resync_size_mismatch`]).
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub enum Property {
+pub enum Property<'a> {
     Int8 {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: Int8,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Int16 {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: Int16,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Int {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: Int,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Int64 {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: Int64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     UInt8 {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: UInt8,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     UInt16 {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: UInt16,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     UInt32 {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: UInt32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     UInt64 {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: UInt64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Float {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: Float,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Double {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: Double,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Bool {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         value: Bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Byte {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: Byte,
-        enum_type: String,
+        value: Byte<'a>,
+        enum_type: FString<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Enum {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: Enum,
-        enum_type: String,
+        value: Enum<'a>,
+        enum_type: FString<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Str {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: String,
+        value: FString<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     FieldPath {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: FieldPath,
+        value: FieldPath<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     SoftObject {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: String,
-        value2: String,
-        value3: String,
+        value: FString<'a>,
+        value2: FString<'a>,
+        value3: FString<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Name {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: String,
+        value: FString<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Object {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: String,
+        value: FString<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Text {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: Text,
+        value: Text<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Delegate {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: Delegate,
+        value: Delegate<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     MulticastDelegate {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: MulticastDelegate,
+        value: MulticastDelegate<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     MulticastInlineDelegate {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: MulticastInlineDelegate,
+        value: MulticastInlineDelegate<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     MulticastSparseDelegate {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: MulticastSparseDelegate,
+        value: MulticastSparseDelegate<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Set {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         set_type: PropertyType,
-        value: ValueSet,
+        value: ValueSet<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Map {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
         key_type: PropertyType,
         value_type: PropertyType,
-        value: Vec<MapEntry>,
+        value: Vec<MapEntry<'a>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Struct {
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: StructValue,
+        value: StructValue<'a>,
         struct_type: StructType,
         struct_id: uuid::Uuid,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
     Array {
         array_type: PropertyType,
         #[serde(skip_serializing_if = "Option::is_none")]
         id: Option<uuid::Uuid>,
-        value: ValueArray,
+        value: ValueArray<'a>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        complete_type: Option<PropertyTypeName<'a>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        trailing: Vec<u8>,
     },
+    /// Property of a type unknown to uesave. The inline tag data and value are kept as opaque
+    /// bytes so the property can be written back unchanged.
+    Raw {
+        type_name: String,
+        #[serde(
+            serialize_with = "hex::serialize",
+            deserialize_with = "deserialize_hex"
+        )]
+        tag_bytes: Cow<'a, [u8]>,
+        #[serde(
+            serialize_with = "hex::serialize",
+            deserialize_with = "deserialize_hex"
+        )]
+        value_bytes: Cow<'a, [u8]>,
+    },
+}
+/// Decodes the hex encoded bytes of [`Property::Raw`]
+fn deserialize_hex<'de, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Cow<'a, [u8]>, D::Error> {
+    hex::deserialize::<_, Vec<u8>>(deserializer).map(Cow::Owned)
 }
 
-impl Property {
-    fn get_type(&self) -> PropertyType {
-        match &self {
+impl<'a> Property<'a> {
+    /// Struct type the value was read as, if any
+    fn read_struct_type(&self) -> Option<&StructType> {
+        match self {
+            Property::Struct { struct_type, .. } => Some(struct_type),
+            Property::Array {
+                value: ValueArray::Struct { struct_type, .. },
+                ..
+            } => Some(struct_type),
+            _ => None,
+        }
+    }
+    /// Type of the property, `None` for [`Property::Raw`] whose type is only known by name
+    fn get_type(&self) -> Option<PropertyType> {
+        Some(match &self {
             Property::Int8 { .. } => PropertyType::Int8Property,
             Property::Int16 { .. } => PropertyType::Int16Property,
             Property::Int { .. } => PropertyType::IntProperty,
@@ -2109,61 +4024,486 @@ impl Property {
             Property::Map { .. } => PropertyType::MapProperty,
             Property::Struct { .. } => PropertyType::StructProperty,
             Property::Array { .. } => PropertyType::ArrayProperty,
+            Property::Raw { .. } => return None,
+        })
+    }
+    fn id(&self) -> Option<uuid::Uuid> {
+        match self {
+            Property::Int8 { id, .. }
+            | Property::Int16 { id, .. }
+            | Property::Int { id, .. }
+            | Property::Int64 { id, .. }
+            | Property::UInt8 { id, .. }
+            | Property::UInt16 { id, .. }
+            | Property::UInt32 { id, .. }
+            | Property::UInt64 { id, .. }
+            | Property::Float { id, .. }
+            | Property::Double { id, .. }
+            | Property::Bool { id, .. }
+            | Property::Byte { id, .. }
+            | Property::Enum { id, .. }
+            | Property::Name { id, .. }
+            | Property::Str { id, .. }
+            | Property::FieldPath { id, .. }
+            | Property::SoftObject { id, .. }
+            | Property::Object { id, .. }
+            | Property::Text { id, .. }
+            | Property::Delegate { id, .. }
+            | Property::MulticastDelegate { id, .. }
+            | Property::MulticastInlineDelegate { id, .. }
+            | Property::MulticastSparseDelegate { id, .. }
+            | Property::Set { id, .. }
+            | Property::Map { id, .. }
+            | Property::Struct { id, .. }
+            | Property::Array { id, .. } => *id,
+            Property::Raw { .. } => None,
+        }
+    }
+    fn complete_type(&self) -> Option<&PropertyTypeName<'a>> {
+        match self {
+            Property::Int8 { complete_type, .. }
+            | Property::Int16 { complete_type, .. }
+            | Property::Int { complete_type, .. }
+            | Property::Int64 { complete_type, .. }
+            | Property::UInt8 { complete_type, .. }
+            | Property::UInt16 { complete_type, .. }
+            | Property::UInt32 { complete_type, .. }
+            | Property::UInt64 { complete_type, .. }
+            | Property::Float { complete_type, .. }
+            | Property::Double { complete_type, .. }
+            | Property::Bool { complete_type, .. }
+            | Property::Byte { complete_type, .. }
+            | Property::Enum { complete_type, .. }
+            | Property::Name { complete_type, .. }
+            | Property::Str { complete_type, .. }
+            | Property::FieldPath { complete_type, .. }
+            | Property::SoftObject { complete_type, .. }
+            | Property::Object { complete_type, .. }
+            | Property::Text { complete_type, .. }
+            | Property::Delegate { complete_type, .. }
+            | Property::MulticastDelegate { complete_type, .. }
+            | Property::MulticastInlineDelegate { complete_type, .. }
+            | Property::MulticastSparseDelegate { complete_type, .. }
+            | Property::Set { complete_type, .. }
+            | Property::Map { complete_type, .. }
+            | Property::Struct { complete_type, .. }
+            | Property::Array { complete_type, .. } => complete_type.as_ref(),
+            Property::Raw { .. } => None,
+        }
+    }
+    fn complete_type_mut(&mut self) -> Option<&mut Option<PropertyTypeName<'a>>> {
+        match self {
+            Property::Int8 { complete_type, .. }
+            | Property::Int16 { complete_type, .. }
+            | Property::Int { complete_type, .. }
+            | Property::Int64 { complete_type, .. }
+            | Property::UInt8 { complete_type, .. }
+            | Property::UInt16 { complete_type, .. }
+            | Property::UInt32 { complete_type, .. }
+            | Property::UInt64 { complete_type, .. }
+            | Property::Float { complete_type, .. }
+            | Property::Double { complete_type, .. }
+            | Property::Bool { complete_type, .. }
+            | Property::Byte { complete_type, .. }
+            | Property::Enum { complete_type, .. }
+            | Property::Name { complete_type, .. }
+            | Property::Str { complete_type, .. }
+            | Property::FieldPath { complete_type, .. }
+            | Property::SoftObject { complete_type, .. }
+            | Property::Object { complete_type, .. }
+            | Property::Text { complete_type, .. }
+            | Property::Delegate { complete_type, .. }
+            | Property::MulticastDelegate { complete_type, .. }
+            | Property::MulticastInlineDelegate { complete_type, .. }
+            | Property::MulticastSparseDelegate { complete_type, .. }
+            | Property::Set { complete_type, .. }
+            | Property::Map { complete_type, .. }
+            | Property::Struct { complete_type, .. }
+            | Property::Array { complete_type, .. } => Some(complete_type),
+            Property::Raw { .. } => None,
+        }
+    }
+    fn trailing(&self) -> &[u8] {
+        match self {
+            Property::Int8 { trailing, .. }
+            | Property::Int16 { trailing, .. }
+            | Property::Int { trailing, .. }
+            | Property::Int64 { trailing, .. }
+            | Property::UInt8 { trailing, .. }
+            | Property::UInt16 { trailing, .. }
+            | Property::UInt32 { trailing, .. }
+            | Property::UInt64 { trailing, .. }
+            | Property::Float { trailing, .. }
+            | Property::Double { trailing, .. }
+            | Property::Bool { trailing, .. }
+            | Property::Byte { trailing, .. }
+            | Property::Enum { trailing, .. }
+            | Property::Name { trailing, .. }
+            | Property::Str { trailing, .. }
+            | Property::FieldPath { trailing, .. }
+            | Property::SoftObject { trailing, .. }
+            | Property::Object { trailing, .. }
+            | Property::Text { trailing, .. }
+            | Property::Delegate { trailing, .. }
+            | Property::MulticastDelegate { trailing, .. }
+            | Property::MulticastInlineDelegate { trailing, .. }
+            | Property::MulticastSparseDelegate { trailing, .. }
+            | Property::Set { trailing, .. }
+            | Property::Map { trailing, .. }
+            | Property::Struct { trailing, .. }
+            | Property::Array { trailing, .. } => trailing,
+            Property::Raw { .. } => &[],
+        }
+    }
+    /// Bytes skipped after the value, see [`ReadOptions::resync_size_mismatch`]
+    fn trailing_mut(&mut self) -> Option<&mut Vec<u8>> {
+        match self {
+            Property::Int8 { trailing, .. }
+            | Property::Int16 { trailing, .. }
+            | Property::Int { trailing, .. }
+            | Property::Int64 { trailing, .. }
+            | Property::UInt8 { trailing, .. }
+            | Property::UInt16 { trailing, .. }
+            | Property::UInt32 { trailing, .. }
+            | Property::UInt64 { trailing, .. }
+            | Property::Float { trailing, .. }
+            | Property::Double { trailing, .. }
+            | Property::Bool { trailing, .. }
+            | Property::Byte { trailing, .. }
+            | Property::Enum { trailing, .. }
+            | Property::Name { trailing, .. }
+            | Property::Str { trailing, .. }
+            | Property::FieldPath { trailing, .. }
+            | Property::SoftObject { trailing, .. }
+            | Property::Object { trailing, .. }
+            | Property::Text { trailing, .. }
+            | Property::Delegate { trailing, .. }
+            | Property::MulticastDelegate { trailing, .. }
+            | Property::MulticastInlineDelegate { trailing, .. }
+            | Property::MulticastSparseDelegate { trailing, .. }
+            | Property::Set { trailing, .. }
+            | Property::Map { trailing, .. }
+            | Property::Struct { trailing, .. }
+            | Property::Array { trailing, .. } => Some(trailing),
+            Property::Raw { .. } => None,
+        }
+    }
+    /// Flags of a UE 5.4+ property tag
+    fn tag_flags(&self, index: u32) -> u8 {
+        let mut flags = 0;
+        if index != 0 {
+            flags |= HAS_ARRAY_INDEX;
+        }
+        if self.id().is_some() {
+            flags |= HAS_PROPERTY_GUID;
+        }
+        match self {
+            Property::Bool { value: true, .. } => flags |= BOOL_TRUE,
+            Property::Struct { struct_type, .. }
+                if !matches!(struct_type, StructType::Struct(_)) =>
+            {
+                flags |= HAS_BINARY_OR_NATIVE_SERIALIZE
+            }
+            _ => {}
+        }
+        flags
+    }
+    /// Complete type name of a UE 5.4+ property tag as far as it can be derived from the
+    /// property itself
+    fn derive_complete_type(&self) -> PropertyTypeName<'static> {
+        fn simple(t: &PropertyType) -> PropertyTypeName<'static> {
+            PropertyTypeName::new(t.get_name())
+        }
+        fn struct_parameters(
+            struct_type: &StructType,
+            struct_id: &uuid::Uuid,
+        ) -> Vec<PropertyTypeName<'static>> {
+            let mut parameters = vec![struct_type.complete_type()];
+            if !struct_id.is_nil() {
+                parameters.push(PropertyTypeName::new(
+                    struct_id
+                        .hyphenated()
+                        .encode_upper(&mut uuid::Uuid::encode_buffer())
+                        .to_owned(),
+                ));
+            }
+            parameters
+        }
+        let parameters = match self {
+            Property::Byte { enum_type, .. } if enum_type != "None" => {
+                vec![PropertyTypeName::from_path(enum_type)]
+            }
+            Property::Enum { enum_type, .. } => vec![
+                PropertyTypeName::from_path(enum_type),
+                simple(&PropertyType::ByteProperty),
+            ],
+            Property::Struct {
+                struct_type,
+                struct_id,
+                ..
+            } => struct_parameters(struct_type, struct_id),
+            Property::Array {
+                value:
+                    ValueArray::Struct {
+                        struct_type, id, ..
+                    },
+                ..
+            } => vec![PropertyTypeName {
+                name: PropertyType::StructProperty.get_name().into(),
+                parameters: struct_parameters(struct_type, id),
+            }],
+            Property::Array { array_type, .. } => vec![simple(array_type)],
+            Property::Set { set_type, .. } => vec![simple(set_type)],
+            Property::Map {
+                key_type,
+                value_type,
+                ..
+            } => vec![simple(key_type), simple(value_type)],
+            _ => vec![],
+        };
+        PropertyTypeName {
+            name: self.type_name().to_owned().into(),
+            parameters,
+        }
+    }
+    /// Reads a property which cannot be decoded. The size of the value is known from the tag
+    /// and so is the length of the type specific tag data preceding it if the type is known.
+    /// Otherwise it is assumed to be a (possibly empty) sequence of FStrings (e.g. inner type
+    /// names) followed by the optional property GUID which is how most known property types lay
+    /// out their tag data.
+    fn read_raw<R: Input<'a>>(
+        reader: &mut Context<R>,
+        type_name: String,
+        size: u32,
+    ) -> TResult<Self> {
+        let tag_bytes = match PropertyType::from_name(&type_name) {
+            Ok(t) => {
+                let start = reader.stream_position()?;
+                skip_tag_data(reader, &t)?;
+                let len = reader.stream_position()? - start;
+                reader.seek(std::io::SeekFrom::Start(start))?;
+                reader.read_bytes(len as usize)?
+            }
+            Err(_) => Self::read_raw_tag_data(reader, size)?.into(),
+        };
+        let value_bytes = reader.read_bytes(size as usize)?;
+        Ok(Property::Raw {
+            type_name,
+            tag_bytes,
+            value_bytes,
+        })
+    }
+    /// Reads the tag data of a property of unknown type. A leading i32 is taken as the length
+    /// of an FString if it is followed by that many characters of which only the last is a null
+    /// terminator. Otherwise the first byte is the flag of the optional property GUID.
+    fn read_raw_tag_data<R: Input<'a>>(reader: &mut Context<R>, size: u32) -> TResult<Vec<u8>> {
+        let mut tag_bytes = vec![];
+        loop {
+            let start = reader.stream_position()?;
+            let len = reader.read_i32::<LE>()?;
+            if let Some(string) = Self::read_raw_string(reader, len, size)? {
+                tag_bytes.extend(len.to_le_bytes());
+                tag_bytes.extend(string);
+                continue;
+            }
+            reader.seek(std::io::SeekFrom::Start(start))?;
+            match reader.read_u8()? {
+                0 => {
+                    tag_bytes.push(0);
+                    break;
+                }
+                1 => {
+                    let mut id = [0; 17];
+                    id[0] = 1;
+                    reader.read_exact(&mut id[1..])?;
+                    tag_bytes.extend(id);
+                    break;
+                }
+                flag => {
+                    return Err(Error::Other(format!(
+                        "unrecognized tag data at offset {start}: expected FString or property GUID flag, found {flag}"
+                    )))
+                }
+            }
+        }
+        Ok(tag_bytes)
+    }
+    /// Reads the characters of an FString of length `len` including the null terminator or
+    /// returns `None` if the data following does not look like one. The tag data of a value of
+    /// `size` bytes holds names, a string longer than both an FName and the value is taken for
+    /// something else e.g. the bytes of a property GUID rather than read up to the end of the
+    /// stream.
+    fn read_raw_string<R: Input<'a>>(
+        reader: &mut Context<R>,
+        len: i32,
+        size: u32,
+    ) -> TResult<Option<Vec<u8>>> {
+        let (bytes, width) = match len {
+            0 => return Ok(None),
+            len if len < 0 => (-(len as i64) as u64 * 2, 2),
+            len => (len as u64, 1),
+        };
+        if bytes > (NAME_SIZE * width as u64).max(size as u64)
+            || bytes > reader.options.limits.max_string_length as u64
+        {
+            return Ok(None);
+        }
+        // read incrementally so a bogus length cannot allocate more than the remaining data,
+        // the string is only accounted for once it has been recognized as one
+        let mut string = vec![];
+        reader.by_ref().take(bytes).read_to_end(&mut string)?;
+        if string.len() as u64 != bytes {
+            return Ok(None);
+        }
+        let mut chars = string.chunks(width).map(|c| c.iter().all(|b| *b == 0));
+        let terminated = chars.next_back() == Some(true);
+        if !terminated || chars.any(|null| null) {
+            return Ok(None);
+        }
+        reader.string_length(bytes)?;
+        Ok(Some(string))
+    }
+    fn read<R: Input<'a>>(reader: &mut Context<R>, t: PropertyType, size: u32) -> TResult<Self> {
+        Self::read_tagged(reader, t, size, None)
+    }
+    /// Reads the type specific tag data followed by the value. For the UE 5.4+ layout the tag
+    /// has already been read and is passed as `tag`.
+    fn read_tagged<R: Input<'a>>(
+        reader: &mut Context<R>,
+        t: PropertyType,
+        size: u32,
+        tag: Option<&CompleteTag>,
+    ) -> TResult<Self> {
+        let start = reader.stream_position()?;
+        reader.value_start = None;
+        let mut value = Self::read_tag_data_and_value(reader, t, size, tag)?;
+        let value_start = reader.value_start.take().unwrap_or(start);
+        let consumed = reader.stream_position()? - value_start;
+        if consumed != size as u64 {
+            let error = Error::SizeMismatch {
+                expected: size,
+                actual: consumed,
+                struct_type: value.read_struct_type().cloned(),
+            };
+            if !reader.options.allow_size_mismatch {
+                return Err(error);
+            }
+            let end = value_start + size as u64;
+            if !reader.options.resync_size_mismatch {
+                reader.warn(DiagnosticKind::SizeMismatch, value_start, error.to_string());
+            } else if let Some((skipped, trailing)) = (size as u64)
+                .checked_sub(consumed)
+                .zip(value.trailing_mut())
+            {
+                // keep the bytes the value did not take up so they are written back
+                reader.allocate(skipped)?;
+                trailing.resize(skipped as usize, 0);
+                reader.read_exact(trailing)?;
+                let message = format!("{error}, keeping {skipped} trailing bytes");
+                reader.warn(DiagnosticKind::SizeMismatch, value_start, message);
+            } else if consumed < size as u64 {
+                reader.seek(std::io::SeekFrom::Start(end))?;
+                let message = format!("{error}, continuing at offset {end}");
+                reader.warn(DiagnosticKind::SizeMismatch, value_start, message);
+            } else {
+                // the value was read in full, cutting it short would read the rest of it as the
+                // next property
+                let message = format!("{error}, continuing after the value");
+                reader.warn(DiagnosticKind::SizeMismatch, value_start, message);
+            }
         }
+        Ok(value)
     }
-    fn read<R: Read + Seek>(
+    fn read_tag_data_and_value<R: Input<'a>>(
         reader: &mut Context<R>,
         t: PropertyType,
         size: u32,
-    ) -> TResult<Property> {
+        tag: Option<&CompleteTag>,
+    ) -> TResult<Self> {
         match t {
             PropertyType::Int8Property => Ok(Property::Int8 {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: reader.read_i8()?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::Int16Property => Ok(Property::Int16 {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: reader.read_i16::<LE>()?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::IntProperty => Ok(Property::Int {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: reader.read_i32::<LE>()?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::Int64Property => Ok(Property::Int64 {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: reader.read_i64::<LE>()?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::UInt8Property => Ok(Property::UInt8 {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: reader.read_u8()?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::UInt16Property => Ok(Property::UInt16 {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: reader.read_u16::<LE>()?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::UInt32Property => Ok(Property::UInt32 {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: reader.read_u32::<LE>()?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::UInt64Property => Ok(Property::UInt64 {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: reader.read_u64::<LE>()?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::FloatProperty => Ok(Property::Float {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: reader.read_f32::<LE>()?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::DoubleProperty => Ok(Property::Double {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: reader.read_f64::<LE>()?,
+                complete_type: None,
+                trailing: vec![],
             }),
-            PropertyType::BoolProperty => Ok(Property::Bool {
-                value: reader.read_u8()? > 0,
-                id: read_optional_uuid(reader)?,
+            PropertyType::BoolProperty => Ok(match tag {
+                Some(tag) => Property::Bool {
+                    value: tag.flags & BOOL_TRUE != 0,
+                    id: tag.id,
+                    complete_type: None,
+                    trailing: vec![],
+                },
+                None => Property::Bool {
+                    value: reader.read_u8()? > 0,
+                    id: read_tag_id(reader, None)?,
+                    complete_type: None,
+                    trailing: vec![],
+                },
             }),
             PropertyType::ByteProperty => Ok({
-                let enum_type = read_string(reader)?;
-                let id = read_optional_uuid(reader)?;
+                let enum_type = match tag {
+                    Some(tag) => match tag.type_name.parameters.first() {
+                        Some(enum_type) => enum_type.to_path().into(),
+                        None => "None".into(),
+                    },
+                    None => read_string(reader)?,
+                };
+                let id = read_tag_id(reader, tag)?;
                 let value = if enum_type == "None" {
                     Byte::Byte(reader.read_u8()?)
                 } else {
@@ -2173,209 +4513,363 @@ impl Property {
                     enum_type,
                     id,
                     value,
+                    complete_type: None,
+                    trailing: vec![],
                 }
             }),
             PropertyType::EnumProperty => Ok(Property::Enum {
-                enum_type: read_string(reader)?,
-                id: read_optional_uuid(reader)?,
+                enum_type: match tag {
+                    Some(tag) => tag.type_name.parameter(0)?.to_path().into(),
+                    None => read_string(reader)?,
+                },
+                id: read_tag_id(reader, tag)?,
                 value: read_string(reader)?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::NameProperty => Ok(Property::Name {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: read_string(reader)?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::StrProperty => Ok(Property::Str {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: read_string(reader)?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::FieldPathProperty => Ok(Property::FieldPath {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: FieldPath::read(reader)?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::SoftObjectProperty => Ok(Property::SoftObject {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: read_string(reader)?,
                 value2: read_string(reader)?,
                 value3: read_string(reader)?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::ObjectProperty => Ok(Property::Object {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: read_string(reader)?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::TextProperty => Ok(Property::Text {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: Text::read(reader)?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::DelegateProperty => Ok(Property::Delegate {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: Delegate::read(reader)?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::MulticastDelegateProperty => Ok(Property::MulticastDelegate {
-                id: read_optional_uuid(reader)?,
+                id: read_tag_id(reader, tag)?,
                 value: MulticastDelegate::read(reader)?,
+                complete_type: None,
+                trailing: vec![],
             }),
             PropertyType::MulticastInlineDelegateProperty => {
                 Ok(Property::MulticastInlineDelegate {
-                    id: read_optional_uuid(reader)?,
+                    id: read_tag_id(reader, tag)?,
                     value: MulticastInlineDelegate::read(reader)?,
+                    complete_type: None,
+                    trailing: vec![],
                 })
             }
             PropertyType::MulticastSparseDelegateProperty => {
                 Ok(Property::MulticastSparseDelegate {
-                    id: read_optional_uuid(reader)?,
+                    id: read_tag_id(reader, tag)?,
                     value: MulticastSparseDelegate::read(reader)?,
+                    complete_type: None,
+                    trailing: vec![],
                 })
             }
             PropertyType::SetProperty => {
-                let set_type = PropertyType::read(reader)?;
-                let id = read_optional_uuid(reader)?;
+                let set_type = match tag {
+                    Some(tag) => PropertyType::from_name(&tag.type_name.parameter(0)?.name)?,
+                    None => PropertyType::read(reader)?,
+                };
+                let id = read_tag_id(reader, tag)?;
                 reader.read_u32::<LE>()?;
-                let struct_type = match set_type {
-                    PropertyType::StructProperty => Some(reader.get_type_or(&StructType::Guid)?),
+                let struct_type = match (&set_type, tag) {
+                    (PropertyType::StructProperty, Some(tag)) => Some(
+                        reader.tagged_struct_type(tag.type_name.parameter(0)?.struct_type(true)?.0),
+                    ),
+                    (PropertyType::StructProperty, None) => reader.get_type().cloned(),
                     _ => None,
                 };
-                let value = ValueSet::read(reader, &set_type, struct_type, size - 8)?;
+                // the value is preceded by the count of removed elements
+                let size = value_size(size, 4)?;
+                let value = match (&set_type, struct_type) {
+                    (PropertyType::StructProperty, None) => {
+                        let offset = reader.stream_position()?;
+                        let candidates = StructType::candidates(StructType::Guid);
+                        let elements_size = value_size(size, 4)?;
+                        let lwc = reader.large_world_coordinates();
+                        let (struct_type, value, ambiguous) = infer_struct_types(
+                            reader,
+                            size,
+                            candidates,
+                            |a, b| a.same_size(b, lwc),
+                            |r, st| ValueSet::read(r, &set_type, Some(st), elements_size),
+                        )?;
+                        reader.inferred_type(offset, &struct_type, &ambiguous)?;
+                        value
+                    }
+                    (_, struct_type) => ValueSet::read(
+                        reader,
+                        &set_type,
+                        struct_type.as_ref(),
+                        value_size(size, 4)?,
+                    )?,
+                };
                 Ok(Property::Set {
                     id,
                     set_type,
                     value,
+                    complete_type: None,
+                    trailing: vec![],
                 })
             }
             PropertyType::MapProperty => {
-                let key_type = PropertyType::read(reader)?;
-                let value_type = PropertyType::read(reader)?;
-                let id = read_optional_uuid(reader)?;
+                let (key_type, value_type) = match tag {
+                    Some(tag) => (
+                        PropertyType::from_name(&tag.type_name.parameter(0)?.name)?,
+                        PropertyType::from_name(&tag.type_name.parameter(1)?.name)?,
+                    ),
+                    None => (PropertyType::read(reader)?, PropertyType::read(reader)?),
+                };
+                let id = read_tag_id(reader, tag)?;
                 reader.read_u32::<LE>()?;
-                let count = reader.read_u32::<LE>()?;
-                let mut value = vec![];
 
-                let key_struct_type = match key_type {
-                    PropertyType::StructProperty => {
-                        Some(reader.scope("Key", |r| r.get_type_or(&StructType::Guid))?)
-                    }
-                    _ => None,
-                };
-                let value_struct_type = match value_type {
-                    PropertyType::StructProperty => {
-                        Some(reader.scope("Value", |r| r.get_type_or(&StructType::Struct(None)))?)
-                    }
-                    _ => None,
+                // candidate types for keys and values, unknown ones are inferred
+                let inner_types = |reader: &mut Context<R>,
+                                   t: &PropertyType,
+                                   index: usize,
+                                   scope: &str,
+                                   default: StructType|
+                 -> TResult<Vec<InnerType>> {
+                    Ok(match (t, tag) {
+                        (PropertyType::StructProperty, Some(tag)) => {
+                            let t = tag.type_name.parameter(index)?.struct_type(true)?.0;
+                            vec![InnerType::Struct(
+                                reader.scope(scope, |r| r.tagged_struct_type(t)),
+                            )]
+                        }
+                        (PropertyType::StructProperty, None) => {
+                            match reader.scope(scope, |r| r.get_type().cloned()) {
+                                Some(t) => vec![InnerType::Struct(t)],
+                                None => StructType::candidates(default)
+                                    .into_iter()
+                                    .map(InnerType::Struct)
+                                    .collect(),
+                            }
+                        }
+                        (PropertyType::ByteProperty, Some(tag)) => {
+                            if tag.type_name.parameter(index)?.parameters.is_empty() {
+                                vec![InnerType::Simple]
+                            } else {
+                                vec![InnerType::Enum]
+                            }
+                        }
+                        (PropertyType::ByteProperty, None) => {
+                            vec![InnerType::Enum, InnerType::Simple]
+                        }
+                        _ => vec![InnerType::Simple],
+                    })
                 };
+                let key_types = inner_types(reader, &key_type, 0, "Key", StructType::Guid)?;
+                let value_types =
+                    inner_types(reader, &value_type, 1, "Value", StructType::Struct(None))?;
 
-                for _ in 0..count {
-                    value.push(MapEntry::read(
+                let value = if key_types.len() == 1 && value_types.len() == 1 {
+                    MapEntry::read_entries(
                         reader,
                         &key_type,
-                        key_struct_type,
+                        &key_types[0],
                         &value_type,
-                        value_struct_type,
-                    )?)
-                }
+                        &value_types[0],
+                    )?
+                } else {
+                    let offset = reader.stream_position()?;
+                    let candidates = key_types
+                        .iter()
+                        .flat_map(|k| value_types.iter().map(|v| (k.clone(), v.clone())))
+                        .collect();
+                    let lwc = reader.large_world_coordinates();
+                    let ((key_inner_type, value_inner_type), value, ambiguous) =
+                        infer_struct_types(
+                            reader,
+                            value_size(size, 4)?,
+                            candidates,
+                            |(k, v), (other_k, other_v)| {
+                                (v == other_v && k.same_size(other_k, lwc))
+                                    || (k == other_k && v.same_size(other_v, lwc))
+                            },
+                            |r, (k, v)| MapEntry::read_entries(r, &key_type, k, &value_type, v),
+                        )?;
+                    let ambiguous_keys = ambiguous.iter().filter_map(|(k, v)| match k {
+                        InnerType::Struct(t) if *v == value_inner_type => Some(t),
+                        _ => None,
+                    });
+                    let ambiguous_values = ambiguous.iter().filter_map(|(k, v)| match v {
+                        InnerType::Struct(t) if *k == key_inner_type => Some(t),
+                        _ => None,
+                    });
+                    if let InnerType::Struct(t) = &key_inner_type {
+                        if key_types.len() > 1 {
+                            reader.scope("Key", |r| r.inferred_type(offset, t, ambiguous_keys))?;
+                        }
+                    }
+                    if let InnerType::Struct(t) = &value_inner_type {
+                        if value_types.len() > 1 {
+                            reader
+                                .scope("Value", |r| r.inferred_type(offset, t, ambiguous_values))?;
+                        }
+                    }
+                    value
+                };
 
                 Ok(Property::Map {
                     key_type,
                     value_type,
                     id,
                     value,
+                    complete_type: None,
+                    trailing: vec![],
                 })
             }
             PropertyType::StructProperty => {
-                let struct_type = StructType::read(reader)?;
-                let struct_id = uuid::Uuid::read(reader)?;
-                let id = read_optional_uuid(reader)?;
+                let (struct_type, struct_id) = match tag {
+                    Some(tag) => tag
+                        .type_name
+                        .struct_type(tag.flags & HAS_BINARY_OR_NATIVE_SERIALIZE != 0)?,
+                    None => {
+                        let struct_type = StructType::read(reader)?;
+                        (
+                            reader.tagged_struct_type(struct_type),
+                            uuid::Uuid::read(reader)?,
+                        )
+                    }
+                };
+                let id = read_tag_id(reader, tag)?;
                 let value = StructValue::read(reader, &struct_type)?;
                 Ok(Property::Struct {
                     struct_type,
                     struct_id,
                     id,
                     value,
+                    complete_type: None,
+                    trailing: vec![],
                 })
             }
             PropertyType::ArrayProperty => {
-                let array_type = PropertyType::read(reader)?;
-                let id = read_optional_uuid(reader)?;
-                let value = ValueArray::read(reader, &array_type, size - 4)?;
+                let inner = tag.map(|tag| tag.type_name.parameter(0)).transpose()?;
+                let array_type = match inner {
+                    Some(inner) => PropertyType::from_name(&inner.name)?,
+                    None => PropertyType::read(reader)?,
+                };
+                let id = read_tag_id(reader, tag)?;
+                let value = ValueArray::read(reader, &array_type, value_size(size, 4)?, inner)?;
 
                 Ok(Property::Array {
                     array_type,
                     id,
                     value,
+                    complete_type: None,
+                    trailing: vec![],
                 })
             }
         }
     }
     fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<usize> {
         Ok(match self {
-            Property::Int8 { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Int8 { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 writer.write_i8(*value)?;
                 1
             }
-            Property::Int16 { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Int16 { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 writer.write_i16::<LE>(*value)?;
                 2
             }
-            Property::Int { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Int { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 writer.write_i32::<LE>(*value)?;
                 4
             }
-            Property::Int64 { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Int64 { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 writer.write_i64::<LE>(*value)?;
                 8
             }
-            Property::UInt8 { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::UInt8 { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 writer.write_u8(*value)?;
                 1
             }
-            Property::UInt16 { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::UInt16 { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 writer.write_u16::<LE>(*value)?;
                 2
             }
-            Property::UInt32 { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::UInt32 { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 writer.write_u32::<LE>(*value)?;
                 4
             }
-            Property::UInt64 { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::UInt64 { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 writer.write_u64::<LE>(*value)?;
                 8
             }
-            Property::Float { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Float { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 writer.write_f32::<LE>(*value)?;
                 4
             }
-            Property::Double { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Double { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 writer.write_f64::<LE>(*value)?;
                 8
             }
-            Property::Bool { id, value } => {
-                writer.write_u8(u8::from(*value))?;
-                write_optional_uuid(writer, *id)?;
+            Property::Bool { id, value, .. } => {
+                if !writer.property_tag_complete_type_name() {
+                    writer.write_u8(u8::from(*value))?;
+                }
+                write_tag_id(writer, *id)?;
                 0
             }
             Property::Byte {
                 enum_type,
                 id,
                 value,
+                ..
             } => {
-                write_string(writer, enum_type)?;
-                write_optional_uuid(writer, *id)?;
+                if !writer.property_tag_complete_type_name() {
+                    write_string(writer, enum_type)?;
+                }
+                write_tag_id(writer, *id)?;
                 match value {
                     Byte::Byte(b) => {
                         writer.write_u8(*b)?;
                         1
                     }
                     Byte::Label(l) => {
-                        write_string(writer, l)?;
-                        l.len() + 5
+                        let mut buf = vec![];
+                        writer.stream(&mut buf, |writer| write_string(writer, l))?;
+                        writer.write_all(&buf)?;
+                        buf.len()
                     }
                 }
             }
@@ -2383,30 +4877,35 @@ impl Property {
                 enum_type,
                 id,
                 value,
+                ..
             } => {
-                write_string(writer, enum_type)?;
-                write_optional_uuid(writer, *id)?;
-                write_string(writer, value)?;
-                value.len() + 5
+                if !writer.property_tag_complete_type_name() {
+                    write_string(writer, enum_type)?;
+                }
+                write_tag_id(writer, *id)?;
+                let mut buf = vec![];
+                writer.stream(&mut buf, |writer| write_string(writer, value))?;
+                writer.write_all(&buf)?;
+                buf.len()
             }
-            Property::Name { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Name { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| write_string(writer, value))?;
                 let size = buf.len();
                 writer.write_all(&buf)?;
                 size
             }
-            Property::Str { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Str { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| write_string(writer, value))?;
                 let size = buf.len();
                 writer.write_all(&buf)?;
                 size
             }
-            Property::FieldPath { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::FieldPath { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| value.write(writer))?;
                 let size = buf.len();
@@ -2418,8 +4917,9 @@ impl Property {
                 value,
                 value2,
                 value3,
+                ..
             } => {
-                write_optional_uuid(writer, *id)?;
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| write_string(writer, value))?;
                 writer.stream(&mut buf, |writer| write_string(writer, value2))?;
@@ -2428,48 +4928,48 @@ impl Property {
                 writer.write_all(&buf)?;
                 size
             }
-            Property::Object { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Object { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| write_string(writer, value))?;
                 let size = buf.len();
                 writer.write_all(&buf)?;
                 size
             }
-            Property::Text { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Text { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| value.write(writer))?;
                 let size = buf.len();
                 writer.write_all(&buf)?;
                 size
             }
-            Property::Delegate { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::Delegate { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| value.write(writer))?;
                 let size = buf.len();
                 writer.write_all(&buf)?;
                 size
             }
-            Property::MulticastDelegate { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::MulticastDelegate { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| value.write(writer))?;
                 let size = buf.len();
                 writer.write_all(&buf)?;
                 size
             }
-            Property::MulticastInlineDelegate { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::MulticastInlineDelegate { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| value.write(writer))?;
                 let size = buf.len();
                 writer.write_all(&buf)?;
                 size
             }
-            Property::MulticastSparseDelegate { id, value } => {
-                write_optional_uuid(writer, *id)?;
+            Property::MulticastSparseDelegate { id, value, .. } => {
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| value.write(writer))?;
                 let size = buf.len();
@@ -2480,9 +4980,12 @@ impl Property {
                 id,
                 set_type,
                 value,
+                ..
             } => {
-                set_type.write(writer)?;
-                write_optional_uuid(writer, *id)?;
+                if !writer.property_tag_complete_type_name() {
+                    set_type.write(writer)?;
+                }
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 buf.write_u32::<LE>(0)?;
                 writer.stream(&mut buf, |writer| value.write(writer))?;
@@ -2495,10 +4998,13 @@ impl Property {
                 value_type,
                 id,
                 value,
+                ..
             } => {
-                key_type.write(writer)?;
-                value_type.write(writer)?;
-                write_optional_uuid(writer, *id)?;
+                if !writer.property_tag_complete_type_name() {
+                    key_type.write(writer)?;
+                    value_type.write(writer)?;
+                }
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 buf.write_u32::<LE>(0)?;
                 buf.write_u32::<LE>(value.len() as u32)?;
@@ -2514,10 +5020,13 @@ impl Property {
                 struct_id,
                 id,
                 value,
+                ..
             } => {
-                struct_type.write(writer)?;
-                struct_id.write(writer)?;
-                write_optional_uuid(writer, *id)?;
+                if !writer.property_tag_complete_type_name() {
+                    struct_type.write(writer)?;
+                    struct_id.write(writer)?;
+                }
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| value.write(writer))?;
                 let size = buf.len();
@@ -2528,15 +5037,27 @@ impl Property {
                 array_type,
                 id,
                 value,
+                ..
             } => {
-                array_type.write(writer)?;
-                write_optional_uuid(writer, *id)?;
+                if !writer.property_tag_complete_type_name() {
+                    array_type.write(writer)?;
+                }
+                write_tag_id(writer, *id)?;
                 let mut buf = vec![];
                 writer.stream(&mut buf, |writer| value.write(writer))?;
                 let size = buf.len();
                 writer.write_all(&buf)?;
                 size
             }
+            Property::Raw {
+                tag_bytes,
+                value_bytes,
+                ..
+            } => {
+                writer.write_all(tag_bytes)?;
+                writer.write_all(value_bytes)?;
+                value_bytes.len()
+            }
         })
     }
 }
@@ -2577,7 +5098,7 @@ pub struct Header {
     pub engine_version_minor: u16,
     pub engine_version_patch: u16,
     pub engine_version_build: u32,
-    pub engine_version: String,
+    pub engine_version: FString<'static>,
     pub custom_format_version: u32,
     pub custom_format: Vec<CustomFormatData>,
 }
@@ -2585,15 +5106,24 @@ impl Header {
     fn large_world_coordinates(&self) -> bool {
         self.engine_version_major >= 5
     }
-}
-impl<R: Read + Seek> Readable<R> for Header {
-    fn read(reader: &mut Context<R>) -> TResult<Self> {
+    /// EUnrealEngineObjectUE5Version::PROPERTY_TAG_COMPLETE_TYPE_NAME (UE 5.4)
+    fn property_tag_complete_type_name(&self) -> bool {
+        matches!(self.package_version, PackageVersion::New(_, ue5) if ue5 >= 1012)
+    }
+    fn read<'d, R: Input<'d>>(reader: &mut Context<R>) -> TResult<Self> {
         let magic = reader.read_u32::<LE>()?;
         if magic != u32::from_le_bytes(*b"GVAS") {
-            eprintln!(
-                "Found non-standard magic: {:02x?} ({}) expected: GVAS, continuing to parse...",
-                &magic.to_le_bytes(),
-                String::from_utf8_lossy(&magic.to_le_bytes())
+            if !reader.options.allow_bad_magic {
+                return Err(Error::BadMagic());
+            }
+            reader.warn(
+                DiagnosticKind::NonStandardMagic,
+                0,
+                format!(
+                    "found non-standard magic: {:02x?} ({}) expected: GVAS, continuing to parse",
+                    &magic.to_le_bytes(),
+                    String::from_utf8_lossy(&magic.to_le_bytes())
+                ),
             );
         }
         let save_game_version = reader.read_u32::<LE>()?;
@@ -2610,7 +5140,7 @@ impl<R: Read + Seek> Readable<R> for Header {
             engine_version_minor: reader.read_u16::<LE>()?,
             engine_version_patch: reader.read_u16::<LE>()?,
             engine_version_build: reader.read_u32::<LE>()?,
-            engine_version: read_string(reader)?,
+            engine_version: read_string(reader)?.into_owned(),
             custom_format_version: reader.read_u32::<LE>()?,
             custom_format: read_array(reader.read_u32::<LE>()?, reader, CustomFormatData::read)?,
         })
@@ -2645,15 +5175,27 @@ impl<W: Write> Writable<W> for Header {
 
 /// Root struct inside a save file which holds both the Unreal Engine class name and list of properties
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Root {
-    pub save_game_type: String,
-    pub properties: Properties,
+pub struct Root<'a> {
+    pub save_game_type: FString<'a>,
+    pub properties: Properties<'a>,
 }
-impl Root {
-    fn read<R: Read + Seek>(reader: &mut Context<R>) -> TResult<Self> {
+impl<'a> Root<'a> {
+    fn read<S: SaveStream + Input<'a>>(reader: &mut Context<S>) -> TResult<Self> {
+        let save_game_type = read_string(reader)?;
+        let mut properties = Properties::default();
+        loop {
+            // earlier root properties are not read again
+            let offset = reader.stream_position()?;
+            reader.stream.release(offset);
+            let Some((name, prop)) = read_property(reader)? else {
+                break;
+            };
+            reader.allocate(std::mem::size_of::<(PropertyKey, Property)>() as u64)?;
+            properties.insert(name, prop);
+        }
         Ok(Self {
-            save_game_type: read_string(reader)?,
-            properties: read_properties_until_none(reader)?,
+            save_game_type,
+            properties,
         })
     }
     fn write<W: Write>(&self, writer: &mut Context<W>) -> TResult<()> {
@@ -2664,51 +5206,314 @@ impl Root {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct Save {
+pub struct Save<'a> {
     pub header: Header,
-    pub root: Root,
+    pub root: Root<'a>,
     pub extra: Vec<u8>,
+    /// Checksum stored alongside the save, if any. It is recomputed on write.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<Checksum>,
+    /// Container the save was wrapped in, if any. It is used to wrap the save again on write.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<Container>,
+    /// Encryption of the save, if any. The key has to be passed to [`Save::write_with_options`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<Encryption>,
 }
-impl Save {
-    /// Reads save from the given reader
+impl Save<'static> {
+    /// Builder for a new save with an empty root, for UE 4.27 unless another
+    /// [`EngineVersion`] or header is chosen
+    pub fn builder() -> SaveBuilder {
+        SaveBuilder::new()
+    }
+    /// Reads save from the given reader. Saves starting with the GVAS magic are read as a stream,
+    /// anything else is read as a whole to be decrypted or unwrapped from one of
+    /// [`ReadOptions::containers`] first in which case offsets in errors and diagnostics refer to
+    /// the unwrapped data.
     pub fn read<R: Read>(reader: &mut R) -> Result<Self, ParseError> {
         Self::read_with_types(reader, &Types::new())
     }
     /// Reads save from the given reader using the provided [`Types`]
     pub fn read_with_types<R: Read>(reader: &mut R, types: &Types) -> Result<Self, ParseError> {
-        let mut reader = SeekReader::new(reader);
-
-        Context::run_with_types(types, &mut reader, |reader| {
-            let header = Header::read(reader)?;
-            let (root, extra) = reader.header(&header, |reader| -> TResult<_> {
-                let root = Root::read(reader)?;
-                let extra = {
-                    let mut buf = vec![];
-                    reader.read_to_end(&mut buf)?;
-                    if buf != [0; 4] {
-                        eprintln!(
-                            "{} extra bytes. Save may not have been parsed completely.",
-                            buf.len()
-                        );
-                    }
-                    buf
+        Self::read_with_diagnostics(reader, types, &mut Diagnostics::new())
+    }
+    /// Reads save from the given reader using the provided [`Types`], reporting non-fatal issues
+    /// to `diagnostics`
+    pub fn read_with_diagnostics<R: Read>(
+        reader: &mut R,
+        types: &Types,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Self, ParseError> {
+        let options = ReadOptions::new().types(types.clone());
+        Self::read_with_options(reader, &options, diagnostics)
+    }
+    /// Reads save from the given reader using the provided [`ReadOptions`], reporting non-fatal
+    /// issues to `diagnostics`
+    pub fn read_with_options<R: Read>(
+        reader: &mut R,
+        options: &ReadOptions,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Self, ParseError> {
+        let cell = RefCell::new(std::mem::take(diagnostics));
+        let result = Self::read_inner(reader, options, &cell);
+        *diagnostics = cell.into_inner();
+        result
+    }
+    fn read_inner<R: Read>(
+        reader: &mut R,
+        options: &ReadOptions,
+        diagnostics: &RefCell<Diagnostics>,
+    ) -> Result<Self, ParseError> {
+        let mut data = vec![];
+        reader
+            .by_ref()
+            .take(4)
+            .read_to_end(&mut data)
+            .map_err(|e| ParseError::new(e.into(), 0, &[]))?;
+        if data == b"GVAS" {
+            // nothing to unwrap, read straight from the stream
+            let mut stream = SeekReader::new((&data[..]).chain(reader));
+            return Self::read_gvas(&mut stream, options, diagnostics, None, None, None);
+        }
+        // stop right after the limit rather than buffering all of an oversized stream, decrypting
+        // it does not make it any larger
+        let max = options.limits.max_allocation;
+        reader
+            .take(max.saturating_sub(data.len() as u64).saturating_add(1))
+            .read_to_end(&mut data)
+            .map_err(|e| ParseError::new(e.into(), 0, &[]))?;
+        options
+            .limits
+            .check_allocation(data.len() as u64)
+            .map_err(|e| ParseError::new(e, 0, &[]))?;
+        Self::parse_inner(Cow::Owned(data), options, diagnostics)
+    }
+}
+impl<'a> Save<'a> {
+    /// Value of the root properties at `path` e.g. `PropList[3].Position.Rotation`, see
+    /// [`Properties::get_path`] for the syntax
+    pub fn get(&self, path: &str) -> Option<ValueRef<'_>> {
+        self.root.properties.get_path(path)
+    }
+    /// Mutable value of the root properties at `path`, see [`Properties::get_path`]
+    pub fn get_mut(&mut self, path: &str) -> Option<ValueMut<'_, 'a>> {
+        self.root.properties.get_path_mut(path)
+    }
+    /// Parses a save held in memory. Unlike [`Save::read`] the names, strings and byte arrays of
+    /// the properties borrow from `data` instead of being copied, unless the save has to be
+    /// decrypted or unwrapped from a container first.
+    pub fn parse(data: &'a [u8]) -> Result<Self, ParseError> {
+        Self::parse_with_options(data, &ReadOptions::new(), &mut Diagnostics::new())
+    }
+    /// Parses a save held in memory using the provided [`ReadOptions`], reporting non-fatal
+    /// issues to `diagnostics`
+    pub fn parse_with_options(
+        data: &'a [u8],
+        options: &ReadOptions,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Self, ParseError> {
+        let cell = RefCell::new(std::mem::take(diagnostics));
+        let result = if data.starts_with(b"GVAS") {
+            // nothing to unwrap, same as a save read as a stream
+            let mut stream = std::io::Cursor::new(data);
+            Self::read_gvas(&mut stream, options, &cell, None, None, None)
+        } else {
+            Self::parse_inner(Cow::Borrowed(data), options, &cell)
+        };
+        *diagnostics = cell.into_inner();
+        result
+    }
+    /// Reads a save which is not known to start with the GVAS data, the properties only borrow
+    /// from `data` if it is not decrypted or unwrapped
+    fn parse_inner(
+        mut data: Cow<'a, [u8]>,
+        options: &ReadOptions,
+        diagnostics: &RefCell<Diagnostics>,
+    ) -> Result<Self, ParseError> {
+        let looks_like_save = |data: &[u8]| {
+            data.starts_with(b"GVAS")
+                || options.containers.iter().any(|c| c.detect(data))
+                || Checksum::detect_header(data).is_some()
+        };
+        let encryption = match &options.aes_key {
+            Some(key) if !looks_like_save(&data) => {
+                let encryption = Encryption {
+                    mode: options.aes_mode,
+                    padding: options.aes_padding,
                 };
-                Ok((root, extra))
-            })?;
+                let decrypted = encryption
+                    .decrypt(key, &data)
+                    .map_err(|e| ParseError::new(e, 0, &data))?;
+                if !looks_like_save(&decrypted) && !options.allow_bad_magic {
+                    return Err(ParseError::new(
+                        Error::Other(
+                            "decrypted data is not a save, check the AES key and mode".into(),
+                        ),
+                        0,
+                        &decrypted,
+                    ));
+                }
+                data = Cow::Owned(decrypted);
+                Some(encryption)
+            }
+            _ => None,
+        };
+        let container = match Container::unwrap_with(&options.containers, &data, &options.limits) {
+            Ok(Some((container, unpacked))) => {
+                data = Cow::Owned(unpacked);
+                Some(container)
+            }
+            Ok(None) => None,
+            Err(e) => return Err(ParseError::new(e, 0, &data)),
+        };
+        let checksum = Checksum::detect_header(&data);
+        let mut start = 0;
+        if let Some(checksum) = checksum {
+            if !checksum.verify(&data) {
+                diagnostics.borrow_mut().push(Diagnostic {
+                    kind: DiagnosticKind::ChecksumMismatch,
+                    path: "".into(),
+                    offset: 0,
+                    message: format!(
+                        "{:?} checksum header does not match the save",
+                        checksum.algorithm
+                    ),
+                });
+            }
+            start = checksum.algorithm.size();
+        }
+        let data = match data {
+            Cow::Borrowed(data) => Cow::Borrowed(&data[start..]),
+            Cow::Owned(mut data) => {
+                data.drain(..start);
+                Cow::Owned(data)
+            }
+        };
+        let mut stream = std::io::Cursor::new(&data);
+        Self::read_gvas(
+            &mut stream,
+            options,
+            diagnostics,
+            checksum,
+            container,
+            encryption,
+        )
+    }
+    /// Reads the GVAS data of a save which was unwrapped from `container` and `encryption` and
+    /// whose checksum header, if any, has been stripped
+    fn read_gvas<S: SaveStream + Input<'a>>(
+        stream: &mut S,
+        options: &ReadOptions,
+        diagnostics: &RefCell<Diagnostics>,
+        mut checksum: Option<Checksum>,
+        container: Option<Container>,
+        encryption: Option<Encryption>,
+    ) -> Result<Self, ParseError> {
+        Context::run_with_options(options, diagnostics, stream, |reader| {
+            let header = Header::read(reader)?;
+            let (root, extra) = reader.header(&header, |reader| -> TResult<_> {
+                let root = Root::read(reader)?;
+                let extra = {
+                    let offset = reader.stream_position()?;
+                    let mut buf = vec![];
+                    reader.read_to_end(&mut buf)?;
+                    if let Some(Encryption {
+                        padding: AesPadding::Zero,
+                        ..
+                    }) = encryption
+                    {
+                        // keep the zeros terminating the save
+                        let padding = AesPadding::zero_padding(&buf);
+                        buf.truncate((buf.len() - padding).max(buf.len().min(4)));
+                    }
+                    let footer = match checksum {
+                        None => Checksum::detect_footer(&buf),
+                        Some(_) => None,
+                    };
+                    if let Some(footer) = footer {
+                        let computed = reader.stream.checksum(footer.algorithm, offset + 4);
+                        let matches = computed.as_deref() == Some(&buf[4..]);
+                        // trailing data which merely has the length of a checksum is only taken
+                        // as a stale footer if the save is known to have one
+                        if matches || reader.options.checksum == Some(footer) {
+                            if !matches {
+                                reader.warn(
+                                    DiagnosticKind::ChecksumMismatch,
+                                    offset + 4,
+                                    format!(
+                                        "{:?} checksum footer does not match the save",
+                                        footer.algorithm
+                                    ),
+                                );
+                            }
+                            buf.truncate(4);
+                            checksum = Some(footer);
+                        }
+                    }
+                    if buf != [0; 4] {
+                        if !reader.options.allow_trailing_data {
+                            reader.seek(std::io::SeekFrom::Start(offset))?;
+                            return Err(Error::Other(format!(
+                                "{} extra bytes following the root properties",
+                                buf.len()
+                            )));
+                        }
+                        reader.warn(
+                            DiagnosticKind::TrailingData,
+                            offset,
+                            format!(
+                                "{} extra bytes, save may not have been parsed completely",
+                                buf.len()
+                            ),
+                        );
+                    }
+                    buf
+                };
+                Ok((root, extra))
+            })?;
 
             Ok(Self {
                 header,
                 root,
                 extra,
+                checksum,
+                container,
+                encryption,
             })
         })
-        .map_err(|e| error::ParseError {
-            offset: reader.stream_position().unwrap() as usize, // our own implemenation which cannot fail
-            error: e,
-        })
+        .map_err(|e| ParseError::from_stream(e, stream))
     }
     pub fn write<W: Write>(&self, writer: &mut W) -> TResult<()> {
-        Context::run(writer, |writer| {
+        self.write_with_options(writer, &WriteOptions::new())
+    }
+    /// Writes save to the given writer using the provided [`WriteOptions`]
+    pub fn write_with_options<W: Write>(
+        &self,
+        writer: &mut W,
+        options: &WriteOptions,
+    ) -> TResult<()> {
+        if self.checksum.is_none() && self.container.is_none() && self.encryption.is_none() {
+            return self.write_gvas(writer, options);
+        }
+        let mut data = vec![];
+        self.write_gvas(&mut data, options)?;
+        if let Some(checksum) = &self.checksum {
+            data = checksum.apply(&data);
+        }
+        if let Some(container) = &self.container {
+            data = container.wrap(&data)?;
+        }
+        if let Some(encryption) = &self.encryption {
+            let key = options.aes_key.as_ref().ok_or_else(|| {
+                Error::Other("save is AES encrypted, a key is required to write it".into())
+            })?;
+            data = encryption.encrypt(key, &data)?;
+        }
+        writer.write_all(&data)?;
+        Ok(())
+    }
+    fn write_gvas<W: Write>(&self, writer: &mut W, options: &WriteOptions) -> TResult<()> {
+        Context::run_with_write_options(options, writer, |writer| {
             writer.header(&self.header, |writer| {
                 self.header.write(writer)?;
                 self.root.write(writer)?;
@@ -2809,44 +5614,1744 @@ mod tests {
         let mut reader = Cursor::new(&original);
         let header = Context::run(&mut reader, |reader| Header::read(reader))?;
         let mut reconstructed = vec![];
-        Context::run(&mut reconstructed, |writer| header.write(writer))?;
-        assert_eq!(original, &reconstructed[..]);
+        Context::run(&mut reconstructed, |writer| header.write(writer))?;
+        assert_eq!(original, &reconstructed[..]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_uuid() -> TResult<()> {
+        let id = uuid::uuid!("2eb5fdbd4d1001ac8ff33681daa59333");
+        let mut writer = vec![];
+        Context::run(&mut writer, |writer| id.write(writer))?;
+        let mut reader = Cursor::new(&writer);
+        let rid = Context::run(&mut reader, |reader| uuid::Uuid::read(reader))?;
+        assert_eq!(id, rid);
+        Ok(())
+    }
+
+    #[test]
+    fn test_uuid2() -> TResult<()> {
+        let id = uuid::uuid!("85b20ca1-49fb-7138-a154-c89a2c20e2cd");
+        let mut writer = vec![];
+        Context::run(&mut writer, |writer| id.write(writer))?;
+        assert_eq!(
+            writer,
+            [
+                0xa1, 0x0c, 0xb2, 0x85, 0x38, 0x71, 0xfb, 0x49, 0x9a, 0xc8, 0x54, 0xa1, 0xcd, 0xe2,
+                0x20, 0x2c
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rw_save1() -> TResult<()> {
+        let mut reader = Cursor::new(&SAVE);
+        let obj = Save::read(&mut reader).unwrap();
+        let mut reconstructed: Vec<u8> = vec![];
+        obj.write(&mut reconstructed)?;
+        assert_eq!(SAVE, reconstructed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_patch() -> TResult<()> {
+        let mut expected = Save::read(&mut Cursor::new(SAVE)).unwrap();
+        let mut patch = Patch::new(SAVE.to_vec()).unwrap();
+
+        // same size, only the value changes
+        let games = Property::Int {
+            id: None,
+            value: 1234,
+            complete_type: None,
+            trailing: vec![],
+        };
+        let span = patch.span(".NumberOfGamesPlayed").unwrap();
+        patch.replace(".NumberOfGamesPlayed", &games)?;
+        let differing: Vec<_> = (0..SAVE.len())
+            .filter(|&i| SAVE[i] != patch.data()[i])
+            .collect();
+        assert!(differing.iter().all(|i| span.contains(i)));
+        expected.root.properties["NumberOfGamesPlayed"] = games;
+
+        // nested property of a different size updates the enclosing struct tag
+        let (parent, key) = expected
+            .root
+            .properties
+            .0
+            .iter()
+            .find_map(|(parent, property)| match property {
+                Property::Struct {
+                    value: StructValue::Struct(properties),
+                    ..
+                } => properties
+                    .0
+                    .keys()
+                    .next()
+                    .map(|key| (parent.1.clone(), key)),
+                _ => None,
+            })
+            .unwrap();
+        let path = format!(".{parent}.{}#{}", key.1, key.0);
+        let replacement = Property::Str {
+            id: None,
+            value: "patched".into(),
+            complete_type: None,
+            trailing: vec![],
+        };
+        patch.replace(&path, &replacement)?;
+        let key = PropertyKey(key.0, key.1.clone());
+        match &mut expected.root.properties[parent.as_str()] {
+            Property::Struct {
+                value: StructValue::Struct(properties),
+                ..
+            } => properties.0[&key] = replacement,
+            _ => unreachable!(),
+        }
+
+        // tag sizes have to match exactly
+        let options = ReadOptions {
+            allow_inferred_types: true,
+            ..ReadOptions::strict()
+        };
+        let data = &mut Cursor::new(patch.data());
+        let patched = Save::read_with_options(data, &options, &mut Diagnostics::new()).unwrap();
+        assert_eq!(expected, patched);
+        let missing = Property::Bool {
+            id: None,
+            value: true,
+            complete_type: None,
+            trailing: vec![],
+        };
+        assert!(patch.replace(".Missing", &missing).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_save_streaming() -> TResult<()> {
+        // read from a stream which cannot seek, keeping only the data still required
+        let mut stream = seek::SeekReader::new(SAVE);
+        let save = Save::read_gvas(
+            &mut stream,
+            &ReadOptions::new(),
+            &RefCell::new(Diagnostics::new()),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(save, Save::read(&mut Cursor::new(SAVE)).unwrap());
+        assert!(stream.seek(std::io::SeekFrom::Start(0)).is_err());
+
+        // the window of an error is read back from the stream
+        let truncated = &SAVE[..SAVE.len() / 2];
+        let error = Save::read(&mut &truncated[..]).unwrap_err();
+        assert_eq!(error.window_offset, error.offset - 16);
+        assert_eq!(error.window, &truncated[error.window_offset..]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_save() -> TResult<()> {
+        let save = Save::parse(SAVE).unwrap();
+        assert_eq!(save, Save::read(&mut Cursor::new(SAVE)).unwrap());
+        // names and strings point into the data instead of being copied
+        assert!(save.root.save_game_type.is_borrowed());
+        assert!(save.root.properties.0.keys().all(|key| key.1.is_borrowed()));
+        let mut written = vec![];
+        save.write(&mut written)?;
+        assert_eq!(SAVE, written);
+
+        // data which has to be unwrapped first is owned
+        let packed = Container::LengthPrefixed(LengthPrefixedContainer).wrap(SAVE)?;
+        let save = Save::parse(&packed).unwrap();
+        assert!(!save.root.save_game_type.is_borrowed());
+        assert_eq!(save, Save::read(&mut Cursor::new(&packed)).unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_save_diagnostics() -> Result<(), ParseError> {
+        let mut data = SAVE.to_vec();
+        data.extend([0xff; 4]);
+        let reported = std::rc::Rc::new(RefCell::new(vec![]));
+        let mut diagnostics = Diagnostics::with_callback({
+            let reported = reported.clone();
+            move |d| reported.borrow_mut().push(d.kind)
+        });
+        Save::read_with_diagnostics(&mut Cursor::new(&data), &Types::new(), &mut diagnostics)?;
+        let diagnostic = diagnostics.iter().last().unwrap();
+        assert_eq!(diagnostic.kind, DiagnosticKind::TrailingData);
+        assert_eq!(diagnostic.offset, SAVE.len() - 4);
+        assert_eq!(reported.borrow().len(), diagnostics.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_save_strict() -> Result<(), ParseError> {
+        let error = Save::read_with_options(
+            &mut Cursor::new(&SAVE),
+            &ReadOptions::strict(),
+            &mut Diagnostics::new(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.property.unwrap().path,
+            ".FSDEventRewardsSave.EventsSeen"
+        );
+
+        let options = ReadOptions {
+            allow_inferred_types: true,
+            ..ReadOptions::strict()
+        };
+        Save::read_with_options(&mut Cursor::new(&SAVE), &options, &mut Diagnostics::new())?;
+
+        let mut data = SAVE.to_vec();
+        data.extend([0xff; 4]);
+        let error =
+            Save::read_with_options(&mut Cursor::new(&data), &options, &mut Diagnostics::new())
+                .unwrap_err();
+        assert_eq!(error.offset, SAVE.len() - 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_save_utf16() -> TResult<()> {
+        let save = Save::read(&mut Cursor::new(&SAVE)).unwrap();
+        let mut utf16 = vec![];
+        let options = WriteOptions::new().string_encoding(StringEncoding::Utf16);
+        save.write_with_options(&mut utf16, &options)?;
+        assert!(utf16.len() > SAVE.len());
+        let reread = Save::read(&mut Cursor::new(&utf16)).unwrap();
+        assert_eq!(save, reread);
+
+        // the encoding of every value is remembered and written back as it was read
+        assert_eq!(
+            reread.root.save_game_type.format(),
+            Some(&StringFormat {
+                utf16: true,
+                trailing: vec![0, 0],
+            })
+        );
+        let mut rewritten = vec![];
+        reread.write_with_options(&mut rewritten, &options)?;
+        assert_eq!(rewritten, utf16);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rw_string_formats() -> TResult<()> {
+        #[rustfmt::skip]
+        let original = [
+            // empty string with terminator
+            1, 0, 0, 0, 0,
+            // bytes following the terminator
+            4, 0, 0, 0, b'a', b'b', 0, b'x',
+            // ASCII as UTF-16
+            0xfd, 0xff, 0xff, 0xff, b'h', 0, b'i', 0, 0, 0,
+            // non-ASCII as UTF-8
+            4, 0, 0, 0, 0xc3, 0xa9, b'!', 0,
+            // default
+            3, 0, 0, 0, b'o', b'k', 0,
+        ];
+        let mut reader = Cursor::new(&original[..]);
+        let values = Context::run(&mut reader, |reader| read_array(5, reader, read_string))?;
+        assert_eq!(values, ["", "ab", "hi", "é!", "ok"]);
+        assert_eq!(
+            values
+                .iter()
+                .map(|v| v.format().is_some())
+                .collect::<Vec<_>>(),
+            [true, true, true, true, false]
+        );
+
+        let mut written = vec![];
+        Context::run(&mut written, |writer| {
+            values.iter().try_for_each(|v| write_string(writer, v))
+        })?;
+        assert_eq!(written, original);
+
+        // only strings with a non-default format are more than a plain string in JSON
+        let json = serde_json::to_value(&values).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"value": "", "utf16": false, "trailing": "00"},
+                {"value": "ab", "utf16": false, "trailing": "0078"},
+                {"value": "hi", "utf16": true, "trailing": "0000"},
+                {"value": "é!", "utf16": false, "trailing": "00"},
+                "ok",
+            ])
+        );
+        let values: Vec<FString> = serde_json::from_value(json).unwrap();
+        let mut written = vec![];
+        Context::run(&mut written, |writer| {
+            values.iter().try_for_each(|v| write_string(writer, v))
+        })?;
+        assert_eq!(written, original);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lossy_strings() -> TResult<()> {
+        // invalid UTF-8
+        let original = [4, 0, 0, 0, b'a', 0xff, b'b', 0];
+
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let error = Context::run_with_options(
+            &ReadOptions::new(),
+            &diagnostics,
+            &mut Cursor::new(&original),
+            read_string,
+        )
+        .unwrap_err();
+        assert!(matches!(error, Error::Other(_)));
+
+        let value = Context::run_with_options(
+            &ReadOptions::lenient(),
+            &diagnostics,
+            &mut Cursor::new(&original),
+            read_string,
+        )?;
+        assert_eq!(value, "a\u{fffd}b");
+        let diagnostics = diagnostics.into_inner();
+        assert_eq!(
+            diagnostics.iter().next().unwrap().kind,
+            DiagnosticKind::LossyString
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_raw_fallback() -> TResult<()> {
+        let original = [
+            0x06, 0x00, 0x00, 0x00, 0x53, 0x6b, 0x69, 0x6e, 0x73, 0x00, 0x0c, 0x00, 0x00, 0x00,
+            0x53, 0x65, 0x74, 0x50, 0x72, 0x6f, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x0d, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x53, 0x74, 0x72, 0x75,
+            0x63, 0x74, 0x50, 0x72, 0x6f, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05,
+        ];
+        let error = Context::run(&mut Cursor::new(original), read_property).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Property { error, .. } if matches!(*error, Error::UninferredStructType(_))
+        ));
+
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let (_, property) = Context::run_with_options(
+            &ReadOptions::lenient(),
+            &diagnostics,
+            &mut Cursor::new(original),
+            read_property,
+        )?
+        .unwrap();
+        assert!(matches!(property, Property::Raw { .. }));
+        assert_eq!(
+            diagnostics.borrow().iter().next().unwrap().kind,
+            DiagnosticKind::RawFallback
+        );
+        let mut reconstructed = vec![];
+        Context::run(&mut reconstructed, |writer| {
+            write_property((&"Skins".into(), &property), writer)
+        })?;
+        assert_eq!(&original[..], &reconstructed[..]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_recover() -> TResult<()> {
+        let count = Property::Int {
+            id: None,
+            value: 7,
+            complete_type: None,
+            trailing: vec![],
+        };
+        let mut original = vec![];
+        Context::run(&mut original, |writer| {
+            write_string(writer, "Broken")?;
+            write_string(writer, "StructProperty")?;
+            writer.write_u32::<LE>(8)?;
+            writer.write_u32::<LE>(0)?;
+            write_string(writer, "Foo")?;
+            uuid::Uuid::nil().write(writer)?;
+            writer.write_u8(0)?;
+            // nested property name claiming to be longer than the rest of the save
+            writer.write_all(&[0x00, 0x00, 0xff, 0x7f, 0x01, 0x02, 0x03, 0x04])?;
+            write_property((&"Count".into(), &count), writer)?;
+            write_string(writer, "None")
+        })?;
+
+        let error =
+            Context::run(&mut Cursor::new(&original), read_properties_until_none).unwrap_err();
+        assert!(matches!(error, Error::Property { .. }));
+
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let properties = Context::run_with_options(
+            &ReadOptions::lenient(),
+            &diagnostics,
+            &mut Cursor::new(&original),
+            read_properties_until_none,
+        )?;
+        assert!(matches!(properties["Broken"], Property::Raw { .. }));
+        assert_eq!(properties["Count"], count);
+        let diagnostic = diagnostics.borrow().iter().next().unwrap().clone();
+        assert_eq!(diagnostic.kind, DiagnosticKind::RawFallback);
+        assert_eq!(diagnostic.path, ".Broken");
+
+        let mut reconstructed = vec![];
+        Context::run(&mut reconstructed, |writer| {
+            write_properties_none_terminated(writer, &properties)
+        })?;
+        assert_eq!(original, reconstructed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_recover_string_formats() -> TResult<()> {
+        let mut original = vec![];
+        Context::run(&mut original, |writer| {
+            write_string(writer, "Broken")?;
+            write_string(writer, "StructProperty")?;
+            writer.write_u32::<LE>(8)?;
+            writer.write_u32::<LE>(0)?;
+            write_string(writer, "Foo")?;
+            uuid::Uuid::nil().write(writer)?;
+            writer.write_u8(0)?;
+            writer.write_all(&[0x00, 0x00, 0xff, 0x7f, 0x01, 0x02, 0x03, 0x04])?;
+            write_string(writer, "Greeting")?;
+            write_string(writer, "StrProperty")?;
+            writer.write_u32::<LE>(10)?;
+            writer.write_u32::<LE>(0)?;
+            writer.write_u8(0)?;
+            // ASCII as UTF-16
+            writer.write_all(&[0xfd, 0xff, 0xff, 0xff, b'h', 0, b'i', 0, 0, 0])?;
+            write_string(writer, "None")
+        })?;
+
+        let options = ReadOptions::lenient();
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let properties = Context::run_with_options(
+            &options,
+            &diagnostics,
+            &mut Cursor::new(&original),
+            read_properties_until_none,
+        )?;
+        assert!(matches!(properties["Broken"], Property::Raw { .. }));
+
+        let mut reconstructed = vec![];
+        Context::run(&mut reconstructed, |writer| {
+            write_properties_none_terminated(writer, &properties)
+        })?;
+        assert_eq!(original, reconstructed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_path() {
+        let int = |value| Property::Int {
+            id: None,
+            value,
+            complete_type: None,
+            trailing: vec![],
+        };
+        let mut position = Properties::default();
+        position.insert(
+            "Rotation",
+            Property::Struct {
+                struct_type: StructType::Quat,
+                struct_id: uuid::Uuid::nil(),
+                id: None,
+                value: StructValue::Quat(Quat {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                    w: 1.0,
+                }),
+                complete_type: None,
+                trailing: vec![],
+            },
+        );
+        let mut prop = Properties::default();
+        prop.insert(
+            "Position_7_B8CD",
+            Property::Struct {
+                struct_type: StructType::Struct(Some("Transform".into())),
+                struct_id: uuid::Uuid::nil(),
+                id: None,
+                value: StructValue::Struct(position),
+                complete_type: None,
+                trailing: vec![],
+            },
+        );
+        let mut properties = Properties::default();
+        properties.insert(
+            "PropList",
+            Property::Array {
+                array_type: PropertyType::StructProperty,
+                id: None,
+                value: ValueArray::Struct {
+                    _type: "StructProperty".into(),
+                    name: "PropList".into(),
+                    struct_type: StructType::Struct(Some("Prop".into())),
+                    id: uuid::Uuid::nil(),
+                    value: vec![
+                        StructValue::Struct(Properties::default()),
+                        StructValue::Struct(prop),
+                    ],
+                },
+                complete_type: None,
+                trailing: vec![],
+            },
+        );
+        properties.insert(
+            "Counts",
+            Property::Array {
+                array_type: PropertyType::IntProperty,
+                id: None,
+                value: ValueArray::Base(ValueVec::Int(vec![1, 2, 3])),
+                complete_type: None,
+                trailing: vec![],
+            },
+        );
+        properties.insert(
+            "Scores",
+            Property::Map {
+                id: None,
+                key_type: PropertyType::StrProperty,
+                value_type: PropertyType::IntProperty,
+                value: vec![MapEntry {
+                    key: PropertyValue::Str("a.b".into()),
+                    value: PropertyValue::Int(5),
+                }],
+                complete_type: None,
+                trailing: vec![],
+            },
+        );
+        properties.insert(PropertyKey(0, "Slot".into()), int(1));
+        properties.insert(PropertyKey(2, "Slot".into()), int(3));
+        properties.insert("Odd.Name", int(4));
+
+        let rotation = "PropList[1].Position_7_B8CD.Rotation";
+        assert!(matches!(
+            properties.get_path(rotation),
+            Some(ValueRef::Property(Property::Struct {
+                value: StructValue::Quat(Quat { w, .. }),
+                ..
+            })) if *w == 1.0
+        ));
+        assert_eq!(
+            properties.get_path(&format!(".{rotation}")),
+            properties.get_path(rotation)
+        );
+        assert!(matches!(
+            properties.get_path("PropList[0]"),
+            Some(ValueRef::Struct(StructValue::Struct(_)))
+        ));
+        assert_eq!(properties.get_path("PropList[2]"), None);
+        assert!(matches!(
+            properties.get_path("Counts[2]"),
+            Some(ValueRef::Element(ValueVec::Int(_), 2))
+        ));
+        assert_eq!(
+            properties.get_path(r#"Scores["a.b"]"#),
+            Some(ValueRef::Value(&PropertyValue::Int(5)))
+        );
+        assert_eq!(properties.get_path(r#"Scores["a"]"#), None);
+        assert_eq!(
+            properties.get_path(r#"Scores["a.b"].Key"#),
+            Some(ValueRef::Value(&PropertyValue::Str("a.b".into())))
+        );
+        assert_eq!(
+            properties.get_path(r#"Scores["a.b"].Value"#),
+            properties.get_path(r#"Scores["a.b"]"#)
+        );
+        assert_eq!(properties.get_path(r#"Scores["a.b"].Other"#), None);
+        assert_eq!(
+            properties.get_path("Slot#2"),
+            Some(ValueRef::Property(&int(3)))
+        );
+        assert_eq!(
+            properties.get_path("Slot"),
+            Some(ValueRef::Property(&int(1)))
+        );
+        assert_eq!(
+            properties.get_path(r"Odd\.Name"),
+            Some(ValueRef::Property(&int(4)))
+        );
+        assert_eq!(properties.get_path("PropList[1"), None);
+        assert_eq!(properties.get_path("Counts.Missing"), None);
+
+        if let Some(ValueMut::Value(value)) = properties.get_path_mut(r#"Scores["a.b"]"#) {
+            *value = PropertyValue::Int(6);
+        }
+        assert_eq!(
+            properties.get_path(r#".Scores["a.b"]"#),
+            Some(ValueRef::Value(&PropertyValue::Int(6)))
+        );
+
+        let mut types = Types::new();
+        types.add(rotation.into(), StructType::Quat);
+        types.add(".Scores[\"x\"].Value.Inner".into(), StructType::Vector);
+        types.add(r"Odd\.Name".into(), StructType::Guid);
+        types.add(".Odd.Name".into(), StructType::Vector);
+        assert_eq!(
+            types.types.get(".PropList.Position_7_B8CD.Rotation"),
+            Some(&StructType::Quat)
+        );
+        assert_eq!(
+            types.types.get(".Scores.Value.Inner"),
+            Some(&StructType::Vector)
+        );
+        assert_eq!(types.types.get(r".Odd\.Name"), Some(&StructType::Guid));
+        assert_eq!(types.types.get(".Odd.Name"), Some(&StructType::Vector));
+    }
+
+    #[test]
+    fn test_typed_access() -> TResult<()> {
+        let id = Some(uuid::Uuid::from_u128(1));
+        let mut int = Property::Int {
+            id,
+            value: 1,
+            complete_type: None,
+            trailing: vec![],
+        };
+        assert_eq!(int.as_i32(), Some(1));
+        assert_eq!(int.as_i64(), None);
+        int.set_i32(2)?;
+        assert_eq!(
+            int,
+            Property::Int {
+                id,
+                value: 2,
+                complete_type: None,
+                trailing: vec![],
+            }
+        );
+        assert!(matches!(
+            int.set_f32(1.0),
+            Err(Error::TypeMismatch { expected, found })
+                if expected == "FloatProperty" && found == "IntProperty"
+        ));
+        assert_eq!(i32::try_from(int)?, 2);
+        assert!(i32::try_from(Property::from(2i64)).is_err());
+
+        let mut label = Property::Enum {
+            id: None,
+            value: "EColor::Red".into(),
+            enum_type: "EColor".into(),
+            complete_type: None,
+            trailing: vec![],
+        };
+        label.set_str("EColor::Blue")?;
+        assert_eq!(label.as_str(), Some("EColor::Blue"));
+        assert!(matches!(&label, Property::Enum { enum_type, .. } if enum_type == "EColor"));
+        assert_eq!(String::try_from(label)?, "EColor::Blue");
+        assert_eq!(Property::from("a").as_str(), Some("a"));
+        assert_eq!(Property::from(7u8).as_u8(), Some(7));
+
+        let mut floats = Property::from(vec![1.0f32, 2.0]);
+        assert_eq!(floats.as_array::<f32>(), Some(&[1.0, 2.0][..]));
+        assert_eq!(floats.as_array::<i32>(), None);
+        floats.as_array_mut::<f32>().unwrap().push(3.0);
+        floats.set_array(vec![4.0f32])?;
+        assert!(floats.set_array(vec![1i32]).is_err());
+        assert_eq!(Vec::<f32>::try_from(floats)?, vec![4.0]);
+
+        let mut rotation = Property::from(Quat {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        });
+        if let Property::Struct { struct_id, .. } = &mut rotation {
+            *struct_id = uuid::Uuid::from_u128(2);
+        }
+        assert_eq!(
+            rotation
+                .as_struct()
+                .and_then(StructValue::as_quat)
+                .map(|q| q.w),
+            Some(1.0)
+        );
+        assert!(rotation
+            .as_struct()
+            .and_then(StructValue::as_vector)
+            .is_none());
+        rotation.set_struct(StructValue::from(Quat {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        }))?;
+        assert!(rotation
+            .set_struct(StructValue::Vector(Vector {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0
+            }))
+            .is_err());
+        assert!(matches!(
+            &rotation,
+            Property::Struct { struct_type: StructType::Quat, struct_id, .. }
+                if *struct_id == uuid::Uuid::from_u128(2)
+        ));
+        assert_eq!(Quat::try_from(rotation)?.x, 1.0);
+
+        assert_eq!(PropertyValue::from(3u32).as_u32(), Some(3));
+        assert_eq!(PropertyValue::from("b").as_str(), Some("b"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_properties() -> TResult<()> {
+        use std::collections::{HashMap, HashSet};
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        enum Color {
+            Red,
+            Blue,
+        }
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Item {
+            name: String,
+            count: u8,
+        }
+        #[derive(Debug, PartialEq, Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Player {
+            level: i32,
+            color: Color,
+            rotation: Quat,
+            items: Vec<Item>,
+            scores: HashMap<String, i32>,
+            tags: HashSet<String>,
+            slots: Vec<i64>,
+            missing: Option<bool>,
+        }
+
+        let mut item = Properties::default();
+        item.insert("Name_2_AB977B2F47FD519F53FB8CB85490631B", "Sword".into());
+        item.insert("Count_5_AA6C35BE4D24AB6B42E8999E55661065", 3u8.into());
+        let mut properties = Properties::default();
+        properties.insert("Level", 7i32.into());
+        properties.insert(
+            "Color",
+            Property::Enum {
+                id: None,
+                value: "EColor::Blue".into(),
+                enum_type: "EColor".into(),
+                complete_type: None,
+                trailing: vec![],
+            },
+        );
+        properties.insert(
+            "Rotation",
+            Quat {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+                w: 1.0,
+            }
+            .into(),
+        );
+        properties.insert(
+            "Items",
+            Property::Array {
+                array_type: PropertyType::StructProperty,
+                id: None,
+                value: ValueArray::Struct {
+                    _type: "StructProperty".into(),
+                    name: "Items".into(),
+                    struct_type: StructType::Struct(Some("Item".into())),
+                    id: uuid::Uuid::nil(),
+                    value: vec![StructValue::Struct(item)],
+                },
+                complete_type: None,
+                trailing: vec![],
+            },
+        );
+        properties.insert(
+            "Scores",
+            Property::Map {
+                id: None,
+                key_type: PropertyType::StrProperty,
+                value_type: PropertyType::IntProperty,
+                value: vec![MapEntry {
+                    key: PropertyValue::Str("a".into()),
+                    value: PropertyValue::Int(5),
+                }],
+                complete_type: None,
+                trailing: vec![],
+            },
+        );
+        properties.insert(
+            "Tags",
+            Property::Set {
+                id: None,
+                set_type: PropertyType::NameProperty,
+                value: ValueSet::Base(ValueVec::Name(vec!["x".into(), "y".into()])),
+                complete_type: None,
+                trailing: vec![],
+            },
+        );
+        // static array stored as properties sharing a name, out of order
+        properties.insert(PropertyKey(1, "Slots".into()), 20i64.into());
+        properties.insert(PropertyKey(0, "Slots".into()), 10i64.into());
+
+        assert!(from_properties::<Player>(&properties).is_err());
+        let options = FromPropertiesOptions {
+            ignore_guid_suffixes: true,
+        };
+        let player: Player = from_properties_with_options(&properties, &options)?;
+        assert_eq!(
+            player,
+            Player {
+                level: 7,
+                color: Color::Blue,
+                rotation: Quat {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                    w: 1.0
+                },
+                items: vec![Item {
+                    name: "Sword".into(),
+                    count: 3
+                }],
+                scores: HashMap::from([("a".into(), 5)]),
+                tags: HashSet::from(["x".into(), "y".into()]),
+                slots: vec![10, 20],
+                missing: None,
+            }
+        );
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Borrowed<'a> {
+            #[serde(rename = "Level")]
+            level: i32,
+            #[serde(rename = "Tags", borrow)]
+            tags: Vec<&'a str>,
+        }
+        let borrowed: Borrowed = from_properties(&properties)?;
+        assert_eq!(borrowed.tags, ["x", "y"]);
+        assert!(matches!(
+            from_properties::<Borrowed>(&Properties::default()),
+            Err(Error::Other(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_properties() -> TResult<()> {
+        use std::collections::BTreeMap;
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        enum Color {
+            Red,
+            Blue,
+        }
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Item {
+            name: String,
+            count: u8,
+        }
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Player {
+            level: i32,
+            gold: i32,
+            color: Color,
+            class: String,
+            rotation: Quat,
+            id: uuid::Uuid,
+            items: Vec<Item>,
+            scores: BTreeMap<String, f32>,
+            tags: Vec<String>,
+            missing: Option<bool>,
+        }
+        let player = Player {
+            level: 7,
+            gold: 100,
+            color: Color::Blue,
+            class: "/Game/Player.Player_C".into(),
+            rotation: Quat {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+                w: 1.0,
+            },
+            id: uuid::Uuid::from_u128(1),
+            items: vec![Item {
+                name: "Sword".into(),
+                count: 3,
+            }],
+            scores: BTreeMap::from([("a".into(), 1.5)]),
+            tags: vec!["x".into()],
+            missing: None,
+        };
+
+        let mut hints = TypeHints::new();
+        hints.add(
+            ".Gold".into(),
+            TypeHint::Property(PropertyType::Int64Property),
+        );
+        hints.add(".Color".into(), TypeHint::Enum("EColor".into()));
+        hints.add(
+            ".Class".into(),
+            TypeHint::Property(PropertyType::ObjectProperty),
+        );
+        hints.add(".Rotation".into(), TypeHint::Struct(StructType::Quat));
+        hints.add(".Id".into(), TypeHint::Struct(StructType::Guid));
+        hints.add(
+            ".Scores.Key".into(),
+            TypeHint::Property(PropertyType::NameProperty),
+        );
+        hints.add(
+            ".Tags".into(),
+            TypeHint::Property(PropertyType::NameProperty),
+        );
+        hints.add_set(".Tags".into());
+        let properties = to_properties_with_hints(&player, &hints)?;
+
+        assert_eq!(properties["Level"], Property::from(7));
+        assert_eq!(properties["Gold"], Property::from(100i64));
+        assert!(matches!(
+            &properties["Color"],
+            Property::Enum { value, enum_type, .. } if value == "EColor::Blue" && enum_type == "EColor"
+        ));
+        assert!(matches!(&properties["Class"], Property::Object { .. }));
+        assert!(matches!(
+            &properties["Rotation"],
+            Property::Struct {
+                value: StructValue::Quat(_),
+                struct_type: StructType::Quat,
+                ..
+            }
+        ));
+        assert!(matches!(
+            &properties["Id"],
+            Property::Struct { value: StructValue::Guid(id), .. } if *id == player.id
+        ));
+        assert!(matches!(
+            &properties["Items"],
+            Property::Array {
+                value: ValueArray::Struct { struct_type: StructType::Struct(Some(t)), .. },
+                ..
+            } if t == "Item"
+        ));
+        assert!(matches!(
+            &properties["Scores"],
+            Property::Map {
+                key_type: PropertyType::NameProperty,
+                value_type: PropertyType::FloatProperty,
+                ..
+            }
+        ));
+        assert!(matches!(
+            &properties["Tags"],
+            Property::Set {
+                value: ValueSet::Base(ValueVec::Name(_)),
+                ..
+            }
+        ));
+        assert!(properties.0.get(&PropertyKey::from("Missing")).is_none());
+
+        // round trip through the binary format
+        let mut data = vec![];
+        Context::run(&mut data, |writer| {
+            write_properties_none_terminated(writer, &properties)
+        })?;
+        let read = Context::run(&mut Cursor::new(&data), read_properties_until_none)?;
+        assert_eq!(read, properties);
+        assert_eq!(from_properties::<Player>(&read)?, player);
+
+        assert!(to_properties(&1).is_err());
+        #[derive(Serialize)]
+        struct Empty {
+            values: Vec<i32>,
+        }
+        assert!(to_properties(&Empty { values: vec![] }).is_err());
+        let mut hints = TypeHints::new();
+        hints.add(
+            ".values".into(),
+            TypeHint::Property(PropertyType::IntProperty),
+        );
+        assert!(to_properties_with_hints(&Empty { values: vec![] }, &hints).is_ok());
+        hints.add(
+            ".values".into(),
+            TypeHint::Property(PropertyType::Int8Property),
+        );
+        assert!(to_properties_with_hints(&Empty { values: vec![300] }, &hints).is_err());
+
+        // structs of the game named like engine structs are made up of properties
+        #[derive(Serialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Vector {
+            name: String,
+        }
+        #[derive(Serialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Spawn {
+            location: Vector,
+        }
+        let location = Vector {
+            name: "Start".into(),
+        };
+        let properties = to_properties(&Spawn { location })?;
+        assert!(matches!(
+            &properties["Location"],
+            Property::Struct {
+                struct_type: StructType::Struct(Some(t)),
+                value: StructValue::Struct(_),
+                ..
+            } if t == "Vector"
+        ));
+        let properties = to_properties(&Vector {
+            name: "Start".into(),
+        })?;
+        assert_eq!(properties["Name"], Property::from("Start"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_builder() -> TResult<()> {
+        let drg = Save::read(&mut Cursor::new(SAVE)).unwrap();
+        let header = EngineVersion::UE4_27.header();
+        assert_eq!(header.save_game_version, drg.header.save_game_version);
+        assert_eq!(header.package_version, drg.header.package_version);
+        assert_eq!(header.custom_format, drg.header.custom_format);
+        let prop_pack = Save::read(&mut Cursor::new(include_bytes!(
+            "../examples/space-rig-decorator/PropPack.sav"
+        )))
+        .unwrap();
+        let header = EngineVersion::UE4_25.header();
+        assert_eq!(header.package_version, prop_pack.header.package_version);
+        assert_eq!(header.custom_format, prop_pack.header.custom_format);
+
+        for version in [
+            EngineVersion::UE4_25,
+            EngineVersion::UE4_26,
+            EngineVersion::UE4_27,
+            EngineVersion::UE5_0,
+            EngineVersion::UE5_1,
+            EngineVersion::UE5_2,
+            EngineVersion::UE5_3,
+            EngineVersion::UE5_4,
+        ] {
+            let id = uuid::Uuid::from_u128(1);
+            let save = Save::builder()
+                .engine_version(version)
+                .custom_version(id, 1)
+                .custom_version(id, 2)
+                .save_game_type("/Script/Game.TestSaveGame")
+                .property("Level", 7.into())
+                .property(
+                    "Location",
+                    Vector {
+                        x: 1.0,
+                        y: 2.0,
+                        z: 3.0,
+                    }
+                    .into(),
+                )
+                .build();
+            let custom_format = &save.header.custom_format;
+            assert_eq!(
+                custom_format.len(),
+                version.header().custom_format.len() + 1
+            );
+            assert_eq!(custom_format.iter().find(|c| c.id == id).unwrap().value, 2);
+            let mut data = vec![];
+            save.write(&mut data)?;
+            assert_eq!(Save::read(&mut Cursor::new(data)).unwrap(), save);
+        }
+
+        let header = EngineVersion::UE5_4.header();
+        assert_eq!(header.save_game_version, 3);
+        assert_eq!(header.package_version, ue54_header().package_version);
+        assert!(header.large_world_coordinates());
+        assert!(header.property_tag_complete_type_name());
+        assert!(!EngineVersion::UE5_3
+            .header()
+            .property_tag_complete_type_name());
+        assert!(!EngineVersion::UE4_26.header().large_world_coordinates());
+
+        // other versions start from a header of their own
+        let save = Save::builder()
+            .header(ue54_header())
+            .property("Level", 7.into())
+            .build();
+        assert_eq!(save.header, ue54_header());
+        let mut data = vec![];
+        save.write(&mut data)?;
+        assert_eq!(Save::read(&mut Cursor::new(data)).unwrap(), save);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_container_size_too_small() -> TResult<()> {
+        for (type_name, inner) in [
+            ("ArrayProperty", &["IntProperty"][..]),
+            ("SetProperty", &["StructProperty"]),
+            ("MapProperty", &["StructProperty", "StructProperty"]),
+        ] {
+            let mut original = vec![];
+            Context::run(&mut original, |writer| {
+                write_string(writer, "Small")?;
+                write_string(writer, type_name)?;
+                writer.write_u32::<LE>(2)?;
+                writer.write_u32::<LE>(0)?;
+                for inner in inner {
+                    write_string(writer, *inner)?;
+                }
+                writer.write_all(&[0; 8])?;
+                Ok::<_, Error>(())
+            })?;
+            let error = Context::run(&mut Cursor::new(&original), read_property).unwrap_err();
+            assert!(matches!(
+                error,
+                Error::Property { error, .. } if matches!(*error, Error::Other(_))
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_discards_rejected_diagnostics() -> TResult<()> {
+        let mut original = vec![];
+        Context::run(&mut original, |writer| {
+            write_string(writer, "Counts")?;
+            write_string(writer, "MapProperty")?;
+            writer.write_u32::<LE>(18)?;
+            writer.write_u32::<LE>(0)?;
+            write_string(writer, "ByteProperty")?;
+            write_string(writer, "IntProperty")?;
+            writer.write_u8(0)?;
+            writer.write_u32::<LE>(0)?;
+            writer.write_u32::<LE>(2)?;
+            // read as enum keys the first key is a string containing invalid UTF-8 followed by
+            // a value and a truncated second key
+            writer.write_all(&[0x02, 0x00, 0x00, 0x00, 0xFF])?;
+            writer.write_all(&[0x00, 0x07, 0x00, 0x00, 0x00])?;
+            Ok::<_, Error>(())
+        })?;
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let (_, property) = Context::run_with_options(
+            &ReadOptions::new(),
+            &diagnostics,
+            &mut Cursor::new(&original),
+            read_property,
+        )?
+        .unwrap();
+        assert!(matches!(
+            property,
+            Property::Map { value, .. } if value[0].key == PropertyValue::Byte(Byte::Byte(2))
+        ));
+        assert!(diagnostics.borrow().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_discards_rejected_allocations() -> TResult<()> {
+        let data = 7u64.to_le_bytes();
+        Context::run(&mut Cursor::new(&data[..]), |reader| {
+            let allocated = reader.usage.allocated();
+            // the first candidate is rejected, the others fit
+            let (candidate, value, ambiguous) = infer_struct_types(
+                reader,
+                8,
+                vec![0, 1, 2],
+                |_, _| true,
+                |reader, &candidate| {
+                    reader.allocate(100)?;
+                    match candidate {
+                        0 => Err(Error::Other("rejected".into())),
+                        _ => Ok(reader.read_u64::<LE>()?),
+                    }
+                },
+            )?;
+            assert_eq!((candidate, value, ambiguous), (1, 7, vec![2]));
+            // only the accepted candidate, the buffer is lent by the input
+            assert_eq!(reader.usage.allocated() - allocated, 100);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_read_container_limits() -> TResult<()> {
+        let limits = Limits {
+            max_allocation: 1 << 20,
+            ..Limits::untrusted()
+        };
+        // compresses to a few kilobytes
+        let bomb = vec![0; 16 << 20];
+        for container in [
+            Container::Palworld(PalworldContainer { save_type: 0x31 }),
+            Container::Palworld(PalworldContainer { save_type: 0x32 }),
+            Container::Chunked(ChunkedContainer::default()),
+        ] {
+            let packed = container.wrap(&bomb)?;
+            let error =
+                Container::unwrap_with(&Container::builtin(), &packed, &limits).unwrap_err();
+            assert!(matches!(
+                error,
+                Error::LimitExceeded {
+                    limit: Limit::Allocation,
+                    ..
+                }
+            ));
+        }
+
+        // a PlZ header understating the length does not decompress everything either
+        let mut packed = Container::Palworld(PalworldContainer { save_type: 0x31 }).wrap(&bomb)?;
+        packed[..4].copy_from_slice(&16u32.to_le_bytes());
+        let error = Container::unwrap_with(&Container::builtin(), &packed, &limits).unwrap_err();
+        assert!(error.to_string().contains("found 17"));
+
+        let error = Save::read_with_options(
+            &mut Cursor::new(&bomb),
+            &ReadOptions::new().limits(limits),
+            &mut Diagnostics::new(),
+        )
+        .unwrap_err();
+        assert!(matches!(error.error, Error::LimitExceeded { .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_limits() -> TResult<()> {
+        fn read(data: &[u8], limits: Limits) -> TResult<Properties<'_>> {
+            Context::run_with_options(
+                &ReadOptions::new().limits(limits),
+                &RefCell::new(Diagnostics::new()),
+                &mut Cursor::new(data),
+                read_properties_until_none,
+            )
+        }
+        fn limit_exceeded(mut error: Error) -> Option<(Limit, u64)> {
+            while let Error::Property { error: inner, .. } = error {
+                error = *inner;
+            }
+            match error {
+                Error::LimitExceeded { limit, value, .. } => Some((limit, value)),
+                _ => None,
+            }
+        }
+
+        // array claiming far more elements than there is data for
+        let mut bogus = vec![];
+        Context::run(&mut bogus, |writer| {
+            write_string(writer, "Values")?;
+            write_string(writer, "ArrayProperty")?;
+            writer.write_u32::<LE>(8)?;
+            writer.write_u32::<LE>(0)?;
+            write_string(writer, "IntProperty")?;
+            writer.write_u8(0)?;
+            writer.write_u32::<LE>(0x1000_0000)?;
+            writer.write_i32::<LE>(1)?;
+            write_string(writer, "None")
+        })?;
+        assert!(matches!(
+            read(&bogus, Limits::new()),
+            Err(Error::Property { .. })
+        ));
+        let limits = Limits {
+            max_elements: 1000,
+            ..Limits::new()
+        };
+        let error = read(&bogus, limits).unwrap_err();
+        assert_eq!(limit_exceeded(error), Some((Limit::Elements, 0x1000_0000)));
+        let limits = Limits {
+            max_allocation: 1 << 20,
+            ..Limits::new()
+        };
+        let error = read(&bogus, limits).unwrap_err();
+        assert_eq!(limit_exceeded(error).map(|e| e.0), Some(Limit::Allocation));
+        let limits = Limits {
+            max_string_length: 4,
+            ..Limits::new()
+        };
+        let error = read(&bogus, limits).unwrap_err();
+        assert_eq!(limit_exceeded(error), Some((Limit::StringLength, 7)));
+        let error = read(&bogus, Limits::untrusted()).unwrap_err();
+        assert_eq!(limit_exceeded(error), Some((Limit::Elements, 0x1000_0000)));
+
+        // raw property claiming a huge value
+        let mut raw = vec![];
+        Context::run(&mut raw, |writer| {
+            write_string(writer, "Maybe")?;
+            write_string(writer, "OptionalProperty")?;
+            writer.write_u32::<LE>(0x7fff_ffff)?;
+            writer.write_u32::<LE>(0)?;
+            write_string(writer, "IntProperty")?;
+            writer.write_u8(0)?;
+            writer.write_i32::<LE>(1)?;
+            Ok::<_, Error>(())
+        })?;
+        let error = Context::run_with_options(
+            &ReadOptions::lenient().limits(Limits::untrusted()),
+            &RefCell::new(Diagnostics::new()),
+            &mut Cursor::new(&raw),
+            read_properties_until_none,
+        )
+        .unwrap_err();
+        assert_eq!(limit_exceeded(error).map(|e| e.0), Some(Limit::Allocation));
+
+        // three levels of nested structs
+        let mut properties = Properties::default();
+        properties.insert(
+            "Count",
+            Property::Int {
+                id: None,
+                value: 1,
+                complete_type: None,
+                trailing: vec![],
+            },
+        );
+        for name in ["Inner", "Outer"] {
+            let mut outer = Properties::default();
+            outer.insert(
+                name,
+                Property::Struct {
+                    struct_type: StructType::Struct(Some(name.into())),
+                    struct_id: uuid::Uuid::nil(),
+                    id: None,
+                    value: StructValue::Struct(properties),
+                    complete_type: None,
+                    trailing: vec![],
+                },
+            );
+            properties = outer;
+        }
+        let mut nested = vec![];
+        Context::run(&mut nested, |writer| {
+            write_properties_none_terminated(writer, &properties)
+        })?;
+        let limits = Limits {
+            max_depth: 3,
+            ..Limits::new()
+        };
+        assert_eq!(read(&nested, limits)?, properties);
+        let limits = Limits {
+            max_depth: 2,
+            ..Limits::new()
+        };
+        let error = read(&nested, limits).unwrap_err();
+        assert_eq!(limit_exceeded(error), Some((Limit::Depth, 3)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_size_mismatch() -> TResult<()> {
+        let location = Property::Struct {
+            struct_type: StructType::Vector,
+            struct_id: uuid::Uuid::nil(),
+            id: None,
+            value: StructValue::Vector(Vector {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            }),
+            complete_type: None,
+            trailing: vec![],
+        };
+        let count = Property::Int {
+            id: None,
+            value: 7,
+            complete_type: None,
+            trailing: vec![],
+        };
+        let mut original = vec![];
+        Context::run(&mut original, |writer| {
+            write_property((&"Location".into(), &location), writer)?;
+            // declare 4 more bytes than the vector takes up and pad accordingly
+            writer.stream.splice(32..36, 16u32.to_le_bytes());
+            writer.write_all(&[0xff; 4])?;
+            write_property((&"Count".into(), &count), writer)?;
+            write_string(writer, "None")
+        })?;
+
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let error = Context::run_with_options(
+            &ReadOptions::new(),
+            &diagnostics,
+            &mut Cursor::new(&original),
+            read_properties_until_none,
+        )
+        .unwrap_err();
+        let error = ParseError::new(error, 0, &original);
+        assert_eq!(error.property.unwrap().path, ".Location");
+        assert!(matches!(
+            error.error,
+            Error::SizeMismatch {
+                expected: 16,
+                actual: 12,
+                struct_type: Some(StructType::Vector),
+            }
+        ));
+
+        // leniently the skipped bytes are kept and written back
+        let properties = Context::run_with_options(
+            &ReadOptions::lenient(),
+            &diagnostics,
+            &mut Cursor::new(&original),
+            read_properties_until_none,
+        )?;
+        let mut padded = location;
+        *padded.trailing_mut().unwrap() = vec![0xff; 4];
+        assert_eq!(properties["Location"], padded);
+        assert_eq!(properties["Count"], count);
+        let diagnostic = diagnostics.borrow().iter().next().unwrap().clone();
+        assert_eq!(diagnostic.kind, DiagnosticKind::SizeMismatch);
+        assert_eq!(diagnostic.path, ".Location");
+        let mut reconstructed = vec![];
+        Context::run(&mut reconstructed, |writer| {
+            write_properties_none_terminated(writer, &properties)
+        })?;
+        assert_eq!(original, reconstructed);
         Ok(())
     }
 
     #[test]
-    fn test_uuid() -> TResult<()> {
-        let id = uuid::uuid!("2eb5fdbd4d1001ac8ff33681daa59333");
-        let mut writer = vec![];
-        Context::run(&mut writer, |writer| id.write(writer))?;
-        let mut reader = Cursor::new(&writer);
-        let rid = Context::run(&mut reader, |reader| uuid::Uuid::read(reader))?;
-        assert_eq!(id, rid);
+    fn test_read_size_overrun() -> TResult<()> {
+        let location = Property::Struct {
+            struct_type: StructType::Vector,
+            struct_id: uuid::Uuid::nil(),
+            id: None,
+            value: StructValue::Vector(Vector {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            }),
+            complete_type: None,
+            trailing: vec![],
+        };
+        let count = Property::Int {
+            id: None,
+            value: 7,
+            complete_type: None,
+            trailing: vec![],
+        };
+        let mut original = vec![];
+        Context::run(&mut original, |writer| {
+            write_property((&"Location".into(), &location), writer)?;
+            // declare 4 fewer bytes than the vector takes up
+            writer.stream.splice(32..36, 8u32.to_le_bytes());
+            write_property((&"Count".into(), &count), writer)?;
+            write_string(writer, "None")
+        })?;
+
+        // the value is kept whole and the next property read after it
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let properties = Context::run_with_options(
+            &ReadOptions::lenient(),
+            &diagnostics,
+            &mut Cursor::new(&original),
+            read_properties_until_none,
+        )?;
+        assert_eq!(properties["Location"], location);
+        assert_eq!(properties["Count"], count);
+        let diagnostics = diagnostics.into_inner();
+        let diagnostic = diagnostics.iter().next().unwrap();
+        assert_eq!(diagnostic.kind, DiagnosticKind::SizeMismatch);
+        assert_eq!(diagnostic.path, ".Location");
+        assert!(diagnostic.message.ends_with("continuing after the value"));
         Ok(())
     }
 
     #[test]
-    fn test_uuid2() -> TResult<()> {
-        let id = uuid::uuid!("85b20ca1-49fb-7138-a154-c89a2c20e2cd");
-        let mut writer = vec![];
-        Context::run(&mut writer, |writer| id.write(writer))?;
+    fn test_rw_save_containers() -> TResult<()> {
+        for container in [
+            Container::Palworld(PalworldContainer { save_type: 0x31 }),
+            Container::Palworld(PalworldContainer { save_type: 0x32 }),
+            Container::Chunked(ChunkedContainer { block_size: 0x1000 }),
+            Container::LengthPrefixed(LengthPrefixedContainer),
+        ] {
+            let packed = container.wrap(SAVE)?;
+            let save = Save::read(&mut Cursor::new(&packed)).unwrap();
+            assert_eq!(save.container.as_ref(), Some(&container));
+
+            let mut written = vec![];
+            save.write(&mut written)?;
+            let (unpacked_container, unpacked) =
+                Container::unwrap_with(&Container::builtin(), &written, &Limits::new())?.unwrap();
+            assert_eq!(unpacked_container, container);
+            assert_eq!(SAVE, unpacked);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_rw_custom_container() -> TResult<()> {
+        /// GVAS data with every byte inverted
+        #[derive(Debug, Clone, PartialEq)]
+        struct Inverted;
+        impl SaveContainer for Inverted {
+            fn detect(&self, data: &[u8]) -> bool {
+                data.starts_with(&b"GVAS".map(|b| !b))
+            }
+            fn unwrap(&self, data: &[u8], _limits: &Limits) -> TResult<(Container, Vec<u8>)> {
+                Ok((
+                    Container::Custom(std::boxed::Box::new(Self)),
+                    data.iter().map(|b| !b).collect(),
+                ))
+            }
+            fn wrap(&self, data: &[u8]) -> TResult<Vec<u8>> {
+                Ok(data.iter().map(|b| !b).collect())
+            }
+        }
+
+        let packed = Inverted.wrap(SAVE)?;
+        assert!(Save::read(&mut Cursor::new(&packed)).is_err());
+        let options = ReadOptions::new().container(Inverted);
+        let save =
+            Save::read_with_options(&mut Cursor::new(&packed), &options, &mut Diagnostics::new())
+                .unwrap();
         assert_eq!(
-            writer,
-            [
-                0xa1, 0x0c, 0xb2, 0x85, 0x38, 0x71, 0xfb, 0x49, 0x9a, 0xc8, 0x54, 0xa1, 0xcd, 0xe2,
-                0x20, 0x2c
-            ]
+            save.container,
+            Some(Container::Custom(std::boxed::Box::new(Inverted)))
         );
+
+        let mut written = vec![];
+        save.write(&mut written)?;
+        assert_eq!(packed, written);
+        assert!(serde_json::to_string(&save).is_err());
         Ok(())
     }
 
     #[test]
-    fn test_rw_save1() -> TResult<()> {
-        let mut reader = Cursor::new(&SAVE);
-        let obj = Save::read(&mut reader).unwrap();
-        let mut reconstructed: Vec<u8> = vec![];
-        obj.write(&mut reconstructed)?;
-        assert_eq!(SAVE, reconstructed);
+    fn test_aes_known_answer() -> TResult<()> {
+        // FIPS-197 appendix C.1
+        let key: AesKey = "000102030405060708090a0b0c0d0e0f".parse()?;
+        let encryption = Encryption {
+            mode: AesMode::Ecb,
+            padding: AesPadding::Zero,
+        };
+        let plain = hex::decode("00112233445566778899aabbccddeeff").unwrap();
+        let encrypted = encryption.encrypt(&key, &plain)?;
+        assert_eq!(hex::encode(&encrypted), "69c4e0d86a7b0430d8cdb78070b4c55a");
+        assert_eq!(encryption.decrypt(&key, &encrypted)?, plain);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rw_save_encrypted() -> TResult<()> {
+        let key: AesKey = "0x4d2fa5e1c07b38d6a9f0e4b21c8573de".parse()?;
+        for encryption in [
+            Encryption {
+                mode: AesMode::Ecb,
+                padding: AesPadding::Pkcs7,
+            },
+            Encryption {
+                mode: AesMode::Cbc { iv: [7; 16] },
+                padding: AesPadding::Zero,
+            },
+        ] {
+            let encrypted = encryption.encrypt(&key, SAVE)?;
+            assert!(Save::read(&mut Cursor::new(&encrypted)).is_err());
+
+            let options = ReadOptions {
+                allow_inferred_types: true,
+                ..ReadOptions::strict()
+            }
+            .aes(key.clone(), encryption.mode, encryption.padding);
+            let save = Save::read_with_options(
+                &mut Cursor::new(&encrypted),
+                &options,
+                &mut Diagnostics::new(),
+            )
+            .unwrap();
+            assert_eq!(save.encryption, Some(encryption));
+            // zero padding does not become trailing data
+            assert_eq!(save.extra, [0; 4]);
+            let padding = match encryption.padding {
+                AesPadding::Zero => AesPadding::Pkcs7,
+                AesPadding::Pkcs7 => AesPadding::Zero,
+            };
+            let wrong_padding = options.clone().aes(key.clone(), encryption.mode, padding);
+            assert!(Save::read_with_options(
+                &mut Cursor::new(&encrypted),
+                &wrong_padding,
+                &mut Diagnostics::new(),
+            )
+            .is_err());
+
+            assert!(save.write(&mut vec![]).is_err());
+            let mut written = vec![];
+            save.write_with_options(&mut written, &WriteOptions::new().aes_key(key.clone()))?;
+            assert_eq!(encrypted, written);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_rw_save_checksums() -> TResult<()> {
+        for algorithm in [
+            ChecksumAlgorithm::Crc32,
+            ChecksumAlgorithm::Md5,
+            ChecksumAlgorithm::Sha1,
+        ] {
+            for position in [ChecksumPosition::Header, ChecksumPosition::Footer] {
+                let checksum = Checksum {
+                    algorithm,
+                    position,
+                };
+                let data = checksum.apply(SAVE);
+                let mut diagnostics = Diagnostics::new();
+                let save = Save::read_with_options(
+                    &mut Cursor::new(&data),
+                    &ReadOptions::new(),
+                    &mut diagnostics,
+                )
+                .unwrap();
+                assert_eq!(save.checksum, Some(checksum));
+                assert_eq!(save.extra, [0; 4]);
+                assert!(diagnostics
+                    .iter()
+                    .all(|d| d.kind != DiagnosticKind::ChecksumMismatch));
+
+                let mut written = vec![];
+                save.write(&mut written)?;
+                assert_eq!(data, written);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_rw_save_checksum_length_trailer() -> TResult<()> {
+        // e.g. a counter which has the length of a CRC-32
+        let mut data = SAVE.to_vec();
+        data.extend(7u32.to_le_bytes());
+        let save = Save::read(&mut Cursor::new(&data)).unwrap();
+        assert_eq!(save.checksum, None);
+        assert_eq!(save.extra, [0, 0, 0, 0, 7, 0, 0, 0]);
+        let mut written = vec![];
+        save.write(&mut written)?;
+        assert_eq!(data, written);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_save_checksum_mismatch() -> TResult<()> {
+        let mut stale = SAVE.to_vec();
+        stale.extend(ChecksumAlgorithm::Md5.compute(b"stale"));
+        let footer = Checksum {
+            algorithm: ChecksumAlgorithm::Md5,
+            position: ChecksumPosition::Footer,
+        };
+        let options = ReadOptions {
+            allow_inferred_types: true,
+            ..ReadOptions::strict()
+        };
+        // a footer which does not match is trailing data like any other
+        let error =
+            Save::read_with_options(&mut Cursor::new(&stale), &options, &mut Diagnostics::new())
+                .unwrap_err();
+        assert_eq!(error.offset, SAVE.len() - 4);
+
+        // unless the save is known to have one
+        let mut diagnostics = Diagnostics::new();
+        let options = options.checksum(footer);
+        let save =
+            Save::read_with_options(&mut Cursor::new(&stale), &options, &mut diagnostics).unwrap();
+        assert_eq!(save.checksum, Some(footer));
+        assert_eq!(save.extra, [0; 4]);
+        let diagnostic = diagnostics
+            .iter()
+            .find(|d| d.kind == DiagnosticKind::ChecksumMismatch)
+            .unwrap();
+        assert_eq!(diagnostic.offset, SAVE.len());
+        let mut written = vec![];
+        save.write(&mut written)?;
+        assert!(save.checksum.unwrap().verify(&written));
+
+        let mut stale = ChecksumAlgorithm::Crc32.compute(b"stale");
+        stale.extend(SAVE);
+        let mut diagnostics = Diagnostics::new();
+        let save = Save::read_with_options(
+            &mut Cursor::new(&stale),
+            &ReadOptions::new(),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.kind == DiagnosticKind::ChecksumMismatch));
+        let mut written = vec![];
+        save.write(&mut written)?;
+        assert!(save.checksum.unwrap().verify(&written));
+        Ok(())
+    }
+
+    #[test]
+    fn test_property_reader() -> Result<(), ParseError> {
+        let save = Save::read(&mut Cursor::new(SAVE))?;
+        fn lookup<'a>(
+            properties: &'a Properties<'a>,
+            keys: &[PropertyKey<'a>],
+        ) -> &'a Property<'a> {
+            let property = &properties.0[&keys[0]];
+            match (property, &keys[1..]) {
+                (_, []) => property,
+                (
+                    Property::Struct {
+                        value: StructValue::Struct(properties),
+                        ..
+                    },
+                    rest,
+                ) => lookup(properties, rest),
+                _ => panic!("{keys:?} is not a property"),
+            }
+        }
+
+        let mut reader = PropertyReader::new(Cursor::new(SAVE))?;
+        assert_eq!(save.root.save_game_type, reader.save_game_type());
+        let mut keys = vec![];
+        let mut values = 0;
+        while let Some(event) = reader.next_event()? {
+            match event {
+                PropertyEvent::Begin { path, index, .. } => {
+                    let name = path.rsplit('.').next().unwrap();
+                    keys.push(PropertyKey(index, name.to_owned().into()));
+                }
+                PropertyEvent::Value(value) => {
+                    assert_eq!(lookup(&save.root.properties, &keys), &*value);
+                    values += 1;
+                }
+                PropertyEvent::End => {
+                    keys.pop();
+                }
+            }
+        }
+        assert!(keys.is_empty());
+        assert!(values > save.root.properties.0.len());
+
+        // skipping every root property yields only their tags
+        let mut reader = PropertyReader::new(Cursor::new(SAVE))?;
+        let mut names = vec![];
+        while let Some(event) = reader.next_event()? {
+            match event {
+                PropertyEvent::Begin { path, .. } => {
+                    names.push(path);
+                    reader.skip_value()?;
+                }
+                PropertyEvent::End => {}
+                PropertyEvent::Value(_) => panic!("skipped property was decoded"),
+            }
+        }
+        let expected: Vec<_> = save
+            .root
+            .properties
+            .0
+            .keys()
+            .map(|key| format!(".{}", key.1))
+            .collect();
+        assert_eq!(expected, names);
+        assert!(reader.skip_value().is_err());
         Ok(())
     }
 
@@ -2858,7 +7363,7 @@ mod tests {
         ];
         let mut reader = Cursor::new(&original);
         let obj = Context::run(&mut reader, |reader| {
-            Property::read(reader, PropertyType::IntProperty, 0)
+            Property::read(reader, PropertyType::IntProperty, 4)
         })?;
         let mut reconstructed: Vec<u8> = vec![];
         Context::run(&mut reconstructed, |writer| obj.write(writer))?;
@@ -2867,7 +7372,9 @@ mod tests {
             obj,
             Property::Int {
                 id: Some(uuid::uuid!("00000000000000000000000000000000")),
-                value: 10
+                value: 10,
+                complete_type: None,
+                trailing: vec![],
             }
         );
         Ok(())
@@ -2883,7 +7390,7 @@ mod tests {
         ];
         let mut reader = Cursor::new(&original);
         let obj = Context::run(&mut reader, |reader| {
-            Property::read(reader, PropertyType::StrProperty, 0)
+            Property::read(reader, PropertyType::StrProperty, 54)
         })?;
         println!("{obj:#?}");
         let mut reconstructed: Vec<u8> = vec![];
@@ -2903,7 +7410,15 @@ mod tests {
         let mut reader = Cursor::new(bytes);
         assert_eq!(
             Context::run(&mut reader, read_property)?,
-            Some(("VersionNumber".into(), Property::Int { id: None, value: 2 }))
+            Some((
+                "VersionNumber".into(),
+                Property::Int {
+                    id: None,
+                    value: 2,
+                    complete_type: None,
+                    trailing: vec![],
+                }
+            ))
         );
         Ok(())
     }
@@ -2942,6 +7457,8 @@ mod tests {
                             Property::Int {
                                 id: None,
                                 value: 140,
+                                complete_type: None,
+                                trailing: vec![],
                             }
                         ),
                         (
@@ -2949,6 +7466,8 @@ mod tests {
                             Property::Int {
                                 id: None,
                                 value: 9018,
+                                complete_type: None,
+                                trailing: vec![],
                             },
                         ),
                         (
@@ -2956,11 +7475,15 @@ mod tests {
                             Property::Bool {
                                 id: None,
                                 value: true,
+                                complete_type: None,
+                                trailing: vec![],
                             },
                         ),
                     ]))),
                     struct_type: StructType::Struct(Some("VanityMasterySave".to_string())),
                     struct_id: uuid::uuid!("00000000000000000000000000000000"),
+                    complete_type: None,
+                    trailing: vec![],
                 }
             ))
         );
@@ -2984,15 +7507,291 @@ mod tests {
                 Property::Array {
                     array_type: PropertyType::IntProperty,
                     id: None,
-                    value: ValueArray::Base(ValueVec::Int(vec![0]))
+                    value: ValueArray::Base(ValueVec::Int(vec![0])),
+                    complete_type: None,
+                    trailing: vec![],
                 }
             ))
         );
         Ok(())
     }
 
-    fn rw_property(original: &[u8]) -> TResult<()> {
+    #[test]
+    fn test_read_raw_property() -> TResult<()> {
+        let original = [
+            0x06, 0x00, 0x00, 0x00, 0x4D, 0x61, 0x79, 0x62, 0x65, 0x00, 0x11, 0x00, 0x00, 0x00,
+            0x4F, 0x70, 0x74, 0x69, 0x6F, 0x6E, 0x61, 0x6C, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72,
+            0x74, 0x79, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x00,
+            0x00, 0x49, 0x6E, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x00,
+            0x01, 0x07, 0x00, 0x00, 0x00,
+        ];
+        let error = Context::run(&mut Cursor::new(original), read_property).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Property { error, .. } if matches!(*error, Error::UnknownPropertyType(_))
+        ));
+
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let property = Context::run_with_options(
+            &ReadOptions::lenient(),
+            &diagnostics,
+            &mut Cursor::new(original),
+            read_property,
+        )?;
+        assert_eq!(
+            property,
+            Some((
+                "Maybe".into(),
+                Property::Raw {
+                    type_name: "OptionalProperty".into(),
+                    tag_bytes: original[39..56].into(),
+                    value_bytes: vec![0x01, 0x07, 0x00, 0x00, 0x00].into(),
+                }
+            ))
+        );
+        assert_eq!(
+            diagnostics.borrow().iter().next().unwrap().kind,
+            DiagnosticKind::RawFallback
+        );
+        let (key, property) = property.unwrap();
+        let mut reconstructed = vec![];
+        Context::run(&mut reconstructed, |writer| {
+            write_property((&key, &property), writer)
+        })?;
+        assert_eq!(&original[..], &reconstructed[..]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_raw_tag_data() -> TResult<()> {
+        let read = |data: &[u8]| {
+            Context::run(&mut Cursor::new(data), |reader| {
+                Property::read_raw_tag_data(reader, 4)
+            })
+        };
+        // an empty FString has a length of 1 and starts like a GUID flag
+        let data = [0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2A, 0x00, 0x00, 0x00];
+        assert_eq!(read(&data)?, &data[..6]);
+        // a GUID flag whose GUID starts with bytes which are not followed by a terminator
+        let mut data = vec![0x01, 0x02, 0x00, 0x00, 0x00];
+        data.extend([0x03; 12]);
+        assert_eq!(read(&data)?, data);
+        let data = [
+            0x03, 0x00, 0x00, 0x00, 0x41, 0x42, 0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0x43, 0x00, 0x00,
+            0x00, 0x00, 0x2A, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(read(&data)?, &data[..16]);
+        // a GUID whose first bytes make up the length of a string spanning the rest of the save
+        let mut data = vec![0x01, 0x00, 0x10, 0x00];
+        data.resize(0x100004, 0x41);
+        data.push(0x00);
+        assert_eq!(read(&data)?, &data[..17]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_map_struct_types() -> TResult<()> {
+        let original = [
+            0x07, 0x00, 0x00, 0x00, 0x53, 0x70, 0x61, 0x77, 0x6E, 0x73, 0x00, 0x0C, 0x00, 0x00,
+            0x00, 0x4D, 0x61, 0x70, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x24,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x53, 0x74, 0x72,
+            0x75, 0x63, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x0F, 0x00,
+            0x00, 0x00, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72,
+            0x74, 0x79, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x02,
+            0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10,
+            0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x40, 0x40,
+        ];
+        let mut reader = Cursor::new(original);
+        let (_, property) = Context::run(&mut reader, read_property)?.unwrap();
+        match property {
+            Property::Map { value, .. } => {
+                assert!(matches!(
+                    value[0].key,
+                    PropertyValue::Struct(StructValue::Guid(_))
+                ));
+                assert_eq!(
+                    value[0].value,
+                    PropertyValue::Struct(StructValue::Vector(Vector {
+                        x: 1.0,
+                        y: 2.0,
+                        z: 3.0
+                    }))
+                );
+            }
+            _ => panic!("expected map property"),
+        }
+
+        // any struct of the same size could have been written instead
+        let diagnostics = RefCell::new(Diagnostics::new());
+        Context::run_with_options(
+            &ReadOptions::new(),
+            &diagnostics,
+            &mut Cursor::new(original),
+            read_property,
+        )?;
+        let messages: Vec<_> = diagnostics
+            .into_inner()
+            .iter()
+            .map(|d| (d.kind, d.message.clone()))
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                (
+                    DiagnosticKind::InferredStructType,
+                    "StructType for \".Spawns.Key\" unspecified, inferred Guid which is \
+                     ambiguous as Quat, LinearColor, Vector4, Plane fit as well"
+                        .to_string()
+                ),
+                (
+                    DiagnosticKind::InferredStructType,
+                    "StructType for \".Spawns.Value\" unspecified, inferred Vector which is \
+                     ambiguous as IntVector, Rotator, Vector3f fit as well"
+                        .to_string()
+                ),
+            ]
+        );
+        rw_property(&original)
+    }
+
+    #[test]
+    fn test_rw_property_map_byte_keys() -> TResult<()> {
+        let original = [
+            0x07, 0x00, 0x00, 0x00, 0x43, 0x6F, 0x75, 0x6E, 0x74, 0x73, 0x00, 0x0C, 0x00, 0x00,
+            0x00, 0x4D, 0x61, 0x70, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x1A,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0D, 0x00, 0x00, 0x00, 0x42, 0x79, 0x74,
+            0x65, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x0F, 0x00, 0x00, 0x00,
+            0x55, 0x49, 0x6E, 0x74, 0x36, 0x34, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x05, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        ];
+        let mut reader = Cursor::new(original);
+        let (_, property) = Context::run(&mut reader, read_property)?.unwrap();
+        match property {
+            Property::Map { value, .. } => {
+                assert_eq!(value[1].key, PropertyValue::Byte(Byte::Byte(2)));
+                assert_eq!(value[1].value, PropertyValue::UInt64(1 << 40));
+            }
+            _ => panic!("expected map property"),
+        }
+        rw_property(&original)
+    }
+
+    #[test]
+    fn test_rw_property_array_uint64() -> TResult<()> {
+        let original = [
+            0x07, 0x00, 0x00, 0x00, 0x54, 0x6F, 0x74, 0x61, 0x6C, 0x73, 0x00, 0x0E, 0x00, 0x00,
+            0x00, 0x41, 0x72, 0x72, 0x61, 0x79, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79,
+            0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x55,
+            0x49, 0x6E, 0x74, 0x36, 0x34, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00,
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        ];
+        rw_property(&original)
+    }
+
+    #[test]
+    fn test_rw_property_array_enum_bytes() -> TResult<()> {
+        let original = [
+            0x06, 0x00, 0x00, 0x00, 0x4D, 0x6F, 0x64, 0x65, 0x73, 0x00, 0x0E, 0x00, 0x00, 0x00,
+            0x41, 0x72, 0x72, 0x61, 0x79, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00,
+            0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0D, 0x00, 0x00, 0x00, 0x45, 0x6E,
+            0x75, 0x6D, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x00, 0x03, 0x00,
+            0x00, 0x00, 0x00, 0x02, 0x01,
+        ];
+        let mut reader = Cursor::new(original);
+        let (_, property) = Context::run(&mut reader, read_property)?.unwrap();
+        assert!(matches!(
+            property,
+            Property::Array {
+                value: ValueArray::Base(ValueVec::EnumByte(_)),
+                ..
+            }
+        ));
+        rw_property(&original)
+    }
+
+    #[test]
+    fn test_rw_property_text_histories() -> TResult<()> {
+        // OrderedFormat (AsPercent argument), NamedFormat (AsCurrency argument), AsTime,
+        // AsDateTime (custom pattern), TextGenerator, Transform
+        let original = [
+            0x07, 0x00, 0x00, 0x00, 0x4C, 0x61, 0x62, 0x65, 0x6C, 0x73, 0x00, 0x0E, 0x00, 0x00,
+            0x00, 0x41, 0x72, 0x72, 0x61, 0x79, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79,
+            0x00, 0x1D, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0D, 0x00, 0x00, 0x00, 0x54,
+            0x65, 0x78, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x00, 0x06,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x01,
+            0x00, 0x00, 0x00, 0x0B, 0x00, 0x00, 0x00, 0x7B, 0x30, 0x7D, 0x20, 0x6F, 0x66, 0x20,
+            0x7B, 0x31, 0x7D, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x05, 0x03, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xE0, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00,
+            0x00, 0x00, 0x7B, 0x50, 0x72, 0x69, 0x63, 0x65, 0x7D, 0x00, 0x01, 0x00, 0x00, 0x00,
+            0x06, 0x00, 0x00, 0x00, 0x50, 0x72, 0x69, 0x63, 0x65, 0x00, 0x04, 0x00, 0x00, 0x00,
+            0x00, 0x06, 0x04, 0x00, 0x00, 0x00, 0x45, 0x55, 0x52, 0x00, 0x00, 0xCF, 0x07, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x2C, 0x01, 0x00, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x64, 0x65, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x08, 0x00, 0x80, 0xEC, 0x01, 0x20, 0x14, 0xD7, 0x08, 0x02, 0x04,
+            0x00, 0x00, 0x00, 0x55, 0x54, 0x43, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x09, 0x00, 0x80, 0xEC, 0x01, 0x20, 0x14, 0xD7, 0x08, 0x05, 0x01, 0x09, 0x00,
+            0x00, 0x00, 0x25, 0x59, 0x2D, 0x25, 0x6D, 0x2D, 0x25, 0x64, 0x00, 0x04, 0x00, 0x00,
+            0x00, 0x55, 0x54, 0x43, 0x00, 0x03, 0x00, 0x00, 0x00, 0x65, 0x6E, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x0C, 0x0C, 0x00, 0x00, 0x00, 0x4D, 0x79, 0x47, 0x65, 0x6E, 0x65, 0x72,
+            0x61, 0x74, 0x6F, 0x72, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x00, 0x00,
+            0x00, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x05, 0x00, 0x00, 0x00, 0x4E, 0x6F,
+            0x6E, 0x65, 0x00, 0x01,
+        ];
+        rw_property(&original)
+    }
+
+    #[test]
+    fn test_rw_property_box2d() -> TResult<()> {
+        let original = [
+            0x07, 0x00, 0x00, 0x00, 0x42, 0x6F, 0x75, 0x6E, 0x64, 0x73, 0x00, 0x0F, 0x00, 0x00,
+            0x00, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74,
+            0x79, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+            0x42, 0x6F, 0x78, 0x32, 0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xBF, 0x00,
+            0x00, 0x00, 0xC0, 0x00, 0x00, 0x40, 0x40, 0x00, 0x00, 0x80, 0x40, 0x01, 0x00, 0x00,
+            0x00,
+        ];
+        rw_property(&original)
+    }
+
+    #[test]
+    fn test_read_error_property_context() {
+        let original = [
+            0x05, 0x00, 0x00, 0x00, 0x4C, 0x69, 0x73, 0x74, 0x00, 0x0E, 0x00, 0x00, 0x00, 0x41,
+            0x72, 0x72, 0x61, 0x79, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x98,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x53, 0x74, 0x72,
+            0x75, 0x63, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x00, 0x02,
+            0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x4C, 0x69, 0x73, 0x74, 0x00, 0x0F, 0x00,
+            0x00, 0x00, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72,
+            0x74, 0x79, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+            0x00, 0x46, 0x6F, 0x6F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x56, 0x61,
+            0x6C, 0x75, 0x65, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x49, 0x6E, 0x74, 0x50, 0x72, 0x6F,
+            0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x07, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x4E, 0x6F, 0x6E, 0x65, 0x00,
+            0x06, 0x00, 0x00, 0x00, 0x56, 0x61, 0x6C, 0x75, 0x65, 0x00, 0x0C, 0x00, 0x00, 0x00,
+            0x49, 0x6E, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x04, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00,
+        ];
         let mut reader = Cursor::new(&original);
+        let error = Context::run(&mut reader, read_property).unwrap_err();
+        let error = ParseError::new(error, original.len(), &original);
+        let property = error.property.as_ref().unwrap();
+        assert_eq!(property.path, ".List[1].Value");
+        assert_eq!(property.property_type, "IntProperty");
+        assert_eq!(property.size, 4);
+        assert_eq!(property.offset, 168);
+        assert!(matches!(error.error, Error::Io(_)));
+        assert_eq!(error.window, &original[original.len() - 16..]);
+    }
+
+    fn rw_property(original: &[u8]) -> TResult<()> {
+        let mut reader = Cursor::new(original);
         Context::run(&mut reader, |reader| {
             let property = read_property(reader)?.unwrap();
             println!("{property:#?}");
@@ -3005,6 +7804,136 @@ mod tests {
         })
     }
 
+    fn ue54_header() -> Header {
+        Header {
+            magic: 0x53415647,
+            save_game_version: 3,
+            package_version: PackageVersion::New(522, 1012),
+            engine_version_major: 5,
+            engine_version_minor: 4,
+            engine_version_patch: 0,
+            engine_version_build: 0,
+            engine_version: "++UE5+Release-5.4".into(),
+            custom_format_version: 3,
+            custom_format: vec![],
+        }
+    }
+
+    fn rw_property_ue54(original: &[u8]) -> TResult<()> {
+        let header = ue54_header();
+        let mut reader = Cursor::new(original);
+        Context::run(&mut reader, |reader| {
+            reader.header(&header, |reader| {
+                let property = read_property(reader)?.unwrap();
+                println!("{property:#?}");
+                let mut reconstructed: Vec<u8> = vec![];
+                Context::run(&mut reconstructed, |writer| {
+                    writer.header(&header, |writer| {
+                        write_property((&property.0, &property.1), writer)
+                    })
+                })?;
+                assert_eq!(original, &reconstructed[..]);
+                Ok(())
+            })
+        })
+    }
+
+    #[test]
+    fn test_rw_property_ue54_struct() -> TResult<()> {
+        let original = [
+            0x04, 0x00, 0x00, 0x00, 0x50, 0x6F, 0x73, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x53, 0x74,
+            0x72, 0x75, 0x63, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x56, 0x65, 0x63, 0x74, 0x6F, 0x72, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x2F, 0x53, 0x63, 0x72, 0x69, 0x70,
+            0x74, 0x2F, 0x43, 0x6F, 0x72, 0x65, 0x55, 0x4F, 0x62, 0x6A, 0x65, 0x63, 0x74, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0xF0, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x08, 0x40,
+        ];
+        let header = ue54_header();
+        let mut reader = Cursor::new(original);
+        assert_eq!(
+            Context::run(&mut reader, |reader| reader.header(&header, read_property))?,
+            Some((
+                "Pos".into(),
+                Property::Struct {
+                    id: None,
+                    value: StructValue::Vector(Vector {
+                        x: 1.0,
+                        y: 2.0,
+                        z: 3.0
+                    }),
+                    struct_type: StructType::Vector,
+                    struct_id: uuid::Uuid::nil(),
+                    complete_type: None,
+                    trailing: vec![],
+                }
+            ))
+        );
+        rw_property_ue54(&original)
+    }
+
+    #[test]
+    fn test_rw_property_ue54_matrix() -> TResult<()> {
+        let original = [
+            0x0A, 0x00, 0x00, 0x00, 0x54, 0x72, 0x61, 0x6E, 0x73, 0x66, 0x6F, 0x72, 0x6D, 0x00,
+            0x0F, 0x00, 0x00, 0x00, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x50, 0x72, 0x6F, 0x70,
+            0x65, 0x72, 0x74, 0x79, 0x00, 0x01, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x4D,
+            0x61, 0x74, 0x72, 0x69, 0x78, 0x00, 0x01, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00,
+            0x2F, 0x53, 0x63, 0x72, 0x69, 0x70, 0x74, 0x2F, 0x43, 0x6F, 0x72, 0x65, 0x55, 0x4F,
+            0x62, 0x6A, 0x65, 0x63, 0x74, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+            0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0xF0, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x08, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x40, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18,
+            0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1C, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x20, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x40, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x24, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x26, 0x40, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2A,
+            0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2C, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x2E, 0x40,
+        ];
+        rw_property_ue54(&original)
+    }
+
+    #[test]
+    fn test_rw_property_ue54_bool() -> TResult<()> {
+        let original = [
+            0x05, 0x00, 0x00, 0x00, 0x46, 0x6C, 0x61, 0x67, 0x00, 0x0D, 0x00, 0x00, 0x00, 0x42,
+            0x6F, 0x6F, 0x6C, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+        ];
+        rw_property_ue54(&original)
+    }
+
+    #[test]
+    fn test_rw_property_ue54_array() -> TResult<()> {
+        let original = [
+            0x04, 0x00, 0x00, 0x00, 0x49, 0x64, 0x73, 0x00, 0x0E, 0x00, 0x00, 0x00, 0x41, 0x72,
+            0x72, 0x61, 0x79, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x49, 0x6E, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65,
+            0x72, 0x74, 0x79, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x01,
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+            0x00,
+        ];
+        rw_property_ue54(&original)
+    }
+
+    #[test]
+    fn test_rw_property_ue54_enum() -> TResult<()> {
+        let original = [
+            0x05, 0x00, 0x00, 0x00, 0x4D, 0x6F, 0x64, 0x65, 0x00, 0x0D, 0x00, 0x00, 0x00, 0x45,
+            0x6E, 0x75, 0x6D, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x45, 0x4D, 0x6F, 0x64, 0x65, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x0D, 0x00, 0x00, 0x00, 0x2F, 0x53, 0x63, 0x72, 0x69, 0x70, 0x74, 0x2F,
+            0x47, 0x61, 0x6D, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0D, 0x00, 0x00, 0x00, 0x42,
+            0x79, 0x74, 0x65, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x0D, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x45, 0x4D, 0x6F,
+            0x64, 0x65, 0x3A, 0x3A, 0x42, 0x00,
+        ];
+        rw_property_ue54(&original)
+    }
+
     #[test]
     fn test_rw_property_int() -> TResult<()> {
         let original = [
@@ -3043,6 +7972,68 @@ mod tests {
         rw_property(&original)
     }
 
+    #[test]
+    fn test_read_gameplay_tag() -> TResult<()> {
+        // UE writes the TagName as a property list
+        let tagged = [
+            0x04, 0x00, 0x00, 0x00, 0x54, 0x61, 0x67, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x53, 0x74,
+            0x72, 0x75, 0x63, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x37,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x47, 0x61, 0x6D,
+            0x65, 0x70, 0x6C, 0x61, 0x79, 0x54, 0x61, 0x67, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00,
+            0x00, 0x00, 0x54, 0x61, 0x67, 0x4E, 0x61, 0x6D, 0x65, 0x00, 0x0D, 0x00, 0x00, 0x00,
+            0x4E, 0x61, 0x6D, 0x65, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x08,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x41, 0x2E,
+            0x42, 0x00, 0x05, 0x00, 0x00, 0x00, 0x4E, 0x6F, 0x6E, 0x65, 0x00,
+        ];
+        // some games write just the name
+        let native = [
+            0x04, 0x00, 0x00, 0x00, 0x54, 0x61, 0x67, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x53, 0x74,
+            0x72, 0x75, 0x63, 0x74, 0x50, 0x72, 0x6F, 0x70, 0x65, 0x72, 0x74, 0x79, 0x00, 0x08,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x47, 0x61, 0x6D,
+            0x65, 0x70, 0x6C, 0x61, 0x79, 0x54, 0x61, 0x67, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00,
+            0x00, 0x00, 0x41, 0x2E, 0x42, 0x00,
+        ];
+
+        let (_, property) = Context::run(&mut Cursor::new(tagged), read_property)?.unwrap();
+        let Property::Struct {
+            struct_type: StructType::Struct(Some(name)),
+            value: StructValue::Struct(properties),
+            ..
+        } = property
+        else {
+            panic!("expected a property list, got {property:?}");
+        };
+        assert_eq!(name, "GameplayTag");
+        assert!(matches!(
+            &properties.0[&PropertyKey::from("TagName")],
+            Property::Name { value, .. } if value == "A.B"
+        ));
+        rw_property(&tagged)?;
+        assert!(Context::run(&mut Cursor::new(native), read_property).is_err());
+
+        let mut types = Types::new();
+        types.add(".Tag".into(), StructType::GameplayTag);
+        let options = ReadOptions::new().types(types);
+        let (_, property) = Context::run_with_options(
+            &options,
+            &RefCell::new(Diagnostics::new()),
+            &mut Cursor::new(native),
+            read_property,
+        )?
+        .unwrap();
+        assert!(matches!(
+            property,
+            Property::Struct {
+                struct_type: StructType::GameplayTag,
+                value: StructValue::GameplayTag(GameplayTag { name }),
+                ..
+            } if name == "A.B"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_rw_property_struct2() -> TResult<()> {
         let original = [
@@ -0,0 +1,121 @@
+use std::cell::Cell;
+
+use crate::Error;
+
+/// Caps on the resources used to read a save to guard against corrupted or malicious data.
+/// Exceeding any of them fails with [`Error::LimitExceeded`].
+///
+/// By default only the nesting depth is limited so deeply nested data cannot exhaust the stack.
+/// Sizes and counts stored in a save are trusted, so a few corrupted bytes can request huge
+/// allocations. Services reading saves they did not write themselves (e.g. uploads) must use
+/// [`Limits::untrusted`] or limits of their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum number of elements of a single array, set or map
+    pub max_elements: u32,
+    /// Maximum nesting depth of properties and texts
+    pub max_depth: u32,
+    /// Maximum length of a single string in bytes as stored
+    pub max_string_length: u32,
+    /// Maximum number of bytes allocated for the values read in total. Collections are accounted
+    /// for by their element count as soon as it is read, so a bogus count fails before any
+    /// elements are.
+    pub max_allocation: u64,
+}
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_elements: u32::MAX,
+            max_depth: 128,
+            max_string_length: u32::MAX,
+            max_allocation: u64::MAX,
+        }
+    }
+}
+impl Limits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Limits generous enough for real saves which bound the memory used to read malicious ones
+    pub fn untrusted() -> Self {
+        Self {
+            max_elements: 1 << 20,
+            max_depth: 64,
+            max_string_length: 1 << 20,
+            max_allocation: 256 << 20,
+        }
+    }
+    /// Checks a single buffer of `bytes` outside of the values read against `max_allocation`,
+    /// e.g. the data decompressed by a [`crate::SaveContainer`]
+    pub fn check_allocation(&self, bytes: u64) -> Result<(), Error> {
+        check(Limit::Allocation, bytes, self.max_allocation)
+    }
+}
+
+/// Resource whose [`Limits`] were exceeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    Elements,
+    Depth,
+    StringLength,
+    Allocation,
+}
+impl std::fmt::Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Limit::Elements => "element count",
+            Limit::Depth => "nesting depth",
+            Limit::StringLength => "string length",
+            Limit::Allocation => "allocated bytes",
+        })
+    }
+}
+
+/// Resources used so far while reading a save
+#[derive(Debug, Default)]
+pub(crate) struct Usage {
+    depth: Cell<u32>,
+    allocated: Cell<u64>,
+}
+impl Usage {
+    pub(crate) fn allocate(&self, limits: &Limits, bytes: u64) -> Result<(), Error> {
+        let allocated = self.allocated.get().saturating_add(bytes);
+        check(Limit::Allocation, allocated, limits.max_allocation)?;
+        self.allocated.set(allocated);
+        Ok(())
+    }
+    /// Accounts for a collection of `count` elements of `size` bytes each
+    pub(crate) fn elements(&self, limits: &Limits, count: u32, size: usize) -> Result<(), Error> {
+        check(Limit::Elements, count as u64, limits.max_elements as u64)?;
+        self.allocate(limits, count as u64 * size as u64)
+    }
+    /// Accounts for a string of `len` bytes
+    pub(crate) fn string(&self, limits: &Limits, len: u64) -> Result<(), Error> {
+        check(Limit::StringLength, len, limits.max_string_length as u64)?;
+        self.allocate(limits, len)
+    }
+    /// Bytes allocated so far to [`Usage::restore`] once everything allocated since is dropped
+    pub(crate) fn allocated(&self) -> u64 {
+        self.allocated.get()
+    }
+    pub(crate) fn restore(&self, allocated: u64) {
+        self.allocated.set(allocated);
+    }
+    pub(crate) fn enter(&self, limits: &Limits) -> Result<(), Error> {
+        let depth = self.depth.get() + 1;
+        check(Limit::Depth, depth as u64, limits.max_depth as u64)?;
+        self.depth.set(depth);
+        Ok(())
+    }
+    pub(crate) fn exit(&self) {
+        self.depth.set(self.depth.get() - 1);
+    }
+}
+
+fn check(limit: Limit, value: u64, max: u64) -> Result<(), Error> {
+    if value > max {
+        Err(Error::LimitExceeded { limit, value, max })
+    } else {
+        Ok(())
+    }
+}
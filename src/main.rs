@@ -1,10 +1,13 @@
 use std::fs::{self, File, OpenOptions};
-use std::io::{stdin, stdout, BufRead, BufReader, BufWriter, Cursor, Write};
+use std::io::{stdin, stdout, BufRead, BufReader, BufWriter, Cursor, Read, Write};
 
 use anyhow::{anyhow, Result};
 use clap::{Parser, Subcommand};
 
-use uesave::{Save, StructType, Types};
+use uesave::{
+    AesKey, AesMode, AesPadding, Container, Diagnostics, ReadOptions, Save, StringEncoding,
+    StructType, Types, WriteOptions,
+};
 
 #[derive(Parser, Debug)]
 struct ActionToJson {
@@ -24,6 +27,27 @@ struct ActionToJson {
     ///   -t .EnemiesKilled.Value=Struct
     #[arg(short, long, value_parser = parse_type)]
     r#type: Vec<(String, StructType)>,
+
+    /// Fail on anything which may prevent the save from being written back correctly e.g.
+    /// inferred types, invalid strings or trailing data
+    #[arg(long, conflicts_with = "lenient")]
+    strict: bool,
+
+    /// Keep properties which cannot be decoded as raw bytes, replace invalid characters in strings
+    /// and accept properties whose size does not match their tag instead of failing
+    #[arg(long)]
+    lenient: bool,
+    /// AES key the save is encrypted with, hex encoded or a path to a file containing it
+    #[arg(long, value_parser = parse_aes_key)]
+    aes_key: Option<AesKey>,
+
+    /// Hex encoded IV if the save is AES encrypted in CBC mode, otherwise ECB is assumed
+    #[arg(long, value_parser = parse_aes_iv, requires = "aes_key")]
+    aes_iv: Option<[u8; 16]>,
+
+    /// Padding of the AES encrypted save, either "zero" or "pkcs7"
+    #[arg(long, value_parser = parse_aes_padding, default_value = "zero", requires = "aes_key")]
+    aes_padding: AesPadding,
 }
 
 #[derive(Parser, Debug)]
@@ -33,6 +57,15 @@ struct ActionFromJson {
 
     #[arg(short, long, default_value = "-")]
     output: String,
+
+    /// Write all strings as UTF-16 instead of only those which are not ASCII
+    #[arg(long)]
+    utf16: bool,
+
+    /// AES key to encrypt the save with if it was read from an encrypted save, hex encoded or a
+    /// path to a file containing it
+    #[arg(long, value_parser = parse_aes_key)]
+    aes_key: Option<AesKey>,
 }
 
 #[derive(Parser, Debug)]
@@ -50,6 +83,27 @@ struct ActionEdit {
     ///   -t .EnemiesKilled.Value=Struct
     #[arg(short, long, value_parser = parse_type)]
     r#type: Vec<(String, StructType)>,
+
+    /// Fail on anything which may prevent the save from being written back correctly e.g.
+    /// inferred types, invalid strings or trailing data
+    #[arg(long, conflicts_with = "lenient")]
+    strict: bool,
+
+    /// Keep properties which cannot be decoded as raw bytes, replace invalid characters in strings
+    /// and accept properties whose size does not match their tag instead of failing
+    #[arg(long)]
+    lenient: bool,
+    /// AES key the save is encrypted with, hex encoded or a path to a file containing it
+    #[arg(long, value_parser = parse_aes_key)]
+    aes_key: Option<AesKey>,
+
+    /// Hex encoded IV if the save is AES encrypted in CBC mode, otherwise ECB is assumed
+    #[arg(long, value_parser = parse_aes_iv, requires = "aes_key")]
+    aes_iv: Option<[u8; 16]>,
+
+    /// Padding of the AES encrypted save, either "zero" or "pkcs7"
+    #[arg(long, value_parser = parse_aes_padding, default_value = "zero", requires = "aes_key")]
+    aes_padding: AesPadding,
 }
 
 #[derive(Parser, Debug)]
@@ -71,6 +125,16 @@ struct ActionTestResave {
     ///   -t .EnemiesKilled.Value=Struct
     #[arg(short, long, value_parser = parse_type)]
     r#type: Vec<(String, StructType)>,
+
+    /// Fail on anything which may prevent the save from being written back correctly e.g.
+    /// inferred types, invalid strings or trailing data
+    #[arg(long, conflicts_with = "lenient")]
+    strict: bool,
+
+    /// Keep properties which cannot be decoded as raw bytes, replace invalid characters in strings
+    /// and accept properties whose size does not match their tag instead of failing
+    #[arg(long)]
+    lenient: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -110,12 +174,22 @@ pub fn main() -> Result<()> {
                 types.add(path, t);
             }
 
-            let save = Save::read_with_types(&mut input(&action.input)?, &types)?;
+            let mut options = read_options(types, action.strict, action.lenient);
+            options.aes_key = action.aes_key;
+            options.aes_mode = aes_mode(action.aes_iv);
+            options.aes_padding = action.aes_padding;
+            let save = read_save(&mut input(&action.input)?, &options)?;
             serde_json::to_writer_pretty(output(&action.output)?, &save)?;
         }
         Action::FromJson(io) => {
             let save: Save = serde_json::from_reader(&mut input(&io.input)?)?;
-            save.write(&mut output(&io.output)?)?;
+            let mut options = WriteOptions::new().string_encoding(if io.utf16 {
+                StringEncoding::Utf16
+            } else {
+                StringEncoding::Auto
+            });
+            options.aes_key = io.aes_key;
+            save.write_with_options(&mut output(&io.output)?, &options)?;
         }
         Action::TestResave(action) => {
             let mut types = Types::new();
@@ -135,8 +209,21 @@ pub fn main() -> Result<()> {
 
             let mut input = std::io::Cursor::new(fs::read(path)?);
             let mut output = std::io::Cursor::new(vec![]);
-            Save::read_with_types(&mut input, &types)?.write(&mut output)?;
-            let (input, output) = (input.into_inner(), output.into_inner());
+            let options = read_options(types, action.strict, action.lenient);
+            read_save(&mut input, &options)?.write(&mut output)?;
+            let (mut input, mut output) = (input.into_inner(), output.into_inner());
+            if let Some((_, unwrapped)) =
+                Container::unwrap_with(&options.containers, &input, &options.limits)?
+            {
+                // compressors do not necessarily produce identical output so only the wrapped
+                // saves are compared
+                input = unwrapped;
+                if let Some((_, unwrapped)) =
+                    Container::unwrap_with(&options.containers, &output, &options.limits)?
+                {
+                    output = unwrapped;
+                }
+            }
             if input != output {
                 if action.debug {
                     fs::write("input.sav", input)?;
@@ -152,7 +239,11 @@ pub fn main() -> Result<()> {
                 types.add(path, t);
             }
 
-            let save = Save::read_with_types(&mut Cursor::new(fs::read(&action.path)?), &types)?;
+            let mut options = read_options(types, action.strict, action.lenient);
+            options.aes_key = action.aes_key.clone();
+            options.aes_mode = aes_mode(action.aes_iv);
+            options.aes_padding = action.aes_padding;
+            let save = read_save(&mut Cursor::new(fs::read(&action.path)?), &options)?;
             let modified_save: Save = serde_json::from_slice(&edit::edit_bytes_with_builder(
                 serde_json::to_vec_pretty(&save)?,
                 tempfile::Builder::new().suffix(".json"),
@@ -162,19 +253,76 @@ pub fn main() -> Result<()> {
                 println!("File unchanged, doing nothing.");
             } else {
                 println!("File modified, writing new save.");
-                modified_save.write(&mut BufWriter::new(
-                    OpenOptions::new()
-                        .create(true)
-                        .truncate(true)
-                        .write(true)
-                        .open(action.path)?,
-                ))?;
+                let mut options = WriteOptions::new();
+                options.aes_key = action.aes_key;
+                modified_save.write_with_options(
+                    &mut BufWriter::new(
+                        OpenOptions::new()
+                            .create(true)
+                            .truncate(true)
+                            .write(true)
+                            .open(action.path)?,
+                    ),
+                    &options,
+                )?;
             }
         }
     }
     Ok(())
 }
 
+/// Parses a hex encoded AES key or reads it from a file containing either the raw or hex
+/// encoded key
+fn parse_aes_key(key: &str) -> Result<AesKey> {
+    match fs::read(key) {
+        Ok(bytes) => match std::str::from_utf8(&bytes).map(str::parse) {
+            Ok(Ok(key)) => Ok(key),
+            _ => Ok(AesKey::new(&bytes)?),
+        },
+        Err(_) => Ok(key.parse()?),
+    }
+}
+
+fn parse_aes_iv(iv: &str) -> Result<[u8; 16]> {
+    let mut buf = [0; 16];
+    hex::decode_to_slice(iv.strip_prefix("0x").unwrap_or(iv), &mut buf)?;
+    Ok(buf)
+}
+
+fn parse_aes_padding(padding: &str) -> Result<AesPadding> {
+    match padding.to_ascii_lowercase().as_str() {
+        "zero" => Ok(AesPadding::Zero),
+        "pkcs7" => Ok(AesPadding::Pkcs7),
+        _ => Err(anyhow!(
+            "unknown AES padding {padding:?}, expected zero or pkcs7"
+        )),
+    }
+}
+
+fn aes_mode(iv: Option<[u8; 16]>) -> AesMode {
+    match iv {
+        Some(iv) => AesMode::Cbc { iv },
+        None => AesMode::Ecb,
+    }
+}
+
+fn read_options(types: Types, strict: bool, lenient: bool) -> ReadOptions {
+    let options = if strict {
+        ReadOptions::strict()
+    } else if lenient {
+        ReadOptions::lenient()
+    } else {
+        ReadOptions::new()
+    };
+    options.types(types)
+}
+
+/// Reads a save printing any diagnostics to stderr as they are encountered
+fn read_save<R: Read>(reader: &mut R, options: &ReadOptions) -> Result<Save<'static>> {
+    let mut diagnostics = Diagnostics::with_callback(|d| eprintln!("warning: {d}"));
+    Ok(Save::read_with_options(reader, options, &mut diagnostics)?)
+}
+
 fn input<'a>(path: &str) -> Result<Box<dyn BufRead + 'a>> {
     Ok(match path {
         "-" => Box::new(BufReader::new(stdin().lock())),
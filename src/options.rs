@@ -0,0 +1,143 @@
+use crate::{AesKey, AesMode, AesPadding, Checksum, Container, Limits, SaveContainer, Types};
+
+/// Options controlling how a save is read.
+///
+/// The default tolerates recoverable issues and reports them as [`crate::Diagnostic`]s.
+/// [`ReadOptions::strict`] rejects them instead while [`ReadOptions::lenient`] additionally
+/// accepts properties which do not match the size declared by their tag and falls back to lossy
+/// or raw representations where data cannot be decoded.
+#[derive(Debug, Clone)]
+pub struct ReadOptions {
+    /// Struct types of containers which cannot be determined from the save itself
+    pub types: Types,
+    /// Accept a header magic other than "GVAS"
+    pub allow_bad_magic: bool,
+    /// Accept data following the root properties and keep it in [`crate::Save::extra`]
+    pub allow_trailing_data: bool,
+    /// Accept properties whose value does not take up exactly the size declared by its tag
+    pub allow_size_mismatch: bool,
+    /// Continue reading at the end of such properties as declared by the tag instead of where
+    /// the value ended. Bytes the value did not take up are kept in the property and written
+    /// back. A value which took up more than declared is kept whole and reading continues after
+    /// it as the declared size cannot be trusted either.
+    pub resync_size_mismatch: bool,
+    /// Infer struct types of containers not specified in `types` from the property size
+    pub allow_inferred_types: bool,
+    /// Replace invalid UTF-8 and UTF-16 sequences in strings with U+FFFD instead of failing
+    pub lossy_strings: bool,
+    /// Keep properties whose values cannot be decoded (e.g. because their type is unknown or their
+    /// struct types cannot be inferred) as [`crate::Property::Raw`] and continue after them using the size declared by
+    /// their tag instead of failing
+    pub raw_fallback: bool,
+    /// Key to decrypt saves which are AES encrypted. Saves which are not are read as is.
+    pub aes_key: Option<AesKey>,
+    pub aes_mode: AesMode,
+    pub aes_padding: AesPadding,
+    /// Checksum the save is known to have. A footer is otherwise only detected if it matches the
+    /// save as any data following it may look like one, one given here is kept and recomputed on
+    /// write even if it does not match. Headers are detected by the magic following them.
+    pub checksum: Option<Checksum>,
+    /// Containers which are detected and unwrapped, in order. The built-in ones by default.
+    pub containers: Vec<Box<dyn SaveContainer>>,
+    /// Caps on the resources used to read the save. Mostly unbounded by default, saves from
+    /// untrusted sources should be read with [`Limits::untrusted`].
+    pub limits: Limits,
+}
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            types: Types::new(),
+            allow_bad_magic: true,
+            allow_trailing_data: true,
+            allow_size_mismatch: false,
+            resync_size_mismatch: false,
+            allow_inferred_types: true,
+            lossy_strings: false,
+            raw_fallback: false,
+            aes_key: None,
+            aes_mode: AesMode::Ecb,
+            aes_padding: AesPadding::Zero,
+            checksum: None,
+            containers: Container::builtin(),
+            limits: Limits::default(),
+        }
+    }
+}
+impl ReadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Reject anything which may prevent the save from being read or written back correctly
+    pub fn strict() -> Self {
+        Self {
+            allow_bad_magic: false,
+            allow_trailing_data: false,
+            allow_inferred_types: false,
+            ..Self::default()
+        }
+    }
+    /// Read as much of the save as possible
+    pub fn lenient() -> Self {
+        Self {
+            allow_size_mismatch: true,
+            resync_size_mismatch: true,
+            lossy_strings: true,
+            raw_fallback: true,
+            ..Self::default()
+        }
+    }
+    pub fn types(mut self, types: Types) -> Self {
+        self.types = types;
+        self
+    }
+    pub fn aes(mut self, key: AesKey, mode: AesMode, padding: AesPadding) -> Self {
+        self.aes_key = Some(key);
+        self.aes_mode = mode;
+        self.aes_padding = padding;
+        self
+    }
+    pub fn checksum(mut self, checksum: Checksum) -> Self {
+        self.checksum = Some(checksum);
+        self
+    }
+    /// Detects and unwraps saves in `container` too
+    pub fn container(mut self, container: impl SaveContainer + 'static) -> Self {
+        self.containers.push(Box::new(container));
+        self
+    }
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+/// Encoding used to write strings
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StringEncoding {
+    /// Single byte characters for ASCII strings, UTF-16 otherwise
+    #[default]
+    Auto,
+    /// UTF-16 for all non-empty strings
+    Utf16,
+}
+
+/// Options controlling how a save is written
+#[derive(Debug, Default, Clone)]
+pub struct WriteOptions {
+    pub string_encoding: StringEncoding,
+    /// Key to encrypt saves read from AES encrypted data with
+    pub aes_key: Option<AesKey>,
+}
+impl WriteOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn string_encoding(mut self, string_encoding: StringEncoding) -> Self {
+        self.string_encoding = string_encoding;
+        self
+    }
+    pub fn aes_key(mut self, key: AesKey) -> Self {
+        self.aes_key = Some(key);
+        self
+    }
+}
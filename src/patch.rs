@@ -0,0 +1,132 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
+
+use crate::path::Path;
+use crate::{
+    write_property, Context, Diagnostics, Error, Header, ParseError, Property, ReadOptions, Root,
+    TResult, WriteOptions,
+};
+
+/// Size field declaring the length of the data in `covers`
+#[derive(Debug)]
+struct SizeField {
+    width: u8,
+    covers: Range<usize>,
+}
+
+/// Byte spans recorded while reading a save
+#[derive(Debug, Default)]
+pub(crate) struct Spans {
+    /// Extent of each property including its tag by path
+    properties: HashMap<String, Range<usize>>,
+    /// Size fields by offset
+    sizes: BTreeMap<usize, SizeField>,
+}
+impl Spans {
+    pub(crate) fn add_property(&mut self, path: String, start: u64, end: u64) {
+        self.properties.insert(path, start as usize..end as usize);
+    }
+    pub(crate) fn add_size(&mut self, offset: u64, width: u8, start: u64, end: u64) {
+        let covers = start as usize..end as usize;
+        self.sizes
+            .insert(offset as usize, SizeField { width, covers });
+    }
+}
+
+/// Edits a save in place at the byte level. Replacing a property rewrites only its own bytes and
+/// the size fields of the tags enclosing it, everything else is kept exactly as it was read even
+/// if it would not be written back the same by [`crate::Save::write`].
+///
+/// The data has to be the GVAS data itself, containers, encryption and checksums are not
+/// handled. Properties nested in containers whose struct types had to be inferred cannot be
+/// addressed individually, replace the container property instead.
+///
+/// Properties are addressed with the syntax of [`crate::Properties::get_path`] except that map
+/// entries are addressed by position like array elements, e.g. `.Map[2].Value.Name`.
+pub struct Patch {
+    data: Vec<u8>,
+    header: Header,
+    options: ReadOptions,
+    spans: Spans,
+}
+impl Patch {
+    pub fn new(data: Vec<u8>) -> Result<Self, ParseError> {
+        Self::with_options(data, ReadOptions::new())
+    }
+    pub fn with_options(data: Vec<u8>, options: ReadOptions) -> Result<Self, ParseError> {
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let spans = RefCell::new(Spans::default());
+        let mut reader = std::io::Cursor::new(&data[..]);
+        let header = Context::run_with_spans(
+            &options,
+            &diagnostics,
+            Some(&spans),
+            &mut reader,
+            |reader| {
+                let header = Header::read(reader)?;
+                reader.header(&header, Root::read)?;
+                Ok(header)
+            },
+        )
+        .map_err(|e| ParseError::new(e, reader.position() as usize, &data))?;
+        Ok(Self {
+            data,
+            header,
+            options,
+            spans: spans.into_inner(),
+        })
+    }
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+    /// Byte range of the property at `path` (e.g. `.PropList[12].Rotation`) including its tag
+    pub fn span(&self, path: &str) -> Option<Range<usize>> {
+        let path = Path::parse(path).ok()?.display_path()?;
+        self.spans.properties.get(&path).cloned()
+    }
+    /// Replaces the property at `path` by `property`
+    pub fn replace(&mut self, path: &str, property: &Property) -> TResult<()> {
+        let span = self
+            .span(path)
+            .ok_or_else(|| Error::Other(format!("no property {path}")))?;
+        let parsed = Path::parse(path)?;
+        let key = parsed
+            .property()
+            .ok_or_else(|| Error::Other(format!("{path} is not a property")))?;
+        let mut encoded = vec![];
+        Context::run_with_write_options(&WriteOptions::new(), &mut encoded, |writer| {
+            writer.header(&self.header, |writer| {
+                write_property((key, property), writer)
+            })
+        })?;
+        let delta = encoded.len() as i64 - span.len() as i64;
+
+        let mut data = self.data.clone();
+        for (&offset, size) in &self.spans.sizes {
+            let encloses = size.covers.start <= span.start && span.end <= size.covers.end;
+            if !encloses || span.contains(&offset) {
+                continue;
+            }
+            let field = &mut data[offset..offset + size.width as usize];
+            let value = field.iter().rev().fold(0, |v, &b| v << 8 | b as u64);
+            let value = value
+                .checked_add_signed(delta)
+                .filter(|v| size.width == 8 || *v <= u32::MAX as u64)
+                .ok_or_else(|| Error::Other(format!("size at offset {offset} out of range")))?;
+            field.copy_from_slice(&value.to_le_bytes()[..size.width as usize]);
+        }
+        data.splice(span, encoded);
+
+        // read the result again to verify it and record the new spans
+        *self = Self::with_options(data, self.options.clone())
+            .map_err(|e| Error::Other(format!("patched save cannot be read: {e}")))?;
+        Ok(())
+    }
+}
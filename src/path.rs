@@ -0,0 +1,361 @@
+use std::iter::Peekable;
+use std::slice::Iter;
+
+use crate::{
+    Byte, Error, Properties, Property, PropertyKey, PropertyValue, StructValue, TResult,
+    ValueArray, ValueSet, ValueVec,
+};
+
+/// Key of a map entry
+#[derive(Debug, PartialEq)]
+enum MapKey {
+    Int(i128),
+    Str(String),
+}
+
+#[derive(Debug, PartialEq)]
+enum Segment {
+    /// Property of a struct with the name and array index of its [`PropertyKey`]
+    Property(PropertyKey<'static>),
+    /// Element of an array or set or entry of a map by key
+    Element(MapKey),
+}
+
+/// Path to a value nested in [`Properties`], see [`Properties::get_path`] for the syntax
+#[derive(Debug, PartialEq)]
+pub(crate) struct Path(Vec<Segment>);
+impl Path {
+    pub(crate) fn parse(path: &str) -> TResult<Self> {
+        let error = |message: &str| Error::Other(format!("invalid path {path:?}: {message}"));
+        let mut segments = vec![];
+        let mut chars = path.chars().peekable();
+        if chars.peek() == Some(&'.') {
+            chars.next();
+        }
+        while let Some(&c) = chars.peek() {
+            if c == '[' {
+                chars.next();
+                let key = if chars.peek() == Some(&'"') {
+                    chars.next();
+                    let mut key = String::new();
+                    loop {
+                        match chars.next().ok_or_else(|| error("unterminated key"))? {
+                            '"' => break,
+                            '\\' => key.push(chars.next().ok_or_else(|| error("trailing \\"))?),
+                            c => key.push(c),
+                        }
+                    }
+                    MapKey::Str(key)
+                } else {
+                    let mut index = String::new();
+                    while let Some(c) = chars.next_if(|&c| c != ']') {
+                        index.push(c);
+                    }
+                    MapKey::Int(index.trim().parse().map_err(|_| error("invalid index"))?)
+                };
+                if chars.next() != Some(']') {
+                    return Err(error("expected ]"));
+                }
+                segments.push(Segment::Element(key));
+            } else {
+                if !segments.is_empty() {
+                    if c != '.' {
+                        return Err(error("expected . or ["));
+                    }
+                    chars.next();
+                }
+                let mut name = String::new();
+                let mut index = None;
+                while let Some(c) = chars.next_if(|&c| !matches!(c, '.' | '[')) {
+                    match c {
+                        '\\' => name.push(chars.next().ok_or_else(|| error("trailing \\"))?),
+                        '#' if index.is_none() => index = Some(String::new()),
+                        c => match &mut index {
+                            Some(index) => index.push(c),
+                            None => name.push(c),
+                        },
+                    }
+                }
+                if name.is_empty() {
+                    return Err(error("empty property name"));
+                }
+                let index = match index {
+                    Some(index) => index.parse().map_err(|_| error("invalid array index"))?,
+                    None => 0,
+                };
+                segments.push(Segment::Property(PropertyKey(index, name.into())));
+            }
+        }
+        Ok(Self(segments))
+    }
+    /// Path as used to look up [`crate::Types`]. Those apply to all elements of a container so
+    /// elements are not part of it.
+    pub(crate) fn type_path(&self) -> String {
+        self.0
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Property(key) => Some(format!(".{}", escape(&key.1))),
+                Segment::Element(_) => None,
+            })
+            .collect()
+    }
+    /// Path as displayed while reading with elements addressed by position. `None` if an element
+    /// is addressed by a string key.
+    pub(crate) fn display_path(&self) -> Option<String> {
+        self.0
+            .iter()
+            .map(|segment| match segment {
+                Segment::Property(PropertyKey(0, name)) => Some(format!(".{}", escape(name))),
+                Segment::Property(PropertyKey(index, name)) => {
+                    Some(format!(".{}#{index}", escape(name)))
+                }
+                Segment::Element(MapKey::Int(index)) => Some(format!("[{index}]")),
+                Segment::Element(MapKey::Str(_)) => None,
+            })
+            .collect()
+    }
+    /// Key of the property the path ends in
+    pub(crate) fn property(&self) -> Option<&PropertyKey<'static>> {
+        match self.0.last()? {
+            Segment::Property(key) => Some(key),
+            Segment::Element(_) => None,
+        }
+    }
+}
+
+/// Escapes `name` so it is parsed back as a single property name
+pub(crate) fn escape(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        if matches!(c, '\\' | '.' | '[' | '#') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Side of a map entry selected after its element segment
+enum Side {
+    Key,
+    Value,
+}
+impl Side {
+    /// Consumes an explicit `Key` or `Value` segment. A map element is only followed by one of
+    /// them, without any it stands for the value.
+    fn next(segments: &mut Peekable<Iter<Segment>>) -> Option<Self> {
+        let side = match segments.peek() {
+            None => return Some(Side::Value),
+            Some(Segment::Property(PropertyKey(0, name))) if name == "Key" => Side::Key,
+            Some(Segment::Property(PropertyKey(0, name))) if name == "Value" => Side::Value,
+            Some(_) => return None,
+        };
+        segments.next();
+        Some(side)
+    }
+}
+
+/// Value found at a path, see [`Properties::get_path`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueRef<'a> {
+    Property(&'a Property<'a>),
+    /// Element of an array or set of structs
+    Struct(&'a StructValue<'a>),
+    /// Value of a map entry
+    Value(&'a PropertyValue<'a>),
+    /// Element of an array or set of anything but structs with its index
+    Element(&'a ValueVec<'a>, usize),
+}
+
+/// Mutable value found at a path, see [`Properties::get_path_mut`]. It is borrowed for `'r` from
+/// properties which may in turn borrow from the save data for `'a`.
+#[derive(Debug, PartialEq)]
+pub enum ValueMut<'r, 'a> {
+    Property(&'r mut Property<'a>),
+    /// Element of an array or set of structs
+    Struct(&'r mut StructValue<'a>),
+    /// Value of a map entry
+    Value(&'r mut PropertyValue<'a>),
+    /// Element of an array or set of anything but structs with its index
+    Element(&'r mut ValueVec<'a>, usize),
+}
+
+impl<'a> Properties<'a> {
+    /// Value at `path` e.g. `PropList[3].Position.Rotation`. Returns `None` if there is none or
+    /// the path is invalid.
+    ///
+    /// # Path syntax
+    ///
+    /// Segments are separated by `.` (a leading one is optional) and made up of:
+    /// - `Name` for the property `Name` of these properties or a struct
+    /// - `Name#2` for the property `Name` with array index 2 in its [`PropertyKey`]
+    /// - `[3]` for the element 3 of an array or set or the entry of a map with the numeric key 3
+    /// - `["key"]` for the entry of a map with the string, name, enum or object key `key`
+    ///
+    /// `\` escapes the following character in names and quoted keys. The same syntax is accepted
+    /// by [`crate::Types::add`].
+    pub fn get_path(&self, path: &str) -> Option<ValueRef<'_>> {
+        let path = Path::parse(path).ok()?;
+        let mut segments = path.0.iter().peekable();
+        let Some(Segment::Property(key)) = segments.next() else {
+            return None;
+        };
+        let mut value = ValueRef::Property(self.0.get(key)?);
+        while let Some(segment) = segments.next() {
+            value = match segment {
+                Segment::Property(key) => ValueRef::Property(value.properties()?.0.get(key)?),
+                Segment::Element(key) => value.element(key, &mut segments)?,
+            };
+        }
+        Some(value)
+    }
+    /// Mutable value at `path`, see [`Properties::get_path`]
+    pub fn get_path_mut(&mut self, path: &str) -> Option<ValueMut<'_, 'a>> {
+        let path = Path::parse(path).ok()?;
+        let mut segments = path.0.iter().peekable();
+        let Some(Segment::Property(key)) = segments.next() else {
+            return None;
+        };
+        let mut value = ValueMut::Property(self.0.get_mut(key)?);
+        while let Some(segment) = segments.next() {
+            value = match segment {
+                Segment::Property(key) => {
+                    ValueMut::Property(value.into_properties()?.0.get_mut(key)?)
+                }
+                Segment::Element(key) => value.into_element(key, &mut segments)?,
+            };
+        }
+        Some(value)
+    }
+}
+
+impl<'a> ValueRef<'a> {
+    pub fn as_property(self) -> Option<&'a Property<'a>> {
+        match self {
+            ValueRef::Property(property) => Some(property),
+            _ => None,
+        }
+    }
+    /// Value of a struct property, array or set element or map value
+    pub fn as_struct(self) -> Option<&'a StructValue<'a>> {
+        match self {
+            ValueRef::Property(property) => property.as_struct(),
+            ValueRef::Struct(value) => Some(value),
+            ValueRef::Value(value) => value.as_struct(),
+            ValueRef::Element(..) => None,
+        }
+    }
+    /// Properties of a struct made up of properties
+    fn properties(self) -> Option<&'a Properties<'a>> {
+        self.as_struct()?.as_properties()
+    }
+    fn element(self, key: &MapKey, segments: &mut Peekable<Iter<Segment>>) -> Option<Self> {
+        let ValueRef::Property(property) = self else {
+            return None;
+        };
+        match property {
+            Property::Array { value, .. } => match value {
+                ValueArray::Base(vec) => element(vec, key).map(|i| ValueRef::Element(vec, i)),
+                ValueArray::Struct { value, .. } => value.get(index(key)?).map(ValueRef::Struct),
+            },
+            Property::Set { value, .. } => match value {
+                ValueSet::Base(vec) => element(vec, key).map(|i| ValueRef::Element(vec, i)),
+                ValueSet::Struct(value) => value.get(index(key)?).map(ValueRef::Struct),
+            },
+            Property::Map { value, .. } => {
+                let entry = value.iter().find(|entry| matches_key(&entry.key, key))?;
+                Some(ValueRef::Value(match Side::next(segments)? {
+                    Side::Key => &entry.key,
+                    Side::Value => &entry.value,
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'r, 'a> ValueMut<'r, 'a> {
+    pub fn into_property(self) -> Option<&'r mut Property<'a>> {
+        match self {
+            ValueMut::Property(property) => Some(property),
+            _ => None,
+        }
+    }
+    /// Value of a struct property, array or set element or map value
+    pub fn into_struct(self) -> Option<&'r mut StructValue<'a>> {
+        match self {
+            ValueMut::Property(property) => property.as_struct_mut(),
+            ValueMut::Struct(value) => Some(value),
+            ValueMut::Value(value) => value.as_struct_mut(),
+            ValueMut::Element(..) => None,
+        }
+    }
+    fn into_properties(self) -> Option<&'r mut Properties<'a>> {
+        self.into_struct()?.as_properties_mut()
+    }
+    fn into_element(self, key: &MapKey, segments: &mut Peekable<Iter<Segment>>) -> Option<Self> {
+        let ValueMut::Property(property) = self else {
+            return None;
+        };
+        match property {
+            Property::Array { value, .. } => match value {
+                ValueArray::Base(vec) => element(vec, key).map(|i| ValueMut::Element(vec, i)),
+                ValueArray::Struct { value, .. } => {
+                    value.get_mut(index(key)?).map(ValueMut::Struct)
+                }
+            },
+            Property::Set { value, .. } => match value {
+                ValueSet::Base(vec) => element(vec, key).map(|i| ValueMut::Element(vec, i)),
+                ValueSet::Struct(value) => value.get_mut(index(key)?).map(ValueMut::Struct),
+            },
+            Property::Map { value, .. } => {
+                let index = value
+                    .iter()
+                    .position(|entry| matches_key(&entry.key, key))?;
+                let entry = &mut value[index];
+                Some(ValueMut::Value(match Side::next(segments)? {
+                    Side::Key => &mut entry.key,
+                    Side::Value => &mut entry.value,
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn index(key: &MapKey) -> Option<usize> {
+    match key {
+        MapKey::Int(index) => usize::try_from(*index).ok(),
+        MapKey::Str(_) => None,
+    }
+}
+
+/// Index of the element `key` of `vec` if it exists
+fn element(vec: &ValueVec, key: &MapKey) -> Option<usize> {
+    index(key).filter(|&index| index < vec.len())
+}
+
+fn matches_key(value: &PropertyValue, key: &MapKey) -> bool {
+    match key {
+        MapKey::Int(key) => match *value {
+            PropertyValue::Int(v) => v as i128 == *key,
+            PropertyValue::Int8(v) => v as i128 == *key,
+            PropertyValue::Int16(v) => v as i128 == *key,
+            PropertyValue::Int64(v) => v as i128 == *key,
+            PropertyValue::UInt8(v) => v as i128 == *key,
+            PropertyValue::UInt16(v) => v as i128 == *key,
+            PropertyValue::UInt32(v) => v as i128 == *key,
+            PropertyValue::UInt64(v) => v as i128 == *key,
+            PropertyValue::Byte(Byte::Byte(v)) => v as i128 == *key,
+            _ => false,
+        },
+        MapKey::Str(key) => match value {
+            PropertyValue::Str(v)
+            | PropertyValue::Name(v)
+            | PropertyValue::Enum(v)
+            | PropertyValue::Object(v)
+            | PropertyValue::Byte(Byte::Label(v)) => v == key,
+            _ => false,
+        },
+    }
+}
@@ -0,0 +1,172 @@
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use crate::checksum::Hashers;
+use crate::{ChecksumAlgorithm, ParseError};
+
+/// Stream a save is read from
+pub(crate) trait SaveStream: Read + Seek {
+    /// Allows dropping the data preceding `offset` which will not be read again
+    fn release(&mut self, _offset: u64) {}
+    /// Checksum of the data preceding `end`, `None` if it is no longer available
+    fn checksum(&mut self, algorithm: ChecksumAlgorithm, end: u64) -> Option<Vec<u8>>;
+}
+impl<T: AsRef<[u8]>> SaveStream for Cursor<T> {
+    fn checksum(&mut self, algorithm: ChecksumAlgorithm, end: u64) -> Option<Vec<u8>> {
+        let data = self.get_ref().as_ref();
+        Some(algorithm.compute(data.get(..end as usize)?))
+    }
+}
+
+/// Stream properties are read from. One over data which is held in memory for `'d` lends
+/// strings and byte arrays to the properties instead of copying them.
+pub(crate) trait Input<'d>: Read + Seek {
+    /// The next `len` bytes if they can be lent, advancing past them
+    fn lend(&mut self, _len: usize) -> Option<&'d [u8]> {
+        None
+    }
+}
+fn lend<'d>(cursor: &mut Cursor<impl AsRef<[u8]>>, data: &'d [u8], len: usize) -> Option<&'d [u8]> {
+    let start = usize::try_from(cursor.position()).ok()?;
+    let bytes = data.get(start..start.checked_add(len)?)?;
+    cursor.set_position((start + len) as u64);
+    Some(bytes)
+}
+impl<'d> Input<'d> for Cursor<&'d [u8]> {
+    fn lend(&mut self, len: usize) -> Option<&'d [u8]> {
+        let data = *self.get_ref();
+        lend(self, data, len)
+    }
+}
+impl<'d, const N: usize> Input<'d> for Cursor<&'d [u8; N]> {
+    fn lend(&mut self, len: usize) -> Option<&'d [u8]> {
+        let data = *self.get_ref();
+        lend(self, data, len)
+    }
+}
+impl<'d> Input<'d> for Cursor<&'d Vec<u8>> {
+    fn lend(&mut self, len: usize) -> Option<&'d [u8]> {
+        let data = *self.get_ref();
+        lend(self, data, len)
+    }
+}
+/// Data which is only lent if it is borrowed for `'d` itself
+impl<'d> Input<'d> for Cursor<&std::borrow::Cow<'d, [u8]>> {
+    fn lend(&mut self, len: usize) -> Option<&'d [u8]> {
+        match *self.get_ref() {
+            std::borrow::Cow::Borrowed(data) => lend(self, data, len),
+            std::borrow::Cow::Owned(_) => None,
+        }
+    }
+}
+impl Input<'_> for Cursor<Vec<u8>> {}
+impl<const N: usize> Input<'_> for Cursor<[u8; N]> {}
+impl<R: Read> Input<'_> for SeekReader<R> {}
+
+/// Any stream as [`Input`] which never lends so everything read from it is owned
+pub(crate) struct Unlent<R>(pub(crate) R);
+impl<R: Read> Read for Unlent<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+impl<R: Seek> Seek for Unlent<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.0.seek(pos)
+    }
+}
+impl<R: Read + Seek> Input<'_> for Unlent<R> {}
+
+/// Makes a plain [`Read`] stream seekable by keeping the data read since the last
+/// [`SaveStream::release`] and a few bytes before it for the window of a [`ParseError`]. The data
+/// which is dropped is hashed so a checksum footer can still be verified.
+pub(crate) struct SeekReader<R> {
+    reader: R,
+    /// Data kept starting at offset `start`
+    buf: Vec<u8>,
+    start: u64,
+    position: u64,
+    /// Checksums of the data preceding `start`
+    hashers: Hashers,
+}
+impl<R: Read> SeekReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: vec![],
+            start: 0,
+            position: 0,
+            hashers: Hashers::default(),
+        }
+    }
+    fn end(&self) -> u64 {
+        self.start + self.buf.len() as u64
+    }
+    /// Reads from the stream until the data up to `target` is kept or it ends
+    fn fill(&mut self, target: u64) -> std::io::Result<()> {
+        let missing = target.saturating_sub(self.end());
+        if missing > 0 {
+            self.reader
+                .by_ref()
+                .take(missing)
+                .read_to_end(&mut self.buf)?;
+        }
+        Ok(())
+    }
+}
+impl<R: Read> Read for SeekReader<R> {
+    fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+        self.fill(self.position + out.len() as u64)?;
+        let available = &self.buf[(self.position.min(self.end()) - self.start) as usize..];
+        let len = available.len().min(out.len());
+        out[..len].copy_from_slice(&available[..len]);
+        self.position += len as u64;
+        Ok(len)
+    }
+}
+impl<R: Read> Seek for SeekReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(position) => Some(position),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+            SeekFrom::End(offset) => {
+                self.reader.read_to_end(&mut self.buf)?;
+                self.end().checked_add_signed(offset)
+            }
+        }
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "invalid seek position")
+        })?;
+        if position < self.start {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                format!(
+                    "cannot seek back to {position}, data before {} was dropped",
+                    self.start
+                ),
+            ));
+        }
+        self.position = position;
+        Ok(position)
+    }
+}
+impl<R: Read> SaveStream for SeekReader<R> {
+    fn release(&mut self, offset: u64) {
+        let offset = offset
+            .saturating_sub(ParseError::WINDOW as u64)
+            .clamp(self.start, self.end());
+        let dropped = (offset - self.start) as usize;
+        self.hashers.update(&self.buf[..dropped]);
+        self.buf.drain(..dropped);
+        self.start = offset;
+    }
+    fn checksum(&mut self, algorithm: ChecksumAlgorithm, end: u64) -> Option<Vec<u8>> {
+        if end < self.start {
+            return None;
+        }
+        self.fill(end).ok()?;
+        let kept = self.buf.get(..(end - self.start) as usize)?;
+        let mut hashers = self.hashers.clone();
+        hashers.update(kept);
+        Some(hashers.finish(algorithm))
+    }
+}
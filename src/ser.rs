@@ -0,0 +1,764 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::ser::{self, Impossible, Serialize};
+
+use crate::{
+    Byte, ByteArray, Error, MapEntry, Path, Properties, Property, PropertyKey, PropertyType,
+    PropertyValue, StructType, StructValue, TResult, ValueArray, ValueSet, ValueVec,
+};
+
+impl ser::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::Other(msg.to_string())
+    }
+}
+
+/// Type of a value which cannot be told from its Rust type, see [`TypeHints`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeHint {
+    /// Store a number or string as this property type e.g. `Int64Property` for an `i32` or
+    /// `NameProperty` for a `String`. `ByteProperty` stores unit variants as labels.
+    Property(PropertyType),
+    /// Store a string or unit variant as an `EnumProperty` of this enum type e.g. `EColor`
+    Enum(String),
+    /// Store a struct as this struct type instead of the name of the Rust type. Native types
+    /// such as `Vector` are converted from the serde representation of the value.
+    Struct(StructType),
+}
+
+/// Type information used by [`to_properties_with_hints`] for values whose property type cannot
+/// be told from their Rust type alone
+#[derive(Debug, Default, Clone)]
+pub struct TypeHints {
+    hints: HashMap<String, TypeHint>,
+    sets: HashSet<String>,
+}
+impl TypeHints {
+    /// Create an empty [`TypeHints`] specification
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Add a hint for the value at the given path e.g. `.Inventory.Items.Key`. Like
+    /// [`crate::Types`] hints for arrays and sets apply to their elements and keys and values
+    /// of maps are addressed by appending `.Key` and `.Value`.
+    pub fn add(&mut self, path: String, hint: TypeHint) {
+        let path = Path::parse(&path).map_or(path, |p| p.type_path());
+        self.hints.insert(path, hint);
+    }
+    /// Store the sequence at the given path as `SetProperty` instead of `ArrayProperty`
+    pub fn add_set(&mut self, path: String) {
+        let path = Path::parse(&path).map_or(path, |p| p.type_path());
+        self.sets.insert(path);
+    }
+}
+
+/// Serializes `value`, which must serialize as a struct, to properties named after its fields.
+///
+/// Numbers, bools and strings map to the corresponding properties (`u8` to `ByteProperty`,
+/// `i32` to `IntProperty`, strings to `StrProperty`...), unit variants to `EnumProperty` of the
+/// Rust enum, structs to `StructProperty` of the Rust struct, sequences to `ArrayProperty` and
+/// maps to `MapProperty`. Fields which are `None` are omitted. Use `#[serde(rename)]` for
+/// property names and [`to_properties_with_hints`] for types which differ from these defaults,
+/// including engine structs like [`crate::Quat`] which are only serialized natively if hinted
+/// with [`TypeHint::Struct`].
+pub fn to_properties<T: Serialize + ?Sized>(value: &T) -> TResult<Properties<'static>> {
+    to_properties_with_hints(value, &TypeHints::default())
+}
+
+/// Serializes `value` to properties using the provided [`TypeHints`], see [`to_properties`]
+pub fn to_properties_with_hints<T: Serialize + ?Sized>(
+    value: &T,
+    hints: &TypeHints,
+) -> TResult<Properties<'static>> {
+    let serializer = Serializer {
+        hints,
+        path: String::new(),
+    };
+    match value.serialize(serializer)? {
+        Some(Property::Struct {
+            value: StructValue::Struct(properties),
+            ..
+        }) => Ok(properties),
+        _ => Err(Error::Other(
+            "only structs can be serialized to properties".into(),
+        )),
+    }
+}
+
+fn is_native(t: &StructType) -> bool {
+    !matches!(t, StructType::Struct(_))
+}
+
+fn struct_property(struct_type: StructType, value: StructValue<'static>) -> Property<'static> {
+    Property::Struct {
+        id: None,
+        value,
+        struct_type,
+        struct_id: uuid::Uuid::nil(),
+        complete_type: None,
+        trailing: vec![],
+    }
+}
+
+/// Converts the serde representation of a native struct
+fn native_struct(t: &StructType, value: serde_json::Value) -> TResult<Property<'static>> {
+    let tagged =
+        serde_json::Value::Object([(t.get_name().to_owned(), value)].into_iter().collect());
+    let value = serde_json::from_value(tagged)
+        .map_err(|e| Error::Other(format!("invalid {}: {e}", t.get_name())))?;
+    Ok(struct_property(t.clone(), value))
+}
+
+/// Value of a property stored in a container
+fn into_value(property: Property<'static>) -> TResult<PropertyValue<'static>> {
+    Ok(match property {
+        Property::Int8 { value, .. } => PropertyValue::Int8(value),
+        Property::Int16 { value, .. } => PropertyValue::Int16(value),
+        Property::Int { value, .. } => PropertyValue::Int(value),
+        Property::Int64 { value, .. } => PropertyValue::Int64(value),
+        Property::UInt8 { value, .. } => PropertyValue::UInt8(value),
+        Property::UInt16 { value, .. } => PropertyValue::UInt16(value),
+        Property::UInt32 { value, .. } => PropertyValue::UInt32(value),
+        Property::UInt64 { value, .. } => PropertyValue::UInt64(value),
+        Property::Float { value, .. } => PropertyValue::Float(value),
+        Property::Double { value, .. } => PropertyValue::Double(value),
+        Property::Bool { value, .. } => PropertyValue::Bool(value),
+        Property::Byte { value, .. } => PropertyValue::Byte(value),
+        Property::Enum { value, .. } => PropertyValue::Enum(value),
+        Property::Str { value, .. } => PropertyValue::Str(value),
+        Property::Name { value, .. } => PropertyValue::Name(value),
+        Property::Object { value, .. } => PropertyValue::Object(value),
+        Property::Struct { value, .. } => PropertyValue::Struct(value),
+        property => {
+            return Err(Error::Other(format!(
+                "{} cannot be stored in a container",
+                property.type_name()
+            )))
+        }
+    })
+}
+
+/// Type of a property stored in a container
+fn element_type(property: &Property<'static>) -> TResult<PropertyType> {
+    property.get_type().ok_or_else(|| {
+        Error::Other(format!(
+            "{} cannot be stored in a container",
+            property.type_name()
+        ))
+    })
+}
+
+/// Elements of an array or set of `t`
+fn value_vec(t: &PropertyType, values: Vec<PropertyValue<'static>>) -> TResult<ValueVec<'static>> {
+    macro_rules! collect {
+        ($variant:ident) => {
+            values
+                .into_iter()
+                .map(|value| match value {
+                    PropertyValue::$variant(value) => Ok(value),
+                    _ => Err(Error::Other(format!(
+                        "elements are not all of type {}",
+                        t.get_name()
+                    ))),
+                })
+                .collect::<TResult<Vec<_>>>()?
+        };
+    }
+    Ok(match t {
+        PropertyType::Int8Property => ValueVec::Int8(collect!(Int8)),
+        PropertyType::Int16Property => ValueVec::Int16(collect!(Int16)),
+        PropertyType::IntProperty => ValueVec::Int(collect!(Int)),
+        PropertyType::Int64Property => ValueVec::Int64(collect!(Int64)),
+        PropertyType::UInt8Property => ValueVec::UInt8(collect!(UInt8)),
+        PropertyType::UInt16Property => ValueVec::UInt16(collect!(UInt16)),
+        PropertyType::UInt32Property => ValueVec::UInt32(collect!(UInt32)),
+        PropertyType::UInt64Property => ValueVec::UInt64(collect!(UInt64)),
+        PropertyType::FloatProperty => ValueVec::Float(collect!(Float)),
+        PropertyType::DoubleProperty => ValueVec::Double(collect!(Double)),
+        PropertyType::BoolProperty => ValueVec::Bool(collect!(Bool)),
+        PropertyType::EnumProperty => ValueVec::Enum(collect!(Enum)),
+        PropertyType::StrProperty => ValueVec::Str(collect!(Str)),
+        PropertyType::NameProperty => ValueVec::Name(collect!(Name)),
+        PropertyType::ObjectProperty => ValueVec::Object(collect!(Object)),
+        PropertyType::ByteProperty => {
+            let bytes = collect!(Byte);
+            ValueVec::Byte(if bytes.iter().all(|b| matches!(b, Byte::Byte(_))) {
+                ByteArray::Byte(
+                    bytes
+                        .into_iter()
+                        .filter_map(|b| match b {
+                            Byte::Byte(b) => Some(b),
+                            Byte::Label(_) => None,
+                        })
+                        .collect(),
+                )
+            } else {
+                ByteArray::Label(
+                    bytes
+                        .into_iter()
+                        .map(|b| match b {
+                            Byte::Label(l) => Ok(l),
+                            Byte::Byte(_) => {
+                                Err(Error::Other("elements mix bytes and enum labels".into()))
+                            }
+                        })
+                        .collect::<TResult<_>>()?,
+                )
+            })
+        }
+        t => {
+            return Err(Error::Other(format!(
+                "{} cannot be stored in a container",
+                t.get_name()
+            )))
+        }
+    })
+}
+
+/// Serializes a value to the property at `path`, `None` if it should be omitted
+struct Serializer<'h> {
+    hints: &'h TypeHints,
+    /// Path used to look up [`TypeHints`], elements share the path of their container
+    path: String,
+}
+impl<'h> Serializer<'h> {
+    fn hint(&self) -> Option<&'h TypeHint> {
+        self.hints.hints.get(&self.path)
+    }
+    fn child(&self, name: &str) -> Self {
+        Self {
+            hints: self.hints,
+            path: format!("{}.{name}", self.path),
+        }
+    }
+    fn same(&self) -> Self {
+        Self {
+            hints: self.hints,
+            path: self.path.clone(),
+        }
+    }
+    /// Name of the property being serialized
+    fn name(&self) -> &str {
+        self.path.rsplit('.').next().unwrap_or_default()
+    }
+    fn error(&self, message: impl std::fmt::Display) -> Error {
+        Error::Other(format!("{}: {message}", self.path))
+    }
+    fn mismatch(&self, found: &str) -> Error {
+        let expected = match self.hint() {
+            Some(TypeHint::Property(t)) => t.get_name(),
+            Some(TypeHint::Enum(_)) => "EnumProperty",
+            Some(TypeHint::Struct(t)) => t.get_name(),
+            None => "a supported type",
+        };
+        self.error(format!("cannot store {found} as {expected}"))
+    }
+    fn native_hint(&self) -> Option<&'h StructType> {
+        match self.hint() {
+            Some(TypeHint::Struct(t)) if is_native(t) => Some(t),
+            _ => None,
+        }
+    }
+    /// Serializes a single element, map key or value
+    fn element<T: Serialize + ?Sized>(self, value: &T) -> TResult<Property<'static>> {
+        let path = self.path.clone();
+        value
+            .serialize(self)?
+            .ok_or_else(|| Error::Other(format!("{path}: None cannot be stored in a container")))
+    }
+    fn integer(
+        self,
+        value: i128,
+        default: Property<'static>,
+    ) -> TResult<Option<Property<'static>>> {
+        if let Some(t) = self.native_hint() {
+            return native_struct(t, serde_json::json!(value)).map(Some);
+        }
+        let t = match self.hint() {
+            None => return Ok(Some(default)),
+            Some(TypeHint::Property(t)) => t,
+            Some(_) => return Err(self.mismatch(default.type_name())),
+        };
+        let range = |_| self.error(format!("{value} is out of range of {}", t.get_name()));
+        Ok(Some(match t {
+            PropertyType::Int8Property => i8::try_from(value).map_err(range)?.into(),
+            PropertyType::Int16Property => i16::try_from(value).map_err(range)?.into(),
+            PropertyType::IntProperty => i32::try_from(value).map_err(range)?.into(),
+            PropertyType::Int64Property => i64::try_from(value).map_err(range)?.into(),
+            PropertyType::ByteProperty => u8::try_from(value).map_err(range)?.into(),
+            PropertyType::UInt8Property => Property::UInt8 {
+                id: None,
+                value: u8::try_from(value).map_err(range)?,
+                complete_type: None,
+                trailing: vec![],
+            },
+            PropertyType::UInt16Property => u16::try_from(value).map_err(range)?.into(),
+            PropertyType::UInt32Property => u32::try_from(value).map_err(range)?.into(),
+            PropertyType::UInt64Property => u64::try_from(value).map_err(range)?.into(),
+            PropertyType::FloatProperty => (value as f32).into(),
+            PropertyType::DoubleProperty => (value as f64).into(),
+            _ => return Err(self.mismatch(default.type_name())),
+        }))
+    }
+    fn float(self, value: f64, default: Property<'static>) -> TResult<Option<Property<'static>>> {
+        if let Some(t) = self.native_hint() {
+            return native_struct(t, serde_json::json!(value)).map(Some);
+        }
+        Ok(Some(match self.hint() {
+            None => default,
+            Some(TypeHint::Property(PropertyType::FloatProperty)) => (value as f32).into(),
+            Some(TypeHint::Property(PropertyType::DoubleProperty)) => value.into(),
+            Some(_) => return Err(self.mismatch(default.type_name())),
+        }))
+    }
+    /// `EnumProperty` or `ByteProperty` holding `value`
+    fn label(&self, enum_type: &str, value: String, byte: bool) -> Property<'static> {
+        let value = match value.contains("::") {
+            true => value,
+            false => format!("{enum_type}::{value}"),
+        };
+        match byte {
+            true => Property::Byte {
+                id: None,
+                value: Byte::Label(value.into()),
+                enum_type: enum_type.to_owned().into(),
+                complete_type: None,
+                trailing: vec![],
+            },
+            false => Property::Enum {
+                id: None,
+                value: value.into(),
+                enum_type: enum_type.to_owned().into(),
+                complete_type: None,
+                trailing: vec![],
+            },
+        }
+    }
+    fn sequence(self) -> SeqSerializer<'h> {
+        SeqSerializer {
+            serializer: self,
+            elements: vec![],
+        }
+    }
+}
+
+impl<'h> ser::Serializer for Serializer<'h> {
+    type Ok = Option<Property<'static>>;
+    type Error = Error;
+    type SerializeSeq = SeqSerializer<'h>;
+    type SerializeTuple = SeqSerializer<'h>;
+    type SerializeTupleStruct = SeqSerializer<'h>;
+    type SerializeTupleVariant = Impossible<Option<Property<'static>>, Error>;
+    type SerializeMap = MapSerializer<'h>;
+    type SerializeStruct = StructSerializer<'h>;
+    type SerializeStructVariant = Impossible<Option<Property<'static>>, Error>;
+
+    fn serialize_bool(self, v: bool) -> TResult<Option<Property<'static>>> {
+        match self.hint() {
+            None | Some(TypeHint::Property(PropertyType::BoolProperty)) => Ok(Some(v.into())),
+            Some(_) => Err(self.mismatch("bool")),
+        }
+    }
+    fn serialize_i8(self, v: i8) -> TResult<Option<Property<'static>>> {
+        self.integer(v.into(), v.into())
+    }
+    fn serialize_i16(self, v: i16) -> TResult<Option<Property<'static>>> {
+        self.integer(v.into(), v.into())
+    }
+    fn serialize_i32(self, v: i32) -> TResult<Option<Property<'static>>> {
+        self.integer(v.into(), v.into())
+    }
+    fn serialize_i64(self, v: i64) -> TResult<Option<Property<'static>>> {
+        self.integer(v.into(), v.into())
+    }
+    fn serialize_u8(self, v: u8) -> TResult<Option<Property<'static>>> {
+        self.integer(v.into(), v.into())
+    }
+    fn serialize_u16(self, v: u16) -> TResult<Option<Property<'static>>> {
+        self.integer(v.into(), v.into())
+    }
+    fn serialize_u32(self, v: u32) -> TResult<Option<Property<'static>>> {
+        self.integer(v.into(), v.into())
+    }
+    fn serialize_u64(self, v: u64) -> TResult<Option<Property<'static>>> {
+        self.integer(v.into(), v.into())
+    }
+    fn serialize_f32(self, v: f32) -> TResult<Option<Property<'static>>> {
+        self.float(v.into(), v.into())
+    }
+    fn serialize_f64(self, v: f64) -> TResult<Option<Property<'static>>> {
+        self.float(v, v.into())
+    }
+    fn serialize_char(self, v: char) -> TResult<Option<Property<'static>>> {
+        self.serialize_str(&v.to_string())
+    }
+    fn serialize_str(self, v: &str) -> TResult<Option<Property<'static>>> {
+        let value = v.to_owned();
+        Ok(Some(match self.hint() {
+            None | Some(TypeHint::Property(PropertyType::StrProperty)) => value.into(),
+            Some(TypeHint::Property(PropertyType::NameProperty)) => Property::Name {
+                id: None,
+                value: value.into(),
+                complete_type: None,
+                trailing: vec![],
+            },
+            Some(TypeHint::Property(PropertyType::ObjectProperty)) => Property::Object {
+                id: None,
+                value: value.into(),
+                complete_type: None,
+                trailing: vec![],
+            },
+            Some(TypeHint::Enum(enum_type)) => self.label(enum_type, value, false),
+            Some(TypeHint::Struct(t)) if is_native(t) => native_struct(t, value.into())?,
+            Some(_) => return Err(self.mismatch("string")),
+        }))
+    }
+    fn serialize_bytes(self, v: &[u8]) -> TResult<Option<Property<'static>>> {
+        let mut seq = self.sequence();
+        for byte in v {
+            ser::SerializeSeq::serialize_element(&mut seq, byte)?;
+        }
+        ser::SerializeSeq::end(seq)
+    }
+    fn serialize_none(self) -> TResult<Option<Property<'static>>> {
+        Ok(None)
+    }
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> TResult<Option<Property<'static>>> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> TResult<Option<Property<'static>>> {
+        Err(self.mismatch("()"))
+    }
+    fn serialize_unit_struct(self, name: &'static str) -> TResult<Option<Property<'static>>> {
+        Err(self.mismatch(name))
+    }
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> TResult<Option<Property<'static>>> {
+        match self.hint() {
+            None => Ok(Some(self.label(name, variant.into(), false))),
+            Some(TypeHint::Property(PropertyType::EnumProperty)) => {
+                Ok(Some(self.label(name, variant.into(), false)))
+            }
+            Some(TypeHint::Property(PropertyType::ByteProperty)) => {
+                Ok(Some(self.label(name, variant.into(), true)))
+            }
+            Some(_) => self.serialize_str(variant),
+        }
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> TResult<Option<Property<'static>>> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _value: &T,
+    ) -> TResult<Option<Property<'static>>> {
+        Err(self.mismatch(&format!("{name}::{variant}")))
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> TResult<SeqSerializer<'h>> {
+        Ok(self.sequence())
+    }
+    fn serialize_tuple(self, _len: usize) -> TResult<SeqSerializer<'h>> {
+        Ok(self.sequence())
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> TResult<SeqSerializer<'h>> {
+        Ok(self.sequence())
+    }
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> TResult<Self::SerializeTupleVariant> {
+        Err(self.mismatch(&format!("{name}::{variant}")))
+    }
+    fn serialize_map(self, _len: Option<usize>) -> TResult<MapSerializer<'h>> {
+        Ok(MapSerializer {
+            serializer: self,
+            entries: vec![],
+            key_type: None,
+            value_type: None,
+            key: None,
+        })
+    }
+    fn serialize_struct(self, name: &'static str, len: usize) -> TResult<StructSerializer<'h>> {
+        let struct_type = match self.hint() {
+            // a struct of the user is only serialized as an engine struct if hinted to be one
+            None => StructType::Struct(Some(name.to_owned())),
+            Some(TypeHint::Struct(t)) => t.clone(),
+            Some(_) => return Err(self.mismatch(name)),
+        };
+        let fields = if is_native(&struct_type) {
+            Fields::Native(
+                serde_json::value::Serializer
+                    .serialize_struct(name, len)
+                    .map_err(|e| self.error(e))?,
+            )
+        } else {
+            Fields::Properties(Properties::default())
+        };
+        Ok(StructSerializer {
+            serializer: self,
+            struct_type,
+            fields,
+        })
+    }
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> TResult<Self::SerializeStructVariant> {
+        Err(self.mismatch(&format!("{name}::{variant}")))
+    }
+}
+
+/// Array or set
+struct SeqSerializer<'h> {
+    serializer: Serializer<'h>,
+    elements: Vec<Property<'static>>,
+}
+impl SeqSerializer<'_> {
+    fn end(self) -> TResult<Option<Property<'static>>> {
+        let serializer = &self.serializer;
+        let (t, struct_type) =
+            match self.elements.first() {
+                Some(Property::Struct { struct_type, .. }) => {
+                    (PropertyType::StructProperty, Some(struct_type.clone()))
+                }
+                Some(element) => (
+                    element_type(element).map_err(|e| serializer.error(e))?,
+                    None,
+                ),
+                None => match serializer.hint() {
+                    Some(TypeHint::Property(t)) => (t.clone(), None),
+                    Some(TypeHint::Enum(_)) => (PropertyType::EnumProperty, None),
+                    Some(TypeHint::Struct(t)) => (PropertyType::StructProperty, Some(t.clone())),
+                    None => return Err(serializer.error(
+                        "element type of an empty sequence is unknown, specify it with a type hint",
+                    )),
+                },
+            };
+        let set = serializer.hints.sets.contains(&serializer.path);
+        let property = if let Some(struct_type) = struct_type {
+            let value = self
+                .elements
+                .into_iter()
+                .map(|element| match element {
+                    Property::Struct {
+                        value,
+                        struct_type: t,
+                        ..
+                    } if t == struct_type => Ok(value),
+                    _ => Err(serializer.error("elements are not all of the same struct type")),
+                })
+                .collect::<TResult<Vec<_>>>()?;
+            match set {
+                true => Property::Set {
+                    id: None,
+                    set_type: t,
+                    value: ValueSet::Struct(value),
+                    complete_type: None,
+                    trailing: vec![],
+                },
+                false => Property::Array {
+                    array_type: t,
+                    id: None,
+                    value: ValueArray::Struct {
+                        _type: "StructProperty".into(),
+                        name: serializer.name().to_owned().into(),
+                        struct_type,
+                        id: uuid::Uuid::nil(),
+                        value,
+                    },
+                    complete_type: None,
+                    trailing: vec![],
+                },
+            }
+        } else {
+            let values = self
+                .elements
+                .into_iter()
+                .map(into_value)
+                .collect::<TResult<Vec<_>>>()
+                .and_then(|values| value_vec(&t, values))
+                .map_err(|e| serializer.error(e))?;
+            match set {
+                true => Property::Set {
+                    id: None,
+                    set_type: t,
+                    value: ValueSet::Base(values),
+                    complete_type: None,
+                    trailing: vec![],
+                },
+                false => Property::Array {
+                    array_type: t,
+                    id: None,
+                    value: ValueArray::Base(values),
+                    complete_type: None,
+                    trailing: vec![],
+                },
+            }
+        };
+        Ok(Some(property))
+    }
+}
+impl ser::SerializeSeq for SeqSerializer<'_> {
+    type Ok = Option<Property<'static>>;
+    type Error = Error;
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> TResult<()> {
+        self.elements.push(self.serializer.same().element(value)?);
+        Ok(())
+    }
+    fn end(self) -> TResult<Option<Property<'static>>> {
+        SeqSerializer::end(self)
+    }
+}
+impl ser::SerializeTuple for SeqSerializer<'_> {
+    type Ok = Option<Property<'static>>;
+    type Error = Error;
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> TResult<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+    fn end(self) -> TResult<Option<Property<'static>>> {
+        SeqSerializer::end(self)
+    }
+}
+impl ser::SerializeTupleStruct for SeqSerializer<'_> {
+    type Ok = Option<Property<'static>>;
+    type Error = Error;
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> TResult<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+    fn end(self) -> TResult<Option<Property<'static>>> {
+        SeqSerializer::end(self)
+    }
+}
+
+struct MapSerializer<'h> {
+    serializer: Serializer<'h>,
+    entries: Vec<MapEntry<'static>>,
+    key_type: Option<PropertyType>,
+    value_type: Option<PropertyType>,
+    key: Option<PropertyValue<'static>>,
+}
+impl MapSerializer<'_> {
+    /// Property type of the map key or value `name` if there are no entries to tell it from
+    fn hinted_type(&self, t: Option<PropertyType>, name: &str) -> TResult<PropertyType> {
+        if let Some(t) = t {
+            return Ok(t);
+        }
+        let serializer = self.serializer.child(name);
+        match serializer.hint() {
+            Some(TypeHint::Property(t)) => Ok(t.clone()),
+            Some(TypeHint::Enum(_)) => Ok(PropertyType::EnumProperty),
+            Some(TypeHint::Struct(_)) => Ok(PropertyType::StructProperty),
+            None => {
+                Err(serializer
+                    .error("type of an empty map is unknown, specify it with a type hint"))
+            }
+        }
+    }
+}
+impl ser::SerializeMap for MapSerializer<'_> {
+    type Ok = Option<Property<'static>>;
+    type Error = Error;
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> TResult<()> {
+        let serializer = self.serializer.child("Key");
+        let key = serializer.element(key)?;
+        if self.key_type.is_none() {
+            self.key_type = Some(element_type(&key).map_err(|e| self.serializer.error(e))?);
+        }
+        self.key = Some(into_value(key).map_err(|e| self.serializer.error(e))?);
+        Ok(())
+    }
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> TResult<()> {
+        let serializer = self.serializer.child("Value");
+        let value = serializer.element(value)?;
+        if self.value_type.is_none() {
+            self.value_type = Some(element_type(&value).map_err(|e| self.serializer.error(e))?);
+        }
+        self.entries.push(MapEntry {
+            key: self
+                .key
+                .take()
+                .expect("serialize_value called before serialize_key"),
+            value: into_value(value).map_err(|e| self.serializer.error(e))?,
+        });
+        Ok(())
+    }
+    fn end(self) -> TResult<Option<Property<'static>>> {
+        Ok(Some(Property::Map {
+            id: None,
+            key_type: self.hinted_type(self.key_type.clone(), "Key")?,
+            value_type: self.hinted_type(self.value_type.clone(), "Value")?,
+            value: self.entries,
+            complete_type: None,
+            trailing: vec![],
+        }))
+    }
+}
+
+enum Fields {
+    Properties(Properties<'static>),
+    /// Serde representation of a native struct
+    Native(<serde_json::value::Serializer as ser::Serializer>::SerializeStruct),
+}
+
+struct StructSerializer<'h> {
+    serializer: Serializer<'h>,
+    struct_type: StructType,
+    fields: Fields,
+}
+impl ser::SerializeStruct for StructSerializer<'_> {
+    type Ok = Option<Property<'static>>;
+    type Error = Error;
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> TResult<()> {
+        match &mut self.fields {
+            Fields::Properties(properties) => {
+                if let Some(property) = value.serialize(self.serializer.child(key))? {
+                    properties.insert(PropertyKey(0, key.into()), property);
+                }
+            }
+            Fields::Native(fields) => fields
+                .serialize_field(key, value)
+                .map_err(|e| self.serializer.error(e))?,
+        }
+        Ok(())
+    }
+    fn end(self) -> TResult<Option<Property<'static>>> {
+        let property = match self.fields {
+            Fields::Properties(properties) => {
+                struct_property(self.struct_type, StructValue::Struct(properties))
+            }
+            Fields::Native(fields) => {
+                let value = fields.end().map_err(|e| self.serializer.error(e))?;
+                native_struct(&self.struct_type, value).map_err(|e| self.serializer.error(e))?
+            }
+        };
+        Ok(Some(property))
+    }
+}
@@ -0,0 +1,294 @@
+use std::cell::RefCell;
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{ReadBytesExt, LE};
+
+use crate::seek::Unlent;
+use crate::{
+    read_complete_tag, read_complete_tag_value, read_legacy_tag_value, read_optional_uuid,
+    read_string, skip_tag_data, CompleteTag, Context, Diagnostics, Error, Header, Limit,
+    ParseError, Property, PropertyType, ReadOptions, Readable, StructType, TResult,
+    HAS_BINARY_OR_NATIVE_SERIALIZE,
+};
+
+/// Event emitted by [`PropertyReader`]
+#[derive(Debug, PartialEq)]
+pub enum PropertyEvent {
+    /// Start of a property. Struct properties made up of properties are followed by the events
+    /// of those, all other properties by a single [`PropertyEvent::Value`]. Either way
+    /// [`PropertyEvent::End`] follows.
+    Begin {
+        /// Path of the property e.g. `.PropList.Rotation`
+        path: String,
+        property_type: String,
+        /// Size of the value as declared by the property tag
+        size: u32,
+        index: u32,
+    },
+    /// Complete value of the current property
+    Value(Box<Property<'static>>),
+    /// End of the current property
+    End,
+}
+
+/// Property tag which has been read and announced by [`PropertyEvent::Begin`]
+struct Tag {
+    /// Offset of the start of the property tag
+    offset: u64,
+    type_name: String,
+    size: u32,
+    index: u32,
+    /// Offset of the data following the generic part of the tag. In the legacy layout this is
+    /// the type specific tag data, in the UE 5.4+ layout the value.
+    data_start: u64,
+    complete: Option<CompleteTag<'static>>,
+}
+
+enum State {
+    /// Expecting the next property tag or the terminating "None"
+    Properties,
+    /// Following [`PropertyEvent::Begin`]
+    Tag(Tag),
+    /// Following [`PropertyEvent::Value`] or a skipped property
+    End,
+    Done,
+}
+
+/// Pull parser reading the properties of a save one event at a time instead of building the
+/// whole tree in memory. Properties which are of no interest can be skipped using the size
+/// declared by their tag without decoding them.
+///
+/// Only struct properties made up of properties are descended into, all other values
+/// (including arrays, sets and maps) are decoded as a whole. Containers, encryption and
+/// checksums (see [`crate::Save::read`]) are not supported, the stream has to contain the GVAS
+/// data itself. Data following the root properties is not read.
+pub struct PropertyReader<R> {
+    stream: Unlent<R>,
+    header: Header,
+    save_game_type: String,
+    options: ReadOptions,
+    diagnostics: RefCell<Diagnostics>,
+    /// Names of the properties currently being read
+    path: Vec<String>,
+    state: State,
+}
+impl<R: Read + Seek> PropertyReader<R> {
+    /// Reads the header of the save
+    pub fn new(stream: R) -> Result<Self, ParseError> {
+        Self::with_options(stream, ReadOptions::new())
+    }
+    /// Reads the header of the save using the provided [`ReadOptions`]
+    pub fn with_options(stream: R, options: ReadOptions) -> Result<Self, ParseError> {
+        let mut stream = Unlent(stream);
+        let diagnostics = RefCell::new(Diagnostics::new());
+        let (header, save_game_type) =
+            Context::run_with_options(&options, &diagnostics, &mut stream, |reader| {
+                let header = Header::read(reader)?;
+                let save_game_type = reader.header(&header, read_string)?.into_string();
+                Ok((header, save_game_type))
+            })
+            .map_err(|e| ParseError::from_stream(e, &mut stream))?;
+        Ok(Self {
+            stream,
+            header,
+            save_game_type,
+            options,
+            diagnostics,
+            path: vec![],
+            state: State::Properties,
+        })
+    }
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+    pub fn save_game_type(&self) -> &str {
+        &self.save_game_type
+    }
+    /// Non-fatal issues encountered so far
+    pub fn diagnostics(&mut self) -> &Diagnostics {
+        self.diagnostics.get_mut()
+    }
+    /// Returns the next event or `None` once all root properties have been read
+    pub fn next_event(&mut self) -> Result<Option<PropertyEvent>, ParseError> {
+        let result = self.read_event();
+        if result.is_err() {
+            self.state = State::Done;
+        }
+        result
+    }
+    /// Skips the value of the property announced by the preceding [`PropertyEvent::Begin`]
+    /// so the next event is its [`PropertyEvent::End`]
+    pub fn skip_value(&mut self) -> Result<(), ParseError> {
+        let State::Tag(tag) = std::mem::replace(&mut self.state, State::Done) else {
+            let error = Error::Other("skip_value has to directly follow a Begin event".into());
+            return Err(ParseError::new(error, stream_offset(&mut self.stream), &[]));
+        };
+        self.run(|reader| {
+            reader.seek(SeekFrom::Start(tag.data_start))?;
+            if tag.complete.is_none() {
+                match PropertyType::from_name(&tag.type_name) {
+                    Ok(t) => skip_tag_data(reader, &t)?,
+                    // the extent of unknown tag data is only found by parsing it
+                    Err(_) => {
+                        Property::read_raw(reader, tag.type_name.clone(), tag.size)?;
+                        return Ok(());
+                    }
+                }
+            }
+            reader.seek(SeekFrom::Current(tag.size as i64))?;
+            Ok(())
+        })?;
+        self.state = State::End;
+        Ok(())
+    }
+
+    fn read_event(&mut self) -> Result<Option<PropertyEvent>, ParseError> {
+        match std::mem::replace(&mut self.state, State::Done) {
+            State::Done => Ok(None),
+            State::End => {
+                self.path.pop();
+                self.state = State::Properties;
+                Ok(Some(PropertyEvent::End))
+            }
+            State::Properties => {
+                let (offset, name) = self.run(|reader| {
+                    let offset = reader.stream_position()?;
+                    Ok((offset, read_string(reader)?.into_string()))
+                })?;
+                if name == "None" {
+                    if self.path.pop().is_none() {
+                        return Ok(None);
+                    }
+                    self.state = State::Properties;
+                    return Ok(Some(PropertyEvent::End));
+                }
+                let depth = self.path.len() as u64 + 1;
+                let max = self.options.limits.max_depth as u64;
+                if depth > max {
+                    let limit = Limit::Depth;
+                    let error = Error::LimitExceeded {
+                        limit,
+                        value: depth,
+                        max,
+                    };
+                    return Err(ParseError::new(error, offset as usize, &[]));
+                }
+                self.path.push(name);
+                let (tag, path) = self.run(|reader| {
+                    let (type_name, size, index, complete) =
+                        if reader.property_tag_complete_type_name() {
+                            let (tag, _, size, index) = read_complete_tag(reader)?;
+                            (tag.type_name.name.to_string(), size, index, Some(tag))
+                        } else {
+                            let type_name = read_string(reader)?.into_string();
+                            let size = reader.read_u32::<LE>()?;
+                            let index = reader.read_u32::<LE>()?;
+                            (type_name, size, index, None)
+                        };
+                    let tag = Tag {
+                        offset,
+                        type_name,
+                        size,
+                        index,
+                        data_start: reader.stream_position()?,
+                        complete,
+                    };
+                    Ok((tag, reader.display_path()))
+                })?;
+                let event = PropertyEvent::Begin {
+                    path,
+                    property_type: tag.type_name.clone(),
+                    size: tag.size,
+                    index: tag.index,
+                };
+                self.state = State::Tag(tag);
+                Ok(Some(event))
+            }
+            State::Tag(tag) => {
+                if self.enter_struct(&tag)? {
+                    self.state = State::Properties;
+                    return self.read_event();
+                }
+                let value = self.run(|reader| {
+                    reader.seek(SeekFrom::Start(tag.data_start))?;
+                    match tag.complete {
+                        Some(complete) => read_complete_tag_value(
+                            reader,
+                            complete.type_name,
+                            tag.size,
+                            complete.flags,
+                            tag.index,
+                            complete.id,
+                        ),
+                        None => read_legacy_tag_value(reader, &tag.type_name, tag.size),
+                    }
+                    .map_err(|e| reader.property_error(&tag.type_name, tag.size, tag.offset, e))
+                })?;
+                self.state = State::End;
+                Ok(Some(PropertyEvent::Value(Box::new(value))))
+            }
+        }
+    }
+    /// Whether the property is a struct made up of properties. If so the stream is left at the
+    /// first of them.
+    fn enter_struct(&mut self, tag: &Tag) -> Result<bool, ParseError> {
+        if tag.type_name != "StructProperty" {
+            return Ok(false);
+        }
+        self.run(|reader| {
+            let struct_type = match &tag.complete {
+                Some(complete) => {
+                    let native = complete.flags & HAS_BINARY_OR_NATIVE_SERIALIZE != 0;
+                    complete.type_name.struct_type(native)?.0
+                }
+                None => {
+                    reader.seek(SeekFrom::Start(tag.data_start))?;
+                    let struct_type = StructType::read(reader)?;
+                    let struct_type = reader.tagged_struct_type(struct_type);
+                    uuid::Uuid::read(reader)?;
+                    read_optional_uuid(reader)?;
+                    struct_type
+                }
+            };
+            Ok(matches!(struct_type, StructType::Struct(_)))
+        })
+    }
+    /// Runs `f` in the scope of the properties currently being read
+    fn run<T>(
+        &mut self,
+        f: impl FnOnce(&mut Context<'_, '_, '_, '_, Unlent<R>>) -> TResult<T>,
+    ) -> Result<T, ParseError> {
+        let Self {
+            stream,
+            header,
+            options,
+            diagnostics,
+            path,
+            ..
+        } = self;
+        Context::run_with_options(options, diagnostics, stream, |reader| {
+            reader.header(header, |reader| with_path(reader, path, f))
+        })
+        .map_err(|e| ParseError::from_stream(e, stream))
+    }
+}
+impl<R: Read + Seek> Iterator for PropertyReader<R> {
+    type Item = Result<PropertyEvent, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_event().transpose()
+    }
+}
+
+fn with_path<S, T, F>(reader: &mut Context<'_, '_, '_, '_, S>, path: &[String], f: F) -> T
+where
+    F: FnOnce(&mut Context<'_, '_, '_, '_, S>) -> T,
+{
+    match path.split_first() {
+        Some((name, rest)) => reader.scope(name, |reader| with_path(reader, rest, f)),
+        None => f(reader),
+    }
+}
+
+fn stream_offset<S: Seek>(stream: &mut S) -> usize {
+    stream.stream_position().unwrap_or_default() as usize
+}
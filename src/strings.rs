@@ -0,0 +1,232 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::{Hash, Hasher};
+
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Encoding of a string read from a save which differs from how it would be written by default
+/// e.g. ASCII stored as UTF-16, an empty string stored with a terminator or bytes following the
+/// terminator
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StringFormat {
+    pub utf16: bool,
+    /// Terminator and any bytes following it
+    #[serde(with = "hex")]
+    pub trailing: Vec<u8>,
+}
+impl StringFormat {
+    /// Whether a string is stored the same as it would be written by default
+    fn is_default(value: &str, utf16: bool, trailing: &[u8]) -> bool {
+        if value.is_empty() {
+            !utf16 && trailing.is_empty()
+        } else if utf16 {
+            !value.is_ascii() && trailing == [0, 0]
+        } else {
+            value.is_ascii() && trailing == [0]
+        }
+    }
+}
+
+/// String stored in a save. One which is not stored the way it would be written by default keeps
+/// its [`StringFormat`] to be written back the same, it is only part of the JSON representation
+/// in that case. Strings compare and hash by their value alone.
+///
+/// The value is borrowed from the data of a save parsed with [`crate::Save::parse`] and owned
+/// otherwise.
+#[derive(Clone, Default)]
+pub struct FString<'a> {
+    value: Cow<'a, str>,
+    format: Option<Box<StringFormat>>,
+}
+impl<'a> FString<'a> {
+    pub fn new(value: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            value: value.into(),
+            format: None,
+        }
+    }
+    /// String with the given encoding, which is dropped if it is the default for `value`
+    pub fn with_format(value: impl Into<Cow<'a, str>>, format: StringFormat) -> Self {
+        let value = value.into();
+        let format = (!StringFormat::is_default(&value, format.utf16, &format.trailing))
+            .then(|| Box::new(format));
+        Self { value, format }
+    }
+    /// String as read from a save, `trailing` is only evaluated if it is not stored as by default
+    pub(crate) fn read(value: Cow<'a, str>, utf16: bool, trailing: &[u8]) -> Self {
+        if StringFormat::is_default(&value, utf16, trailing) {
+            Self::new(value)
+        } else {
+            let trailing = trailing.to_vec();
+            Self {
+                value,
+                format: Some(Box::new(StringFormat { utf16, trailing })),
+            }
+        }
+    }
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+    /// Encoding of the string if it differs from the default
+    pub fn format(&self) -> Option<&StringFormat> {
+        self.format.as_deref()
+    }
+    pub fn into_string(self) -> String {
+        self.value.into_owned()
+    }
+    /// Whether the value is borrowed from the data of the save
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.value, Cow::Borrowed(_))
+    }
+    /// Copies the value if it is borrowed
+    pub fn into_owned(self) -> FString<'static> {
+        FString {
+            value: Cow::Owned(self.value.into_owned()),
+            format: self.format,
+        }
+    }
+}
+
+impl std::ops::Deref for FString<'_> {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.value
+    }
+}
+impl AsRef<str> for FString<'_> {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+impl Borrow<str> for FString<'_> {
+    fn borrow(&self) -> &str {
+        &self.value
+    }
+}
+impl From<String> for FString<'_> {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+impl<'a> From<&'a str> for FString<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::new(value)
+    }
+}
+impl<'a> From<&'a String> for FString<'a> {
+    fn from(value: &'a String) -> Self {
+        Self::new(value.as_str())
+    }
+}
+impl<'a> From<Cow<'a, str>> for FString<'a> {
+    fn from(value: Cow<'a, str>) -> Self {
+        Self::new(value)
+    }
+}
+impl From<FString<'_>> for String {
+    fn from(value: FString) -> Self {
+        value.into_string()
+    }
+}
+
+impl<'b> PartialEq<FString<'b>> for FString<'_> {
+    fn eq(&self, other: &FString<'b>) -> bool {
+        self.value == other.value
+    }
+}
+impl Eq for FString<'_> {}
+impl PartialOrd for FString<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for FString<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+impl Hash for FString<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+macro_rules! impl_eq {
+    ($($t:ty),*) => {$(
+        impl PartialEq<$t> for FString<'_> {
+            fn eq(&self, other: &$t) -> bool {
+                self.value[..] == other[..]
+            }
+        }
+        impl PartialEq<FString<'_>> for $t {
+            fn eq(&self, other: &FString) -> bool {
+                self[..] == other.value[..]
+            }
+        }
+    )*};
+}
+impl_eq!(str, &str, String);
+
+impl std::fmt::Display for FString<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+impl std::fmt::Debug for FString<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.format {
+            None => self.value.fmt(f),
+            Some(format) => f
+                .debug_struct("FString")
+                .field("value", &self.value)
+                .field("format", format)
+                .finish(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct FormattedRef<'a> {
+    value: &'a str,
+    #[serde(flatten)]
+    format: &'a StringFormat,
+}
+impl Serialize for FString<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.format {
+            None => serializer.serialize_str(&self.value),
+            Some(format) => FormattedRef {
+                value: &self.value,
+                format,
+            }
+            .serialize(serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for FString<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FStringVisitor;
+        impl<'de> Visitor<'de> for FStringVisitor {
+            type Value = FString<'static>;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a string or a string with its format")
+            }
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(FString::new(value.to_owned()))
+            }
+            fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+                Ok(FString::new(value))
+            }
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                #[derive(Deserialize)]
+                struct Formatted {
+                    value: String,
+                    #[serde(flatten)]
+                    format: StringFormat,
+                }
+                let formatted = Formatted::deserialize(de::value::MapAccessDeserializer::new(map))?;
+                Ok(FString::with_format(formatted.value, formatted.format))
+            }
+        }
+        deserializer.deserialize_any(FStringVisitor)
+    }
+}